@@ -0,0 +1,58 @@
+//! Measures the cost of reading through an `RcSlice` compared to a plain slice. Every
+//! iteration indexes a single element, so every iteration goes through `Deref`.
+//!
+//! Run with `cargo bench --bench deref`.
+
+use std::hint::black_box;
+use std::rc::Rc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rc_slice2::{RcSlice, RcSliceContainer};
+
+const LEN: usize = 4096;
+
+fn index_each(c: &mut Criterion) {
+    let data: Vec<u64> = (0..LEN as u64).collect();
+    let slice_view = RcSlice::new(&Rc::<[u64]>::from(data.as_slice()), 1..LEN - 1);
+    let vec_view = RcSlice::new(&Rc::new(data.clone()), 1..LEN - 1);
+
+    let mut group = c.benchmark_group("deref");
+
+    // Baseline: direct slicing, with a single bounds check.
+    group.bench_function("slice index", |b| {
+        b.iter(|| {
+            let data = black_box(&data[..]);
+            (0..LEN - 2).map(|i| data[1..LEN - 1][i]).sum::<u64>()
+        })
+    });
+
+    // The code path `Deref` used before `get_unchecked_by_invariant`: build an `Option`,
+    // then unwrap it.
+    group.bench_function("get unwrap", |b| {
+        b.iter(|| {
+            let data = black_box(&data[..]);
+            (0..LEN - 2)
+                .map(|i| RcSliceContainer::get(data, 1..LEN - 1).unwrap()[i])
+                .sum::<u64>()
+        })
+    });
+
+    group.bench_function("RcSlice<[T]>", |b| {
+        b.iter(|| {
+            let view = black_box(&slice_view);
+            (0..LEN - 2).map(|i| view[i]).sum::<u64>()
+        })
+    });
+
+    group.bench_function("RcSlice<Vec<T>>", |b| {
+        b.iter(|| {
+            let view = black_box(&vec_view);
+            (0..LEN - 2).map(|i| view[i]).sum::<u64>()
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, index_each);
+criterion_main!(benches);
@@ -0,0 +1,79 @@
+//! Measures splitting an `RcSlice` into pieces. The split functions build both halves from
+//! bounds they already know, so the "new" baseline shows the cost of going back through
+//! `RcSlice::new` and its `RangeBounds` matching for each half instead.
+//!
+//! Run with `cargo bench --bench split`.
+
+use std::hint::black_box;
+use std::rc::Rc;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use rc_slice2::RcSlice;
+
+const LEN: usize = 4096;
+const CHUNK: usize = 16;
+
+fn split(c: &mut Criterion) {
+    let buffer: Rc<[u64]> = (0..LEN as u64).collect();
+    let view = RcSlice::new(&buffer, ..);
+
+    let mut group = c.benchmark_group("split");
+
+    // Baseline: split the way `split_at` did before, with two calls to `new`.
+    group.bench_function("new", |b| {
+        b.iter(|| {
+            let mut rest = black_box(&view).clone();
+            let mut total = 0;
+            while rest.len() >= CHUNK {
+                let range = RcSlice::bounds_range(&rest);
+                let inner = RcSlice::inner(&rest);
+                let low = RcSlice::new(inner, range.start..range.start + CHUNK);
+                rest = RcSlice::new(inner, range.start + CHUNK..range.end);
+                total += low[0];
+            }
+            total
+        })
+    });
+
+    group.bench_function("split_at", |b| {
+        b.iter(|| {
+            let mut rest = black_box(&view).clone();
+            let mut total = 0;
+            while rest.len() >= CHUNK {
+                let (low, high) = RcSlice::split_at(&rest, CHUNK);
+                rest = high;
+                total += low[0];
+            }
+            total
+        })
+    });
+
+    group.bench_function("split_off_before", |b| {
+        b.iter(|| {
+            let mut rest = black_box(&view).clone();
+            let mut total = 0;
+            while let Some(low) = RcSlice::split_off_before(&mut rest, CHUNK) {
+                total += low[0];
+            }
+            total
+        })
+    });
+
+    group.bench_function("split_off_after", |b| {
+        b.iter(|| {
+            let mut rest = black_box(&view).clone();
+            let mut total = 0;
+            while rest.len() >= CHUNK {
+                let mid = rest.len() - CHUNK;
+                let high = RcSlice::split_off_after(&mut rest, mid).unwrap();
+                total += high[0];
+            }
+            total
+        })
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, split);
+criterion_main!(benches);
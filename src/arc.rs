@@ -1,17 +1,51 @@
+use alloc::boxed::Box;
+use alloc::rc::Rc;
 use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
-use core::ops::{Bound, Deref, Range, RangeBounds};
+use core::iter::{FusedIterator, Skip, Sum, Zip};
+use core::ops::{Bound, Deref, Index, Range, RangeBounds};
+use core::slice::{self, SliceIndex};
+use core::str::CharIndices;
 
-use crate::RcSliceContainer;
+use crate::sealed::Sealed;
+use crate::{MutError, RcSlice, RcSliceContainer, SharedSlice, ShrinkError, TotalCmp};
 
 /// A read-only view into part of an underlying atomically reference-counted slice.
 ///
 /// The associated functions provided for this type do not take a receiver to avoid conflicting
 /// with (present or future) methods on `[T]`, since `ArcSlice<T>: Deref<Target = [T]>`. To reduce
 /// the length of code, it is recommended to include `use ArcSlice as Arcs` where needed.
+///
+/// # Performance
+///
+/// Cloning an ArcSlice, or creating another view of the same buffer (for example with
+/// [`split_at`](ArcSlice::split_at) or [`new`](ArcSlice::new)), never allocates and never copies the
+/// elements; it only costs an atomic increment of the `Arc`'s strong count.
+///
+/// # Overflow
+///
+/// Functions that take a count, an index, or a range check it against the slice before doing any
+/// arithmetic with it: they return `None`, saturate, or clamp, as documented on each function. The
+/// few that panic instead (such as [`split_at`](ArcSlice::split_at)) say so in a "Panics" section.
+/// Other positions are computed from the slice itself and stay between its start and end, so they
+/// can't overflow either. This includes buffers of `usize::MAX` zero-sized elements.
+///
+/// # Inconsistent containers
+///
+/// Reading the elements (through `Deref`, `AsRef`, or indexing) only panics if the container's
+/// [`RcSliceContainer`] implementation is inconsistent, for example if `len` reports more elements
+/// than `get` can return. The panic then comes from
+/// [`get_unchecked_by_invariant`](RcSliceContainer::get_unchecked_by_invariant), exactly as it
+/// would for [`RcSlice`](crate::RcSlice). The containers provided by this crate are always
+/// consistent, so this can't happen with them.
+///
+/// # Thread safety
+///
+/// Like `Arc`, ArcSlice is `Send` and `Sync` when the underlying container is `Send` and `Sync`.
 pub struct ArcSlice<T: ?Sized> {
     /// The underlying container.
     underlying: Arc<T>,
@@ -49,24 +83,112 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// assert_eq!(*Arcs::new(&buffer, 0..=2), [2, 4, 6]);
     /// assert_eq!(*Arcs::new(&buffer, 10..), []);
     /// ```
+    ///
+    /// Ranges that go past the end of the buffer are clamped to the end of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the start of `range` is greater than the end of `range`
+    /// (for example, `5..2`), since this is almost always a mistake. In release builds, such
+    /// ranges produce an empty slice instead.
     pub fn new<R: RangeBounds<usize>>(underlying: &Arc<T>, range: R) -> Self {
-        let start = match range.start_bound() {
-            Bound::Excluded(x) => usize::min(x.saturating_add(1), underlying.len()),
-            Bound::Included(x) => usize::min(*x, underlying.len()),
+        ArcSlice::from_arc_range(underlying.clone(), range)
+    }
+
+    /// Create a new ArcSlice, taking ownership of `underlying` instead of cloning it. Otherwise
+    /// the same as [`new`](ArcSlice::new), including its handling of `range`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let slice = Arcs::from_arc_range(Arc::new(vec![2, 4, 6, 8, 10]), 1..4);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(Arc::strong_count(Arcs::inner(&slice)), 1);
+    /// ```
+    pub fn from_arc_range<R: RangeBounds<usize>>(underlying: Arc<T>, range: R) -> Self {
+        let requested_start = match range.start_bound() {
+            Bound::Excluded(x) => x.saturating_add(1),
+            Bound::Included(x) => *x,
             Bound::Unbounded => 0,
         };
-        let end = match range.end_bound() {
-            Bound::Excluded(x) => usize::min(*x, underlying.len()),
-            Bound::Included(x) => usize::min(x.saturating_add(1), underlying.len()),
-            Bound::Unbounded => underlying.len(),
+        let requested_end = match range.end_bound() {
+            Bound::Excluded(x) => Some(*x),
+            Bound::Included(x) => Some(x.saturating_add(1)),
+            Bound::Unbounded => None,
+        };
+        if let Some(requested_end) = requested_end {
+            debug_assert!(
+                requested_start <= requested_end,
+                "range start is greater than range end"
+            );
+        }
+
+        let len = underlying.len();
+        let start = usize::min(requested_start, len);
+        let end = match requested_end {
+            Some(x) => usize::min(x, len),
+            None => len,
         };
         Self {
-            underlying: underlying.clone(),
+            underlying,
             start,
             end: usize::max(start, end),
         }
     }
 
+    /// Create a new ArcSlice from a buffer and exact bounds, such as those returned by
+    /// [`inner`](ArcSlice::inner) and [`bounds_range`](ArcSlice::bounds_range). Returns `None` if
+    /// the range is not valid for the buffer, that is, unless
+    /// `range.start <= range.end <= underlying.len()`.
+    ///
+    /// Unlike [`new`](ArcSlice::new), the range is never clamped, so the result always views
+    /// exactly `range`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    ///
+    /// let slice = Arcs::from_parts(buffer.clone(), 1..4).unwrap();
+    /// assert_eq!(*slice, [4, 6, 8]);
+    ///
+    /// assert_eq!(Arcs::from_parts(buffer.clone(), 3..6), None);
+    /// assert_eq!(Arcs::from_parts(buffer, 4..3), None);
+    /// ```
+    pub fn from_parts(underlying: Arc<T>, range: Range<usize>) -> Option<Self> {
+        if range.start <= range.end && range.end <= underlying.len() {
+            Some(Self {
+                underlying,
+                start: range.start,
+                end: range.end,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new ArcSlice from bounds that are already known to be valid, skipping the
+    /// range handling done by [`new`](ArcSlice::new).
+    ///
+    /// The caller must ensure that `start <= end <= underlying.len()`. This is only checked in
+    /// debug builds.
+    #[inline]
+    fn from_bounds(underlying: &Arc<T>, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end && end <= underlying.len());
+        Self {
+            underlying: underlying.clone(),
+            start,
+            end,
+        }
+    }
+
     /////////////////////////////////////////////
     // Slice methods, but for ArcSlice.
     //
@@ -113,16 +235,25 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
         it.end == it.start
     }
 
-    /// Divides one slice into two at an index.
+    /// Returns the elements viewed by the slice. This is the same as `&*it` or `it.as_ref()`.
     ///
-    /// The first will contain all indices from `[0, mid)` (excluding the index `mid` itself)
-    /// and the second will contain all indices from `[mid, len)` (excluding the index `len` itself).
-    ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
     ///
-    /// # Panics
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
     ///
-    /// Panics if `mid > it.len()`.
+    /// assert_eq!(Arcs::as_slice(&slice), [4, 6, 8]);
+    /// ```
+    pub fn as_slice(it: &Self) -> &[T::Item] {
+        it.as_ref()
+    }
+
+    /// Returns an iterator over the elements of the slice, paired with their index in the
+    /// underlying buffer (not their index within the slice).
     ///
     /// ```
     /// # extern crate alloc;
@@ -131,29 +262,20 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// use ArcSlice as Arcs;
     ///
     /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
-    /// let slice = Arcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 2..4);
     ///
-    /// let (low, high) = Arcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// let mut iter = Arcs::iter_indices(&slice);
+    /// assert_eq!(iter.next(), Some((2, &6)));
+    /// assert_eq!(iter.next(), Some((3, &8)));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn split_at(it: &Self, mid: usize) -> (Self, Self) {
-        assert!(mid <= ArcSlice::len(it));
-        // This addition is guaranteed not to overflow because of the above
-        // assertion, and the invariant `start <= end`.
-        let real_mid = it.start + mid;
-
-        (
-            ArcSlice::new(&it.underlying, it.start..real_mid),
-            ArcSlice::new(&it.underlying, real_mid..it.end),
-        )
+    pub fn iter_indices(it: &Self) -> Zip<Range<usize>, slice::Iter<'_, T::Item>> {
+        (it.start..it.end).zip(it.as_ref())
     }
 
-    /// This is the same as [`split_at`](ArcSlice::split_at), but returns `None` if `mid > len` instead
-    /// of panicking.
-    ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    /// Returns an iterator over each pair of adjacent elements of the slice, in order. This is
+    /// like `it.windows(2)`, but yields tuples. The iterator is empty if the slice has fewer than
+    /// two elements.
     ///
     /// ```
     /// # extern crate alloc;
@@ -161,306 +283,3189 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let buffer: Arc<[i64]> = Arc::new([1, 4, 9, 16, 25]);
     /// let slice = Arcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
-    ///
-    /// let (low, high) = Arcs::try_split_at(&slice, 2).unwrap();
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
     ///
-    /// assert_eq!(Arcs::try_split_at(&slice, 5), None);
+    /// let deltas: Vec<i64> = Arcs::pairwise(&slice).map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [5, 7, 9]);
     /// ```
-    pub fn try_split_at(it: &Self, mid: usize) -> Option<(Self, Self)> {
-        if mid <= ArcSlice::len(it) {
-            Some(ArcSlice::split_at(it, mid))
-        } else {
-            None
-        }
+    pub fn pairwise(it: &Self) -> Zip<slice::Iter<'_, T::Item>, Skip<slice::Iter<'_, T::Item>>> {
+        it.iter().zip(it.iter().skip(1))
     }
 
-    /// This is an in-place version of [`try_split_at`](ArcSlice::try_split_at).
-    ///
-    /// If `mid` is valid, mutates `it` to the upper half, and returns the lower half.
-    /// Specifically, this will mutate the slice `it` to be `[mid, len)`, and returns the slice `[0, mid)`.
-    ///
-    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    /// Folds every element of the slice into an accumulator, in order. This is the same as
+    /// `it.iter().fold(init, f)`.
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
-    ///  ```
+    /// ```
     /// # extern crate alloc;
     /// # use rc_slice2::ArcSlice;
     /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
     /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
-    /// let mut slice = Arcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
-    ///
-    /// let (low, high) = Arcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
     ///
-    /// let other_high = Arcs::split_off_before(&mut slice, 2).unwrap();
-    /// assert_eq!(*other_high, [4, 6]);
-    /// assert_eq!(*slice, [8, 10]);
-    ///
-    /// assert_eq!(Arcs::split_off_before(&mut slice, 5), None);
-    /// assert_eq!(*slice, [8, 10]);
+    /// let checksum = Arcs::fold(&slice, 0u8, |acc, x| acc ^ x);
+    /// assert_eq!(checksum, 4 ^ 6 ^ 8);
     /// ```
-    pub fn split_off_before(it: &mut Self, index: usize) -> Option<Self> {
-        let cut = it.start.checked_add(index)?;
-
-        if cut <= it.end {
-            let mut front = it.clone();
-            front.end = cut;
-            it.start = cut;
-
-            Some(front)
-        } else {
-            None
-        }
+    pub fn fold<B, F: FnMut(B, &T::Item) -> B>(it: &Self, init: B, f: F) -> B {
+        it.as_ref().iter().fold(init, f)
     }
 
-    /// This is an in-place version of [`try_split_at`](ArcSlice::try_split_at).
+    /// Sums the elements of the slice. This is the same as `it.iter().sum()`.
     ///
-    /// If `mid` is valid, mutates `it` to the lower half, and returns the upper half.
-    /// Specifically, this will mutate the slice `it` to be `[0, mid)`, and returns the slice `[mid, len)`.
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
     ///
-    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    /// let buffer: Arc<[u32]> = Arc::new([2, 4, 6, 8, 10]);
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    /// assert_eq!(Arcs::sum::<u32>(&Arcs::new(&buffer, 1..4)), 18);
+    /// assert_eq!(Arcs::sum::<u32>(&Arcs::new(&buffer, 2..2)), 0);
+    /// ```
+    pub fn sum<'a, S: Sum<&'a T::Item>>(it: &'a Self) -> S {
+        it.as_ref().iter().sum()
+    }
+
+    /// Applies `f` to each element of the slice, and collects the results into a new buffer.
+    /// Stops at the first error, and returns it; `f` isn't called on any later elements.
     ///
-    ///  ```
+    /// ```
     /// # extern crate alloc;
-    /// # use rc_slice2::ArcSlice;
     /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
-    /// let mut slice = Arcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    /// #[derive(Debug, PartialEq)]
+    /// enum Op {
+    ///     Push,
+    ///     Pop,
+    /// }
+    ///
+    /// let parse = |&b: &u8| match b {
+    ///     0 => Ok(Op::Push),
+    ///     1 => Ok(Op::Pop),
+    ///     other => Err(other),
+    /// };
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([0, 0, 1, 7]);
+    /// let ops = Arcs::try_map(&Arcs::new(&buffer, ..3), parse);
+    /// assert_eq!(*ops.unwrap(), [Op::Push, Op::Push, Op::Pop]);
+    /// assert_eq!(Arcs::try_map(&Arcs::new(&buffer, ..), parse), Err(7));
+    /// ```
+    pub fn try_map<U, E, F: FnMut(&T::Item) -> Result<U, E>>(
+        it: &Self,
+        f: F,
+    ) -> Result<ArcSlice<[U]>, E> {
+        it.as_ref().iter().map(f).collect()
+    }
+
+    /// Like `[T]::partition_point`, but returns the index of the partition point within the
+    /// underlying buffer, rather than within the slice.
     ///
-    /// let (low, high) = Arcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
     ///
-    /// let other_high = Arcs::split_off_after(&mut slice, 2).unwrap();
-    /// assert_eq!(*other_high, [8, 10]);
-    /// assert_eq!(*slice, [4, 6]);
+    /// let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let slice = Arcs::new(&buffer, 2..6);
     ///
-    /// assert_eq!(Arcs::split_off_after(&mut slice, 5), None);
-    /// assert_eq!(*slice, [4, 6]);
+    /// assert_eq!(slice.partition_point(|&x| x < 5), 2);
+    /// assert_eq!(Arcs::partition_point_absolute(&slice, |&x| x < 5), 4);
+    /// assert_eq!(buffer[4], 5);
     /// ```
-    pub fn split_off_after(it: &mut Self, index: usize) -> Option<Self> {
-        let cut = it.start.checked_add(index)?;
-
-        if cut <= it.end {
-            let mut back = it.clone();
-            back.start = cut;
-            it.end = cut;
-
-            Some(back)
-        } else {
-            None
-        }
+    pub fn partition_point_absolute<F: FnMut(&T::Item) -> bool>(it: &Self, pred: F) -> usize {
+        it.start + it.as_ref().partition_point(pred)
     }
 
-    /////////////////////////////////////////////
-    // Methods related to being a view of a
-    // larger container.
-    //
-
-    /// Returns the inner buffer.
-    pub fn inner(it: &Self) -> &Arc<T> {
-        &it.underlying
+    /// Returns a reference to the elements of the slice as an array, if the slice is exactly `N`
+    /// elements long. Returns `None` otherwise.
+    ///
+    /// Unlike [`try_into_array`](ArcSlice::try_into_array), this doesn't copy anything, so it
+    /// works for elements that aren't `Copy`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[String]> = Arc::new(["a".into(), "b".into(), "c".into()]);
+    /// let slice = Arcs::new(&buffer, 1..);
+    ///
+    /// let [b, c] = Arcs::as_array(&slice).unwrap();
+    /// assert_eq!(b, "b");
+    /// assert_eq!(c, "c");
+    /// assert_eq!(Arcs::as_array::<3>(&slice), None);
+    /// ```
+    pub fn as_array<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        <&[T::Item; N]>::try_from(it.as_ref()).ok()
     }
 
-    /// Returns the starting and ending indices of the view `it` within the underlying slice.
+    /// Returns a reference to the first `N` elements of the slice as an array, or `None` if the
+    /// slice has fewer than `N` elements.
+    ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
     /// # use rc_slice2::ArcSlice;
     /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
     /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
     ///
-    /// assert_eq!(Arcs::bounds(&Arcs::new(&buffer, ..)), (0, 5));
-    /// assert_eq!(Arcs::bounds(&Arcs::new(&buffer, 1..3)), (1, 3));
+    /// assert_eq!(Arcs::first_chunk::<2>(&slice), Some(&[4, 6]));
+    /// assert_eq!(Arcs::first_chunk::<4>(&slice), None);
     /// ```
-    #[deprecated(since = "0.4.0", note = "Use `bounds_range` instead.")]
-    pub fn bounds(it: &Self) -> (usize, usize) {
-        (it.start, it.end)
+    pub fn first_chunk<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        it.as_ref().first_chunk()
     }
 
-    /// Returns the range that this slice represents.
+    /// Returns a reference to the last `N` elements of the slice as an array, or `None` if the
+    /// slice has fewer than `N` elements.
     ///
-    ///  ```
+    /// ```
     /// # extern crate alloc;
     /// # use rc_slice2::ArcSlice;
     /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
     /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
     ///
-    /// assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, ..)), 0..5);
-    /// assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, 1..3)), 1..3);
+    /// assert_eq!(Arcs::last_chunk::<2>(&slice), Some(&[6, 8]));
+    /// assert_eq!(Arcs::last_chunk::<4>(&slice), None);
     /// ```
-    pub fn bounds_range(it: &Self) -> Range<usize> {
-        it.start..it.end
+    pub fn last_chunk<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        it.as_ref().last_chunk()
     }
 
-    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
-    /// elements cut off by this operation. The end of the slice is not affected.
-    ///
-    /// Returns `None` and leaves `self` unchanged if this operation would make the starting index
-    /// greater than the ending index.
+    /// Returns a reference to the first `N` elements of the slice as an array, and a new ArcSlice
+    /// of the rest of the slice, or `None` if the slice has fewer than `N` elements.
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
     ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
-    /// # use alloc::sync::Arc;
     /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..8);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
-    ///
-    /// // Take the first two
-    /// assert_eq!(Arcs::advance(&mut slice, 2), Some([4, 6].as_slice()));
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
-    ///
-    /// // Take three more
-    /// assert_eq!(Arcs::advance(&mut slice, 3), Some([8, 10, 12].as_slice()));
-    /// assert_eq!(*slice, [14, 16]);
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let packet = Arcs::new(&buffer, ..);
     ///
-    /// // Try to take three, but can't. Slice is unchanged.
-    /// assert_eq!(Arcs::advance(&mut slice, 3), None);
-    /// assert_eq!(*slice, [14, 16]);
+    /// let (header, body) = Arcs::split_first_chunk::<2>(&packet).unwrap();
+    /// assert_eq!(header, &[2, 4]);
+    /// assert_eq!(*body, [6, 8, 10]);
     ///
-    /// // Take the rest.
-    /// assert_eq!(Arcs::advance(&mut slice, 2), Some([14, 16].as_slice()));
-    /// assert_eq!(*slice, []);
+    /// assert_eq!(Arcs::split_first_chunk::<6>(&packet), None);
     /// ```
-    pub fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
-        let cut = it.start.checked_add(incr)?;
-
-        if cut <= it.end {
-            let shed = it.underlying.get(it.start..cut)?;
-            it.start = cut;
-
-            Some(shed)
-        } else {
-            None
-        }
+    pub fn split_first_chunk<const N: usize>(it: &Self) -> Option<(&[T::Item; N], Self)> {
+        let chunk = ArcSlice::first_chunk(it)?;
+        // `first_chunk` succeeded, so `N <= len`, and `start + N` is at most `end`.
+        Some((
+            chunk,
+            ArcSlice::from_bounds(&it.underlying, it.start + N, it.end),
+        ))
     }
 
-    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
-    /// elements cut off by this operation. The end of the slice is not affected.
-    ///
-    /// If the slice doesn't contain enough elements, returns all available elements.
+    /// Returns a new ArcSlice of the start of the slice, and a reference to the last `N` elements
+    /// of the slice as an array, or `None` if the slice has fewer than `N` elements.
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
     ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
-    /// # use alloc::sync::Arc;
     /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..8);
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let packet = Arcs::new(&buffer, ..);
     ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    /// let (body, trailer) = Arcs::split_last_chunk::<2>(&packet).unwrap();
+    /// assert_eq!(*body, [2, 4, 6]);
+    /// assert_eq!(trailer, &[8, 10]);
     ///
-    /// // Take the first two
-    /// assert_eq!(Arcs::saturating_advance(&mut slice, 2), [4, 6]);
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
+    /// assert_eq!(Arcs::split_last_chunk::<6>(&packet), None);
+    /// ```
+    pub fn split_last_chunk<const N: usize>(it: &Self) -> Option<(Self, &[T::Item; N])> {
+        let chunk = ArcSlice::last_chunk(it)?;
+        // `last_chunk` succeeded, so `N <= len`, and `end - N` is at least `start`.
+        Some((
+            ArcSlice::from_bounds(&it.underlying, it.start, it.end - N),
+            chunk,
+        ))
+    }
+
+    /// Divides one slice into two at an index.
     ///
-    /// // Take three more
-    /// assert_eq!(Arcs::saturating_advance(&mut slice, 3), [8, 10, 12]);
-    /// assert_eq!(*slice, [14, 16]);
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid` itself)
+    /// and the second will contain all indices from `[mid, len)` (excluding the index `len` itself).
     ///
-    /// // Try to take three, but can only take 2.
-    /// assert_eq!(Arcs::saturating_advance(&mut slice, 3), [14, 16]);
-    /// assert_eq!(*slice, []);
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// Each half holds its own strong reference to the buffer, even if it is empty (when `mid` is
+    /// `0` or `len`), so this always adds exactly two to the strong count. If one half would be
+    /// discarded, [`split_off_before`](ArcSlice::split_off_before) and
+    /// [`split_off_after`](ArcSlice::split_off_after) only add one, since they reuse `it`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > it.len()`.
     ///
-    /// // Try to take two, but slice is empty.
-    /// assert_eq!(Arcs::saturating_advance(&mut slice, 2), []);
-    /// assert_eq!(*slice, []);
     /// ```
-    pub fn saturating_advance(it: &mut Self, incr: usize) -> &[T::Item] {
-        let cut = usize::min(it.start.saturating_add(incr), it.end);
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    ///
+    /// let (low, high) = Arcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    /// ```
+    pub fn split_at(it: &Self, mid: usize) -> (Self, Self) {
+        assert!(mid <= ArcSlice::len(it), "split index out of bounds");
+        // This addition should never overflow because of the above assertion, and
+        // the invariant `start <= end`. It is checked anyway, so that a broken
+        // invariant panics instead of silently wrapping around.
+        let real_mid = it
+            .start
+            .checked_add(mid)
+            .expect("split index overflowed usize");
 
-        // TODO: Evaluate whether this will panic, and when.
-        // I believe it will only panic if the container trait impl
-        // is implemented weirdly.
-        let shed = it.underlying.get(it.start..cut).unwrap();
-        it.start = cut;
-        shed
+        (
+            ArcSlice::from_bounds(&it.underlying, it.start, real_mid),
+            ArcSlice::from_bounds(&it.underlying, real_mid, it.end),
+        )
     }
 
-    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
-    /// elements cut off by this operation.
-    ///
-    /// Returns `None` and leaves `it` unchanged if this operation would make the ending index less
-    /// than the starting index.
+    /// This is the same as [`split_at`](ArcSlice::split_at), but returns `None` if `mid > len` instead
+    /// of panicking.
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
     ///
     /// ```
-    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    ///
+    /// let (low, high) = Arcs::try_split_at(&slice, 2).unwrap();
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// assert_eq!(Arcs::try_split_at(&slice, 5), None);
+    /// ```
+    pub fn try_split_at(it: &Self, mid: usize) -> Option<(Self, Self)> {
+        if mid <= ArcSlice::len(it) {
+            Some(ArcSlice::split_at(it, mid))
+        } else {
+            None
+        }
+    }
+
+    /// This is the same as [`try_split_at`](ArcSlice::try_split_at). It is provided to match the
+    /// name of `[T]::split_at_checked`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..);
+    ///
+    /// let (low, high) = Arcs::split_at_checked(&slice, 2).unwrap();
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// assert_eq!(Arcs::split_at_checked(&slice, 5), None);
+    /// ```
+    #[inline]
+    pub fn split_at_checked(it: &Self, mid: usize) -> Option<(Self, Self)> {
+        ArcSlice::try_split_at(it, mid)
+    }
+
+    /// Splits the slice at its midpoint. If the length is odd, the first half gets the extra
+    /// element. Both halves share the same buffer.
+    ///
+    /// ```
     /// # extern crate alloc;
     /// # use alloc::sync::Arc;
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..8);
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let (left, right) = Arcs::halve(&Arcs::from(buffer));
     ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    /// assert_eq!(*left, [2, 4, 6]);
+    /// assert_eq!(*right, [8, 10]);
+    /// ```
+    pub fn halve(it: &Self) -> (Self, Self) {
+        let len = ArcSlice::len(it);
+        ArcSlice::split_at(it, len - len / 2)
+    }
+
+    /// Divides the slice into `N` consecutive parts of nearly equal length. If the length isn't
+    /// a multiple of `N`, the earlier parts are one element longer than the later ones.
     ///
-    /// // Take the first two
-    /// assert_eq!(Arcs::retract(&mut slice, 2), Some([14, 16].as_slice()));
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    /// Each part is a new ArcSlice referencing the same buffer. This function DOES NOT DELETE
+    /// unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
     ///
-    /// // Take three more
-    /// assert_eq!(Arcs::retract(&mut slice, 3), Some([8, 10, 12].as_slice()));
-    /// assert_eq!(*slice, [4, 6]);
+    /// # Panics
     ///
-    /// // Try to take three, but can't. Slice is unchanged.
-    /// assert_eq!(Arcs::retract(&mut slice, 3), None);
+    /// Panics if `N` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let slice = Arcs::from(buffer);
+    ///
+    /// let [a, b, c] = Arcs::split_evenly(&slice);
+    /// assert_eq!(*a, [1, 2, 3]);
+    /// assert_eq!(*b, [4, 5]);
+    /// assert_eq!(*c, [6, 7]);
+    /// ```
+    pub fn split_evenly<const N: usize>(it: &Self) -> [Self; N] {
+        assert!(N != 0, "cannot split a slice into 0 parts");
+
+        let len = ArcSlice::len(it);
+        let (short_len, extra) = (len / N, len % N);
+        // The parts add up to `len`, so `start` never passes `end`.
+        let mut start = it.start;
+        core::array::from_fn(|i| {
+            let part_len = if i < extra { short_len + 1 } else { short_len };
+            let part = ArcSlice::from_bounds(&it.underlying, start, start + part_len);
+            start += part_len;
+            part
+        })
+    }
+
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the
+    /// end, like [`slice::rchunks`]. Each chunk is a slice of the same buffer. If the length
+    /// isn't a multiple of `chunk_size`, the last chunk (the front of the slice) is shorter.
+    ///
+    /// The iterator is double-ended, so `.rev()` yields the short chunk first, followed by full
+    /// chunks that line up with the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let slice = Arcs::from(buffer);
+    ///
+    /// let mut chunks = Arcs::rchunks(&slice, 3);
+    /// assert_eq!(*chunks.next().unwrap(), [5, 6, 7]);
+    /// assert_eq!(*chunks.next().unwrap(), [2, 3, 4]);
+    /// assert_eq!(*chunks.next().unwrap(), [1]);
+    /// assert_eq!(chunks.next(), None);
+    ///
+    /// let mut aligned = Arcs::rchunks(&slice, 3).rev();
+    /// assert_eq!(*aligned.next().unwrap(), [1]);
+    /// assert_eq!(*aligned.next().unwrap(), [2, 3, 4]);
+    /// ```
+    pub fn rchunks(it: &Self, chunk_size: usize) -> ArcRChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        ArcRChunks {
+            rest: it.clone(),
+            chunk_size,
+        }
+    }
+
+    /// This is an in-place version of [`try_split_at`](ArcSlice::try_split_at).
+    ///
+    /// If `mid` is valid, mutates `it` to the upper half, and returns the lower half.
+    /// Specifically, this will mutate the slice `it` to be `[mid, len)`, and returns the slice `[0, mid)`.
+    ///
+    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// The returned slice holds a new strong reference to the buffer, even if it is empty, so
+    /// this adds exactly one to the strong count when it succeeds.
+    ///  ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    ///
+    /// let (low, high) = Arcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// let other_high = Arcs::split_off_before(&mut slice, 2).unwrap();
+    /// assert_eq!(*other_high, [4, 6]);
+    /// assert_eq!(*slice, [8, 10]);
+    ///
+    /// assert_eq!(Arcs::split_off_before(&mut slice, 5), None);
+    /// assert_eq!(*slice, [8, 10]);
+    /// ```
+    pub fn split_off_before(it: &mut Self, index: usize) -> Option<Self> {
+        let cut = it.start.checked_add(index)?;
+
+        if cut <= it.end {
+            let front = ArcSlice::from_bounds(&it.underlying, it.start, cut);
+            it.start = cut;
+
+            Some(front)
+        } else {
+            None
+        }
+    }
+
+    /// This is an in-place version of [`try_split_at`](ArcSlice::try_split_at).
+    ///
+    /// If `mid` is valid, mutates `it` to the lower half, and returns the upper half.
+    /// Specifically, this will mutate the slice `it` to be `[0, mid)`, and returns the slice `[mid, len)`.
+    ///
+    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// The returned slice holds a new strong reference to the buffer, even if it is empty, so
+    /// this adds exactly one to the strong count when it succeeds.
+    ///
+    ///  ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    ///
+    /// let (low, high) = Arcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// let other_high = Arcs::split_off_after(&mut slice, 2).unwrap();
+    /// assert_eq!(*other_high, [8, 10]);
     /// assert_eq!(*slice, [4, 6]);
     ///
-    /// // Take the rest.
-    /// assert_eq!(Arcs::retract(&mut slice, 2), Some([4, 6].as_slice()));
-    /// assert_eq!(*slice, []);
+    /// assert_eq!(Arcs::split_off_after(&mut slice, 5), None);
+    /// assert_eq!(*slice, [4, 6]);
     /// ```
-    pub fn retract(it: &mut Self, decr: usize) -> Option<&[T::Item]> {
-        let cut = it.end.checked_sub(decr)?;
+    pub fn split_off_after(it: &mut Self, index: usize) -> Option<Self> {
+        let cut = it.start.checked_add(index)?;
 
-        if cut >= it.start {
-            let shed = it.underlying.get(cut..it.end)?;
+        if cut <= it.end {
+            let back = ArcSlice::from_bounds(&it.underlying, cut, it.end);
             it.end = cut;
 
-            Some(shed)
+            Some(back)
         } else {
             None
         }
     }
 
-    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
-    /// elements cut off by this operation.
-    ///
-    /// If the slice doesn't contain enough elements, returns all available elements.
+    /////////////////////////////////////////////
+    // Methods related to being a view of a
+    // larger container.
+    //
+
+    /// Returns the inner buffer.
+    pub fn inner(it: &Self) -> &Arc<T> {
+        &it.underlying
+    }
+
+    /// Returns a new handle to the inner buffer. This is the same as
+    /// `Arc::clone(ArcSlice::inner(it))`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let slice = Arcs::from_arc_range(Arc::new(vec![2, 4, 6, 8, 10]), 1..3);
+    /// let buffer = Arcs::to_inner(&slice);
+    ///
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// assert_eq!(Arc::strong_count(&buffer), 2);
+    /// ```
+    pub fn to_inner(it: &Self) -> Arc<T> {
+        Arc::clone(&it.underlying)
+    }
+
+    /// Returns the starting and ending indices of the view `it` within the underlying slice.
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    ///
+    /// assert_eq!(Arcs::bounds(&Arcs::new(&buffer, ..)), (0, 5));
+    /// assert_eq!(Arcs::bounds(&Arcs::new(&buffer, 1..3)), (1, 3));
+    /// ```
+    #[deprecated(since = "0.4.0", note = "Use `bounds_range` instead.")]
+    pub fn bounds(it: &Self) -> (usize, usize) {
+        (it.start, it.end)
+    }
+
+    /// Returns the range that this slice represents.
+    ///
+    ///  ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    ///
+    /// assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, ..)), 0..5);
+    /// assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, 1..3)), 1..3);
+    /// ```
+    pub fn bounds_range(it: &Self) -> Range<usize> {
+        it.start..it.end
+    }
+
+    /// Returns the range of memory addresses occupied by the elements of the slice, from the
+    /// first element to one past the last element. This is the same as `[T]::as_ptr_range`.
+    ///
+    /// This is useful for matching pointers handed back from FFI code to the slice they came
+    /// from. The pointers are only valid while the underlying buffer is alive, and must not be
+    /// used to mutate the buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..3);
+    ///
+    /// let range = Arcs::ptr_range(&slice);
+    /// assert_eq!(range.start, &buffer[1] as *const u8);
+    /// assert_eq!(range.end, &buffer[3] as *const u8);
+    /// assert!(range.contains(&(&buffer[2] as *const u8)));
+    /// ```
+    pub fn ptr_range(it: &Self) -> Range<*const T::Item> {
+        it.as_ref().as_ptr_range()
+    }
+
+    /// Returns the alignment of the elements of the slice, `core::mem::align_of::<T::Item>()`.
+    ///
+    /// A slice always starts a whole number of elements into its buffer, so the pointer to the
+    /// first element (and every other element) is always aligned to this value, no matter how
+    /// the slice was created or split.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u32]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 3..);
+    ///
+    /// assert_eq!(Arcs::data_align(&slice), 4);
+    /// assert_eq!(slice.as_ptr() as usize % Arcs::data_align(&slice), 0);
+    /// ```
+    pub fn data_align(_it: &Self) -> usize {
+        core::mem::align_of::<T::Item>()
+    }
+
+    /// Returns the first element of the slice without advancing past it, or `None` if the slice
+    /// is empty. Equivalent to `it.first()`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// while let Some(&next) = Arcs::peek(&slice) {
+    ///     if next > 4 {
+    ///         break;
+    ///     }
+    ///     Arcs::advance(&mut slice, 1);
+    /// }
+    /// assert_eq!(*slice, [6]);
+    /// ```
+    pub fn peek(it: &Self) -> Option<&T::Item> {
+        it.first()
+    }
+
+    /// Returns the first `n` elements of the slice without advancing past them, or `None` if the
+    /// slice has fewer than `n` elements.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6]);
+    /// let slice = Arcs::from(buffer);
+    ///
+    /// assert_eq!(Arcs::peek_n(&slice, 2), Some([2, 4].as_slice()));
+    /// assert_eq!(Arcs::peek_n(&slice, 4), None);
+    /// assert_eq!(*slice, [2, 4, 6]);
+    /// ```
+    pub fn peek_n(it: &Self, n: usize) -> Option<&[T::Item]> {
+        it.get(..n)
+    }
+
+    /// Returns a reference to the element at index `i` of the slice, or `None` if `i` is out of
+    /// bounds. The index is relative to the start of the slice, not the start of the buffer.
+    ///
+    /// This is the non-panicking form of `slice[i]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Arcs::get_index(&slice, 0), Some(&4));
+    /// assert_eq!(Arcs::get_index(&slice, 2), Some(&8));
+    /// assert_eq!(Arcs::get_index(&slice, 3), None);
+    /// ```
+    pub fn get_index(it: &Self, i: usize) -> Option<&T::Item> {
+        Self::as_slice(it).get(i)
+    }
+
+    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
+    /// elements cut off by this operation. The end of the slice is not affected.
+    ///
+    /// Returns `None` and leaves `self` unchanged if this operation would make the starting index
+    /// greater than the ending index.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Arcs::advance(&mut slice, 2), Some([4, 6].as_slice()));
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Arcs::advance(&mut slice, 3), Some([8, 10, 12].as_slice()));
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Try to take three, but can't. Slice is unchanged.
+    /// assert_eq!(Arcs::advance(&mut slice, 3), None);
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Take the rest.
+    /// assert_eq!(Arcs::advance(&mut slice, 2), Some([14, 16].as_slice()));
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
+        let cut = it.start.checked_add(incr)?;
+
+        if cut <= it.end {
+            let shed = it.underlying.get(it.start..cut)?;
+            it.start = cut;
+
+            Some(shed)
+        } else {
+            None
+        }
+    }
+
+    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
+    /// elements cut off by this operation. The end of the slice is not affected.
+    ///
+    /// If the slice doesn't contain enough elements, returns all available elements.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Arcs::saturating_advance(&mut slice, 2), [4, 6]);
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Arcs::saturating_advance(&mut slice, 3), [8, 10, 12]);
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Try to take three, but can only take 2.
+    /// assert_eq!(Arcs::saturating_advance(&mut slice, 3), [14, 16]);
+    /// assert_eq!(*slice, []);
+    ///
+    /// // Try to take two, but slice is empty.
+    /// assert_eq!(Arcs::saturating_advance(&mut slice, 2), []);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_advance(it: &mut Self, incr: usize) -> &[T::Item] {
+        let cut = usize::min(it.start.saturating_add(incr), it.end);
+
+        let shed = it.underlying.get_unchecked_by_invariant(it.start..cut);
+        it.start = cut;
+        shed
+    }
+
+    /// Like [`saturating_advance`](ArcSlice::saturating_advance), but returns the new bounds of
+    /// the slice instead of the cut-off elements. Because the result doesn't borrow the buffer,
+    /// the slice can be used mutably again right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Arcs::saturating_advance_to(&mut slice, 2), 3..4);
+    /// assert_eq!(*slice, [8]);
+    /// assert_eq!(Arcs::saturating_advance_to(&mut slice, 2), 4..4);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_advance_to(it: &mut Self, incr: usize) -> Range<usize> {
+        it.start = usize::min(it.start.saturating_add(incr), it.end);
+        it.start..it.end
+    }
+
+    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
+    /// elements cut off by this operation.
+    ///
+    /// Returns `None` and leaves `it` unchanged if this operation would make the ending index less
+    /// than the starting index.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Arcs::retract(&mut slice, 2), Some([14, 16].as_slice()));
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Arcs::retract(&mut slice, 3), Some([8, 10, 12].as_slice()));
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Try to take three, but can't. Slice is unchanged.
+    /// assert_eq!(Arcs::retract(&mut slice, 3), None);
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Take the rest.
+    /// assert_eq!(Arcs::retract(&mut slice, 2), Some([4, 6].as_slice()));
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn retract(it: &mut Self, decr: usize) -> Option<&[T::Item]> {
+        let cut = it.end.checked_sub(decr)?;
+
+        if cut >= it.start {
+            let shed = it.underlying.get(cut..it.end)?;
+            it.end = cut;
+
+            Some(shed)
+        } else {
+            None
+        }
+    }
+
+    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
+    /// elements cut off by this operation.
+    ///
+    /// If the slice doesn't contain enough elements, returns all available elements.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Arcs::saturating_retract(&mut slice, 2), [14, 16]);
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Arcs::saturating_retract(&mut slice, 3), [8, 10, 12]);
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Try to take three, but can only take 2.
+    /// assert_eq!(Arcs::saturating_retract(&mut slice, 3), [4, 6]);
+    /// assert_eq!(*slice, []);
+    ///
+    /// // Try to take two, but slice is empty.
+    /// assert_eq!(Arcs::saturating_retract(&mut slice, 2), []);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_retract(it: &mut Self, decr: usize) -> &[T::Item] {
+        let cut = usize::max(it.end.saturating_sub(decr), it.start);
+
+        let shed = it.underlying.get_unchecked_by_invariant(cut..it.end);
+        it.end = cut;
+        shed
+    }
+
+    /// Like [`saturating_retract`](ArcSlice::saturating_retract), but returns the new bounds of
+    /// the slice instead of the cut-off elements. Because the result doesn't borrow the buffer,
+    /// the slice can be used mutably again right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Arcs::saturating_retract_to(&mut slice, 2), 1..2);
+    /// assert_eq!(*slice, [4]);
+    /// assert_eq!(Arcs::saturating_retract_to(&mut slice, 2), 1..1);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_retract_to(it: &mut Self, decr: usize) -> Range<usize> {
+        it.end = usize::max(it.end.saturating_sub(decr), it.start);
+        it.start..it.end
+    }
+
+    /// WARNING: This function is unstable and may change or be removed in future versions!
+    ///
+    /// Increases the starting index of the slice past the leading elements for which `pred`
+    /// returns `true`. Returns the elements that were cut off, which may be empty.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::from(&b"  let x"[..]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// assert_eq!(Arcs::advance_while(&mut slice, |b| *b == b' '), b"  ");
+    /// assert_eq!(Arcs::advance_while(&mut slice, u8::is_ascii_alphabetic), b"let");
+    /// assert_eq!(Arcs::advance_while(&mut slice, u8::is_ascii_alphabetic), b"");
+    /// assert_eq!(*slice, *b" x");
+    /// ```
+    pub fn advance_while<F: FnMut(&T::Item) -> bool>(it: &mut Self, mut pred: F) -> &[T::Item] {
+        let count = it.iter().position(|x| !pred(x)).unwrap_or(it.len());
+        ArcSlice::saturating_advance(it, count)
+    }
+
+    /// Decreases the ending index of the slice past the trailing elements for which `pred`
+    /// returns `true`. Returns the elements that were cut off, which may be empty.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::from(&b"let x  "[..]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// assert_eq!(Arcs::retract_while(&mut slice, |b| *b == b' '), b"  ");
+    /// assert_eq!(Arcs::retract_while(&mut slice, |b| *b == b' '), b"");
+    /// assert_eq!(*slice, *b"let x");
+    /// ```
+    pub fn retract_while<F: FnMut(&T::Item) -> bool>(it: &mut Self, mut pred: F) -> &[T::Item] {
+        let count = it.iter().rev().position(|x| !pred(x)).unwrap_or(it.len());
+        ArcSlice::saturating_retract(it, count)
+    }
+
+    /// Creates a new ArcSlice of part of this slice, sharing the same buffer. The range is
+    /// relative to `it`, and is clamped to the bounds of `it`. Unlike
+    /// [`change_range`](ArcSlice::change_range), `it` is not modified.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14]);
+    /// let slice = Arcs::new(&buffer, 1..6);
+    ///
+    /// let inner = Arcs::subslice(&slice, 1..);
+    /// assert_eq!(*inner, [6, 8, 10, 12]);
+    /// assert_eq!(*Arcs::subslice(&inner, ..2), [6, 8]);
+    /// assert_eq!(*Arcs::subslice(&inner, 3..10), [12]);
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    /// ```
+    pub fn subslice<R: RangeBounds<usize>>(it: &Self, range: R) -> Self {
+        let range = ArcSlice::relative_range(it, range);
+        ArcSlice::from_bounds(&it.underlying, range.start, range.end)
+    }
+
+    /// Adjusts the range of the slice. Roughly equivalent to `ArcSlice::new(it.inner(), new_range)`,
+    /// but the change is made in-place.
+    ///
+    /// Returns the actual range of the new slice.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(Arcs::change_range(&mut slice, 3..), 3..9);
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16, 18]);
+    /// ```
+    pub fn change_range<R: RangeBounds<usize>>(it: &mut Self, new_range: R) -> Range<usize> {
+        let mut start = match new_range.start_bound() {
+            Bound::Excluded(x) => usize::min(x.saturating_add(1), it.underlying.len()),
+            Bound::Included(x) => usize::min(*x, it.underlying.len()),
+            Bound::Unbounded => 0,
+        };
+        let end = match new_range.end_bound() {
+            Bound::Excluded(x) => usize::min(*x, it.underlying.len()),
+            Bound::Included(x) => usize::min(x.saturating_add(1), it.underlying.len()),
+            Bound::Unbounded => it.underlying.len(),
+        };
+        start = usize::min(start, end);
+        it.start = start;
+        it.end = end;
+        start..end
+    }
+
+    /////////////////////////////////////////////
+    // Methods related to `Arc`
+
+    /// Returns a mutable reference into the given slice, if there are no other
+    /// ArcSlice pointers to anywhere else in the underlying array.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Arcs::new(&buffer, 1..8);
+    ///
+    /// // The original Arc buffer is still alive, so get_mut doesn't work.
+    /// assert_eq!(Arcs::get_mut(&mut slice), None);
+    ///
+    /// std::mem::drop(buffer);
+    /// // Now there's only one ArcSlice for this array, so get_mut is allowed.
+    /// assert_eq!(Arcs::get_mut(&mut slice), Some([4, 6, 8, 10, 12, 14, 16].as_mut_slice()));
+    ///
+    /// // We can use the mutable reference.
+    /// Arcs::get_mut(&mut slice).unwrap().reverse();
+    /// assert_eq!(*slice, [16, 14, 12, 10, 8, 6, 4]);
+    ///
+    /// // The mutation changed the original buffer (though we need to create a new reference to see it).
+    /// let buffer = Arc::clone(Arcs::inner(&slice));
+    /// assert_eq!(*buffer, [2, 16, 14, 12, 10, 8, 6, 4, 18]);
+    ///
+    /// // Drop the buffer Arc again.
+    /// std::mem::drop(buffer);
+    /// assert_eq!(Arcs::get_mut(&mut slice), Some([16, 14, 12, 10, 8, 6, 4].as_mut_slice()));
+    ///
+    /// // Disjoint ArcSlices still prevent mutation.
+    /// let (mut low, high) = Arcs::split_at(&slice, 4);
+    /// assert_eq!(*low, [16, 14, 12, 10]);
+    /// assert_eq!(*high, [8, 6, 4]);
+    /// assert_eq!(Arcs::get_mut(&mut low), None);
+    /// ```
+    pub fn get_mut(it: &mut Self) -> Option<&mut [T::Item]> {
+        let start = it.start;
+        let end = it.end;
+        Arc::get_mut(&mut it.underlying).and_then(|s| s.get_mut(start..end))
+    }
+
+    /// Calls `f` on the mutable elements of the slice, and returns its result, if there are no
+    /// other ArcSlice or Arc pointers to the buffer (see [`get_mut`](ArcSlice::get_mut)).
+    /// Otherwise, returns `None` without calling `f`.
+    ///
+    /// The mutable borrow ends with the closure, which makes it easy to pair a mutation with
+    /// bookkeeping (like setting a dirty flag) at the call site.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Arcs::modify(&mut slice, |s| s.len()), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// let mut dirty = false;
+    /// let old_first = Arcs::modify(&mut slice, |s| core::mem::replace(&mut s[0], 0));
+    /// dirty |= old_first.is_some();
+    /// assert_eq!(old_first, Some(4));
+    /// assert!(dirty);
+    /// assert_eq!(*slice, [0, 6, 8]);
+    /// ```
+    pub fn modify<R, F: FnOnce(&mut [T::Item]) -> R>(it: &mut Self, f: F) -> Option<R> {
+        ArcSlice::get_mut(it).map(f)
+    }
+
+    /// Like [`get_mut`](ArcSlice::get_mut), but returns the reason the slice can't be borrowed
+    /// mutably: [`MutError::Shared`] if there are other strong references to the buffer, or
+    /// [`MutError::HasWeak`] if there are only weak references.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::{MutError, ArcSlice};
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    /// assert_eq!(Arcs::get_mut_or(&mut slice), Err(MutError::Shared));
+    ///
+    /// let weak = Arc::downgrade(&buffer);
+    /// core::mem::drop(buffer);
+    /// assert_eq!(Arcs::get_mut_or(&mut slice), Err(MutError::HasWeak));
+    ///
+    /// core::mem::drop(weak);
+    /// Arcs::get_mut_or(&mut slice).unwrap().fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    /// ```
+    pub fn get_mut_or(it: &mut Self) -> Result<&mut [T::Item], MutError> {
+        // Only look at the counts after `get_mut` fails. Checking them first would race with
+        // other threads upgrading and dropping weak pointers in between.
+        if Arc::get_mut(&mut it.underlying).is_none() {
+            return if Arc::strong_count(&it.underlying) > 1 {
+                Err(MutError::Shared)
+            } else {
+                Err(MutError::HasWeak)
+            };
+        }
+        // Can't fail: nothing else can reach the buffer, so it stays unique.
+        Ok(ArcSlice::get_mut(it).unwrap())
+    }
+
+    /// Returns a mutable reference to the entire underlying container, if there are no other
+    /// ArcSlice or Arc pointers to it. Unlike [`get_mut`](ArcSlice::get_mut), this gives access to
+    /// elements outside of the slice, and allows the container itself to be modified.
+    ///
+    /// The bounds of `it` are not updated when the container is modified. If the container
+    /// becomes shorter than the end of the slice, the caller MUST fix the bounds (for example,
+    /// with [`change_range`](ArcSlice::change_range)) before using the slice again, or it will
+    /// panic.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..3);
+    ///
+    /// // The original Arc buffer is still alive.
+    /// assert_eq!(Arcs::get_mut_full(&mut slice), None);
+    /// std::mem::drop(buffer);
+    ///
+    /// // Refill the whole buffer with new data.
+    /// let container = Arcs::get_mut_full(&mut slice).unwrap();
+    /// container.clear();
+    /// container.extend([1, 3, 5, 7]);
+    /// assert_eq!(*slice, [3, 5]);
+    ///
+    /// // Shrinking the container requires fixing the bounds.
+    /// Arcs::get_mut_full(&mut slice).unwrap().truncate(2);
+    /// Arcs::change_range(&mut slice, 1..);
+    /// assert_eq!(*slice, [3]);
+    /// ```
+    pub fn get_mut_full(it: &mut Self) -> Option<&mut T> {
+        Arc::get_mut(&mut it.underlying)
+    }
+
+    /// Returns a mutable reference to part of the slice, if there are no other ArcSlice or Arc
+    /// pointers to the underlying buffer. The range is relative to the slice.
+    ///
+    /// Returns `None` if the buffer is shared, or if `range` is out of bounds for the slice.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// // The original Arc buffer is still alive.
+    /// assert_eq!(Arcs::get_range_mut(&mut slice, 1..), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// Arcs::get_range_mut(&mut slice, 1..).unwrap().fill(0);
+    /// assert_eq!(*slice, [4, 0, 0]);
+    ///
+    /// assert_eq!(Arcs::get_range_mut(&mut slice, 2..4), None);
+    /// ```
+    pub fn get_range_mut<R: RangeBounds<usize>>(it: &mut Self, range: R) -> Option<&mut [T::Item]> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        ArcSlice::get_mut(it)?.get_mut(range)
+    }
+
+    /// Creates a slice over `new_buffer` with the same range as `it`.
+    ///
+    /// Returns `None` if `new_buffer` is too short to contain the range.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let front: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let back: Arc<[u8]> = Arc::new([1, 3, 5, 7, 9]);
+    /// let slice = Arcs::new(&front, 1..4);
+    ///
+    /// let other = Arcs::with_buffer(&slice, &back).unwrap();
+    /// assert_eq!(*other, [3, 5, 7]);
+    /// assert_eq!(Arcs::bounds_range(&other), 1..4);
+    ///
+    /// let short: Arc<[u8]> = Arc::new([1, 3, 5]);
+    /// assert_eq!(Arcs::with_buffer(&slice, &short), None);
+    /// ```
+    pub fn with_buffer(it: &Self, new_buffer: &Arc<T>) -> Option<Self> {
+        if it.end > new_buffer.len() {
+            return None;
+        }
+        Some(ArcSlice::from_bounds(new_buffer, it.start, it.end))
+    }
+
+    /// Points the slice at `new_buffer`, keeping the same range, and returns the old buffer.
+    /// This repoints a view at a freshly-produced buffer (e.g. when double-buffering) without
+    /// building a new slice.
+    ///
+    /// If `new_buffer` is too short for the range, the range is clamped to fit it: the end is
+    /// reduced to the length of `new_buffer`, and the start is reduced to the end if needed. Unlike
+    /// [`with_buffer`](ArcSlice::with_buffer), this never fails.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let front: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5]);
+    /// let mut view = Arcs::new(&front, 1..4);
+    ///
+    /// let back: Arc<[u8]> = Arc::new([10, 20, 30, 40, 50]);
+    /// let old = Arcs::swap_buffer(&mut view, back);
+    /// assert!(Arc::ptr_eq(&old, &front));
+    /// assert_eq!(*view, [20, 30, 40]);
+    ///
+    /// // Shorter buffer: the range is clamped from 1..4 to 1..3.
+    /// Arcs::swap_buffer(&mut view, Arc::new([7, 8, 9]));
+    /// assert_eq!(*view, [8, 9]);
+    /// assert_eq!(Arcs::bounds_range(&view), 1..3);
+    /// ```
+    pub fn swap_buffer(it: &mut Self, new_buffer: Arc<T>) -> Arc<T> {
+        it.end = usize::min(it.end, new_buffer.len());
+        it.start = usize::min(it.start, it.end);
+        core::mem::replace(&mut it.underlying, new_buffer)
+    }
+
+    /// Replaces `it` with `new`, and returns the previous slice. This is [`core::mem::replace`],
+    /// provided alongside [`take`](ArcSlice::take).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut current = Arcs::new(&buffer, ..2);
+    ///
+    /// let previous = Arcs::replace(&mut current, Arcs::new(&buffer, 2..));
+    /// assert_eq!(*previous, [2, 4]);
+    /// assert_eq!(*current, [6, 8, 10]);
+    /// ```
+    pub fn replace(it: &mut Self, new: Self) -> Self {
+        core::mem::replace(it, new)
+    }
+
+    /// Retains only the elements of the slice for which `pred` returns `true`, passing a mutable
+    /// reference to each element in order. This is the slice equivalent of `Vec::retain_mut`.
+    ///
+    /// Only works if the buffer is shrinkable and there are no other ArcSlice or Arc pointers to
+    /// it; otherwise, returns `false` without calling `pred`. The kept elements are moved to the
+    /// front of the slice and the end of the slice is reduced, so the rejected elements stay in
+    /// the buffer until it is [`shrink`](ArcSlice::shrink)ed.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let mut slice = Arcs::new(&buffer, 1..5);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Arcs::retain_mut(&mut slice, |_| false), false);
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Arcs::retain_mut(&mut slice, |x| { *x *= 10; *x != 30 }), true);
+    /// assert_eq!(*slice, [20, 40, 50]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T::Item) -> bool>(it: &mut Self, mut pred: F) -> bool {
+        // This will be optimized away
+        if !T::IS_SHRINKABLE {
+            return false;
+        }
+
+        let Some(slice) = ArcSlice::get_mut(it) else {
+            return false;
+        };
+
+        let mut kept = 0;
+        for i in 0..slice.len() {
+            if pred(&mut slice[i]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+
+        it.end = it.start + kept;
+        true
+    }
+
+    /// Calls `f` on each window of `size` consecutive elements of the slice, as mutable
+    /// references, if there are no other ArcSlice or Arc pointers to the buffer. This is a mutable
+    /// version of `[T]::windows`.
+    ///
+    /// The windows are visited in order from left to right, so changes made in one call are
+    /// visible to the later calls whose windows overlap it. If the slice is shorter than `size`,
+    /// `f` is never called.
+    ///
+    /// Returns `false` without calling `f` if the buffer is shared, and `true` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u32]> = Arc::new([1, 2, 3, 4]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// // Turn the slice into its running sums.
+    /// Arcs::for_each_window_mut(&mut slice, 2, |window| window[1] += window[0]);
+    /// assert_eq!(*slice, [1, 3, 6, 10]);
+    /// ```
+    pub fn for_each_window_mut<F: FnMut(&mut [T::Item])>(
+        it: &mut Self,
+        size: usize,
+        mut f: F,
+    ) -> bool {
+        assert!(size != 0, "window size must be non-zero");
+
+        let Some(slice) = ArcSlice::get_mut(it) else {
+            return false;
+        };
+
+        // `len - size + 1` windows, without overflowing when `len` is `usize::MAX`.
+        if let Some(last) = slice.len().checked_sub(size) {
+            for start in 0..=last {
+                f(&mut slice[start..start + size]);
+            }
+        }
+        true
+    }
+
+    /// Checks if two ArcSlices reference the same slice of the same array in memory.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let first_slice = Arcs::new(&buffer, ..4);
+    /// let second_slice = Arcs::new(&buffer, ..8);
+    ///
+    /// // These slices point to the same buffer, but different ranges.
+    /// assert_eq!(Arcs::ptr_eq(&first_slice, &second_slice), false);
+    ///
+    /// let (low, _) = Arcs::split_at(&second_slice, 4);
+    /// assert_eq!(first_slice, low);
+    ///
+    /// // These slices use the same buffer, and the same range.
+    /// assert_eq!(Arcs::ptr_eq(&first_slice, &low), true);
+    ///
+    /// let other_buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let other_slice = Arcs::new(&other_buffer, ..4);
+    ///
+    /// // The elements for `other_slice` are the same
+    /// assert_eq!(first_slice, other_slice);
+    ///
+    /// // But they use different memory locations.
+    /// assert_eq!(Arcs::ptr_eq(&first_slice, &other_slice), false);
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Arc::ptr_eq(&this.underlying, &other.underlying)
+            && this.start == other.start
+            && this.end == other.end
+    }
+
+    /// Checks if `inner` is a view of the same buffer as `outer`, and is entirely contained within
+    /// `outer`. An empty slice is contained in `outer` if it is within or at the ends of `outer`.
+    ///
+    /// Always returns `false` if the slices use different buffers, even if the buffers are equal.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let outer = Arcs::new(&buffer, 2..6);
+    ///
+    /// assert!(Arcs::contains_slice(&outer, &Arcs::new(&buffer, 3..5)));
+    /// assert!(Arcs::contains_slice(&outer, &outer));
+    /// assert!(!Arcs::contains_slice(&outer, &Arcs::new(&buffer, 4..8)));
+    /// assert!(!Arcs::contains_slice(&Arcs::new(&buffer, 3..5), &outer));
+    ///
+    /// let other_buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// assert!(!Arcs::contains_slice(&outer, &Arcs::new(&other_buffer, 3..5)));
+    /// ```
+    pub fn contains_slice(outer: &Self, inner: &Self) -> bool {
+        Arc::ptr_eq(&outer.underlying, &inner.underlying)
+            && outer.start <= inner.start
+            && inner.end <= outer.end
+    }
+
+    /// Returns the range of `child` relative to the start of `parent`, if `child` is a view of
+    /// the same buffer and is entirely contained within `parent` (see
+    /// [`contains_slice`](ArcSlice::contains_slice)). Otherwise, returns `None`.
+    ///
+    /// This is like [`bounds_range`](ArcSlice::bounds_range), but in the coordinates of `parent`
+    /// instead of the buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let parent = Arcs::new(&buffer, 2..6);
+    ///
+    /// assert_eq!(Arcs::range_within(&Arcs::new(&buffer, 3..5), &parent), Some(1..3));
+    /// assert_eq!(Arcs::range_within(&parent, &parent), Some(0..4));
+    /// assert_eq!(Arcs::range_within(&Arcs::new(&buffer, 4..8), &parent), None);
+    /// ```
+    pub fn range_within(child: &Self, parent: &Self) -> Option<Range<usize>> {
+        if ArcSlice::contains_slice(parent, child) {
+            Some(child.start - parent.start..child.end - parent.start)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the parts of `parent` that aren't covered by any of `children`, as slices of the
+    /// same buffer, sorted by their start. Children may overlap or touch each other. This is the
+    /// [`ArcSlice`] version of [`RcSlice::gaps`](crate::RcSlice::gaps).
+    ///
+    /// Only children that use the same buffer as `parent` and are entirely within it (see
+    /// [`contains_slice`](ArcSlice::contains_slice)) are counted; the rest are ignored.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([0; 16]);
+    /// let arena = Arcs::new(&buffer, 2..14);
+    /// let used = [Arcs::new(&buffer, 8..10), Arcs::new(&buffer, 2..4), Arcs::new(&buffer, 3..5)];
+    ///
+    /// let free: Vec<_> = Arcs::gaps(&arena, &used).iter().map(Arcs::bounds_range).collect();
+    /// assert_eq!(free, [5..8, 10..14]);
+    /// ```
+    pub fn gaps(parent: &Self, children: &[Self]) -> Vec<Self> {
+        let mut covered: Vec<Range<usize>> = children
+            .iter()
+            .filter(|child| ArcSlice::contains_slice(parent, child))
+            .map(ArcSlice::bounds_range)
+            .collect();
+        covered.sort_unstable_by_key(|range| range.start);
+
+        let mut gaps = Vec::new();
+        let mut start = parent.start;
+        for range in covered {
+            if start < range.start {
+                gaps.push(ArcSlice::from_bounds(
+                    &parent.underlying,
+                    start,
+                    range.start,
+                ));
+            }
+            start = usize::max(start, range.end);
+        }
+        if start < parent.end {
+            gaps.push(ArcSlice::from_bounds(&parent.underlying, start, parent.end));
+        }
+        gaps
+    }
+
+    /// Consumes the slice and returns the underlying buffer, if this was the only strong
+    /// reference to it. Otherwise, the slice is dropped and `None` is returned.
+    ///
+    /// This is meant for returning buffers to a pool, so they can be sliced again later. Weak
+    /// references to the buffer are not affected.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..3);
+    ///
+    /// // `buffer` is still alive.
+    /// assert_eq!(Arcs::recycle(slice), None);
+    ///
+    /// let slice = Arcs::new(&buffer, 1..3);
+    /// core::mem::drop(buffer);
+    /// let buffer = Arcs::recycle(slice).unwrap();
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn recycle(it: Self) -> Option<Arc<T>> {
+        if Arc::strong_count(&it.underlying) == 1 {
+            Some(it.underlying)
+        } else {
+            None
+        }
+    }
+
+    /// Converts a range relative to the slice into a range of the underlying buffer, clamped
+    /// to the bounds of the slice.
+    fn relative_range<R: RangeBounds<usize>>(it: &Self, range: R) -> Range<usize> {
+        use Bound::*;
+        let mut start = match range.start_bound() {
+            Unbounded => it.start,
+            Included(s) => it.start.saturating_add(*s),
+            Excluded(s) => it.start.saturating_add(*s).saturating_add(1),
+        };
+        let end = match range.end_bound() {
+            Unbounded => it.end,
+            Included(s) => usize::min(it.end, it.start.saturating_add(*s).saturating_add(1)),
+            Excluded(s) => usize::min(it.end, it.start.saturating_add(*s)),
+        };
+        start = usize::min(start, end);
+        start..end
+    }
+}
+
+/// Iterator returned by [`ArcSlice::rchunks`]. Each chunk is a slice of the same buffer.
+pub struct ArcRChunks<T: ?Sized> {
+    /// The part of the slice that hasn't been split into chunks yet.
+    rest: ArcSlice<T>,
+    /// The length of every chunk, except possibly the one at the front.
+    chunk_size: usize,
+}
+
+impl<T: RcSliceContainer + ?Sized> Iterator for ArcRChunks<T> {
+    type Item = ArcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = ArcSlice::len(&self.rest);
+        if len == 0 {
+            return None;
+        }
+        ArcSlice::split_off_after(&mut self.rest, len.saturating_sub(self.chunk_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = ArcSlice::len(&self.rest).div_ceil(self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> DoubleEndedIterator for ArcRChunks<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = ArcSlice::len(&self.rest);
+        if len == 0 {
+            return None;
+        }
+        // The front chunk holds the remainder, or a full chunk if there isn't one.
+        let front_len = match len % self.chunk_size {
+            0 => self.chunk_size,
+            remainder => remainder,
+        };
+        ArcSlice::split_off_before(&mut self.rest, front_len)
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> ExactSizeIterator for ArcRChunks<T> {}
+
+impl<T: RcSliceContainer + ?Sized> FusedIterator for ArcRChunks<T> {}
+
+impl<T: RcSliceContainer + ?Sized> Clone for ArcRChunks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for ArcRChunks<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcRChunks")
+            .field("rest", &self.rest)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+impl<T: RcSliceContainer + Default> ArcSlice<T> {
+    /// Tries to reduce the size of the original buffer, if this is the only
+    /// Arc or ArcSlice referencing the buffer.
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    /// let weak_buffer = Arc::downgrade(&buffer);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(**Arcs::inner(&slice), [2, 4, 6, 8, 10, 12]);
+    ///
+    /// // Shrink fails: there are two strong references.
+    /// assert_eq!(Arcs::shrink(&mut slice), false);
+    ///
+    /// core::mem::drop(buffer);
+    ///
+    /// // Shrink successful: only one strong reference.
+    /// assert_eq!(Arcs::shrink(&mut slice), true);
+    ///
+    /// // The slice is unchanged, and the buffer has shrunk.
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(**Arcs::inner(&slice), [4, 6, 8]);
+    ///
+    /// // Weak references were not preserved. This behavior MAY be
+    /// // changed in a future version.
+    /// assert_eq!(weak_buffer.upgrade(), None);
+    /// ```
+    pub fn shrink(it: &mut Self) -> bool {
+        ArcSlice::try_shrink(it).is_ok()
+    }
+
+    /// Like [`shrink`](ArcSlice::shrink), but reports why the buffer couldn't be shrunk. On
+    /// success, returns the new range of the slice within the buffer.
+    ///
+    /// Some containers may decline to shrink even when uniquely owned (for example, a `SmallVec`
+    /// that is stored inline). In that case the buffer is left unchanged, and the current range
+    /// is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::{ArcSlice, ShrinkError};
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Arcs::try_shrink(&mut slice), Err(ShrinkError::Shared));
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Arcs::try_shrink(&mut slice), Ok(0..3));
+    /// assert_eq!(**Arcs::inner(&slice), [4, 6, 8]);
+    ///
+    /// assert_eq!(Arcs::try_shrink(&mut slice), Err(ShrinkError::AlreadyMinimal));
+    /// ```
+    pub fn try_shrink(it: &mut Self) -> Result<Range<usize>, ShrinkError> {
+        // This will be optimized away
+        if !T::IS_SHRINKABLE {
+            return Err(ShrinkError::NotShrinkable);
+        }
+
+        if it.start == 0 && it.end == it.underlying.len() {
+            return Err(ShrinkError::AlreadyMinimal);
+        }
+
+        let mut temp = Arc::new(T::default());
+        core::mem::swap(&mut temp, &mut it.underlying);
+        match Arc::try_unwrap(temp) {
+            Ok(mut container) => {
+                if let Some(new_range) = container.shrink_container_to_range(it.start..it.end) {
+                    debug_assert!(
+                        new_range.start <= new_range.end
+                            && new_range.len() == it.end - it.start
+                            && new_range.end <= container.len(),
+                        "shrink_container_to_range returned a range that does not match the kept elements"
+                    );
+                    it.start = new_range.start;
+                    it.end = new_range.end;
+                }
+                let mut temp2 = Arc::new(container);
+                core::mem::swap(&mut temp2, &mut it.underlying);
+                Ok(it.start..it.end)
+            }
+            Err(mut temp2) => {
+                core::mem::swap(&mut temp2, &mut it.underlying);
+                Err(ShrinkError::Shared)
+            }
+        }
+    }
+
+    /// Narrows the slice to `range` (relative to the current slice, and clamped to it), and then
+    /// tries to reduce the size of the original buffer to just that range. This is the same as
+    /// changing the range and then calling [`shrink`](ArcSlice::shrink), and returns the same
+    /// value as `shrink`.
+    ///
+    /// The slice is narrowed even if the buffer can't be shrunk.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Arcs::new(&buffer, 1..5);
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Arcs::shrink_to(&mut slice, 1..3), true);
+    /// assert_eq!(*slice, [6, 8]);
+    /// assert_eq!(**Arcs::inner(&slice), [6, 8]);
+    /// ```
+    pub fn shrink_to<R: RangeBounds<usize>>(it: &mut Self, range: R) -> bool {
+        let range = ArcSlice::relative_range(it, range);
+        it.start = range.start;
+        it.end = range.end;
+        ArcSlice::shrink(it)
+    }
+
+    /// Like [`split_off_before`](ArcSlice::split_off_before), but the front part is copied into a
+    /// new, independent buffer, and then `it` is [`shrink`](ArcSlice::shrink)ed so the original
+    /// buffer no longer holds the front part.
+    ///
+    /// If the original buffer is shared or can't be shrunk, it is left as-is (other references
+    /// still need those elements), and `it` is only narrowed. The returned slice always uses its
+    /// own buffer, so in that case the copy is wasted: the front part stays in the original
+    /// buffer anyway. The copy only frees memory when [`Arc::strong_count`] is 1 and
+    /// [`IS_SHRINKABLE`](RcSliceContainer::IS_SHRINKABLE) is true; otherwise, use
+    /// [`split_off_before`](ArcSlice::split_off_before) instead.
+    ///
+    /// Returns `None` and leaves `it` unchanged if `index` is outside the bounds of the slice.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// let front = Arcs::split_off_before_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(*front, [2, 4]);
+    /// assert_eq!(*slice, [6, 8, 10]);
+    ///
+    /// // The original buffer was shrunk to the tail.
+    /// assert_eq!(**Arcs::inner(&slice), [6, 8, 10]);
+    ///
+    /// assert_eq!(Arcs::split_off_before_owned(&mut slice, 4), None);
+    /// ```
+    pub fn split_off_before_owned(it: &mut Self, index: usize) -> Option<ArcSlice<[T::Item]>>
+    where
+        T::Item: Clone,
+    {
+        let front = ArcSlice::clone_detached(&ArcSlice::split_off_before(it, index)?);
+        ArcSlice::shrink(it);
+        Some(front)
+    }
+
+    /// Like [`get_mut`](ArcSlice::get_mut), but weak references to the buffer don't prevent
+    /// mutation. Returns `None` if there are other strong references to the buffer.
+    ///
+    /// `Arc::get_mut` refuses to hand out a mutable reference while weak references exist, since
+    /// they could be upgraded later. So when there are weak references, the container is moved
+    /// (not copied) into a new `Arc`, and the old weak references are disconnected: they will
+    /// never upgrade again, just as after [`shrink`](ArcSlice::shrink). This requires moving the
+    /// container out of the `Arc`, so it is only available for `Default` containers, and not
+    /// for unsized ones like `[T]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let observer = Arc::downgrade(&buffer);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    /// core::mem::drop(buffer);
+    ///
+    /// // The weak reference blocks `get_mut`, but not `get_mut_ignoring_weak`.
+    /// assert_eq!(Arcs::get_mut(&mut slice), None);
+    /// Arcs::get_mut_ignoring_weak(&mut slice).unwrap().fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    ///
+    /// // The weak reference was disconnected.
+    /// assert_eq!(observer.upgrade(), None);
+    /// ```
+    pub fn get_mut_ignoring_weak(it: &mut Self) -> Option<&mut [T::Item]> {
+        if Arc::get_mut(&mut it.underlying).is_none() {
+            // Don't allocate a placeholder just to find out the buffer is shared. The count can
+            // change on other threads, so `try_unwrap` is still the real check; a race only
+            // costs a spurious `None`.
+            if Arc::strong_count(&it.underlying) != 1 {
+                return None;
+            }
+            let temp = core::mem::replace(&mut it.underlying, Arc::new(T::default()));
+            match Arc::try_unwrap(temp) {
+                Ok(container) => it.underlying = Arc::new(container),
+                Err(temp) => {
+                    it.underlying = temp;
+                    return None;
+                }
+            }
+        }
+        ArcSlice::get_mut(it)
+    }
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> ArcSlice<T> {
+    /// Create a new ArcSlice over UTF-8 encoded bytes, like [`new`](ArcSlice::new), but returns
+    /// `None` if either end of the (clamped) range would split a multibyte character.
+    ///
+    /// The buffer itself is not validated; only the bytes at the boundaries of the range are
+    /// checked, using the same rule as [`str::is_char_boundary`].
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use alloc::sync::Arc;
+    /// use ArcSlice as Arcs;
+    ///
+    /// // 'é' is two bytes long.
+    /// let buffer: Arc<[u8]> = Arc::from("héllo".as_bytes());
+    ///
+    /// assert_eq!(*Arcs::new_utf8(&buffer, 1..3).unwrap(), *"é".as_bytes());
+    /// assert_eq!(*Arcs::new_utf8(&buffer, 3..).unwrap(), *b"llo");
+    ///
+    /// // Both of these ranges cut 'é' in half.
+    /// assert_eq!(Arcs::new_utf8(&buffer, 2..), None);
+    /// assert_eq!(Arcs::new_utf8(&buffer, ..2), None);
+    /// ```
+    pub fn new_utf8<R: RangeBounds<usize>>(underlying: &Arc<T>, range: R) -> Option<Self> {
+        let slice = ArcSlice::new(underlying, range);
+        let bytes = underlying.get(0..underlying.len())?;
+        let is_char_boundary = |index: usize| match bytes.get(index) {
+            // UTF-8 continuation bytes all look like `0b10xx_xxxx`.
+            Some(&byte) => (byte as i8) >= -0x40,
+            None => index == bytes.len(),
+        };
+
+        if is_char_boundary(slice.start) && is_char_boundary(slice.end) {
+            Some(slice)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the lines of the slice, as slices of the same buffer.
+    ///
+    /// Lines are split on `\n`, and a `\r` just before the `\n` is removed, like
+    /// [`str::lines`]. The final line does not need to end with a newline, and a newline at the
+    /// very end of the slice does not produce an extra empty line.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::from(&b"first\r\nsecond\n\nlast"[..]);
+    /// let slice = Arcs::new(&buffer, ..);
+    ///
+    /// let mut lines = Arcs::lines(&slice);
+    /// assert_eq!(*lines.next().unwrap(), *b"first");
+    /// assert_eq!(*lines.next().unwrap(), *b"second");
+    /// assert_eq!(*lines.next().unwrap(), *b"");
+    /// assert_eq!(*lines.next().unwrap(), *b"last");
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    pub fn lines(it: &Self) -> ArcLines<T> {
+        ArcLines { rest: it.clone() }
+    }
+
+    /// Returns an iterator over the characters of the slice and their byte offsets, like
+    /// [`str::char_indices`], or `None` if the slice is not valid UTF-8.
+    ///
+    /// Offsets are relative to the start of the slice, not the start of the buffer, so they can
+    /// be passed straight to [`split_at`](ArcSlice::split_at) or
+    /// [`subslice`](ArcSlice::subslice) to get a slice of the same buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::from("añb€".as_bytes());
+    /// let slice = Arcs::new(&buffer, 1..);
+    ///
+    /// let chars: Vec<_> = Arcs::char_indices(&slice).unwrap().collect();
+    /// assert_eq!(chars, [(0, 'ñ'), (2, 'b'), (3, '€')]);
+    /// assert_eq!(*Arcs::subslice(&slice, 3..), *"€".as_bytes());
+    ///
+    /// // Cuts 'ñ' in half.
+    /// assert!(Arcs::char_indices(&Arcs::new(&buffer, 2..)).is_none());
+    /// ```
+    pub fn char_indices(it: &Self) -> Option<CharIndices<'_>> {
+        core::str::from_utf8(ArcSlice::as_slice(it))
+            .ok()
+            .map(str::char_indices)
+    }
+
+    /// Returns a value whose `Debug` output shows the slice as an escaped byte string, instead
+    /// of a list of numbers. Printable ASCII is shown as-is, and other bytes are escaped like
+    /// [`u8::escape_ascii`] does (for example `\n` or `\xff`).
+    ///
+    /// This doesn't change the slice's own `Debug` output, which is still a list of numbers.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::from(&b"GET /\r\n\x00\xff"[..]);
+    /// let slice = Arcs::from(buffer);
+    ///
+    /// assert_eq!(format!("{:?}", Arcs::debug_ascii(&slice)), r#""GET /\r\n\x00\xff""#);
+    /// ```
+    pub fn debug_ascii(it: &Self) -> impl fmt::Debug + '_ {
+        DebugAscii(ArcSlice::as_slice(it))
+    }
+
+    /// Copies the first `N` bytes of the slice, or returns `None` if it is shorter than that.
+    fn peek_array<const N: usize>(it: &Self) -> Option<[u8; N]> {
+        ArcSlice::peek_n(it, N).map(|bytes| bytes.try_into().unwrap())
+    }
+
+    /// Reads a little-endian `u16` from the first 2 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 2 bytes.
+    ///
+    /// There are `read_*` functions for `u16`, `u32` and `u64`, in both byte orders. To advance
+    /// past the value as well, use the matching `take_*` function, like
+    /// [`take_u16_le`](ArcSlice::take_u16_le).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([0xff, 0x34, 0x12]);
+    /// let slice = Arcs::new(&buffer, 1..);
+    ///
+    /// assert_eq!(Arcs::read_u16_le(&slice), Some(0x1234));
+    /// assert_eq!(Arcs::read_u16_be(&slice), Some(0x3412));
+    /// assert_eq!(Arcs::read_u32_le(&slice), None);
+    /// ```
+    pub fn read_u16_le(it: &Self) -> Option<u16> {
+        ArcSlice::peek_array(it).map(u16::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u16` from the first 2 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 2 bytes.
+    pub fn read_u16_be(it: &Self) -> Option<u16> {
+        ArcSlice::peek_array(it).map(u16::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u32` from the first 4 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 4 bytes.
+    pub fn read_u32_le(it: &Self) -> Option<u32> {
+        ArcSlice::peek_array(it).map(u32::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u32` from the first 4 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 4 bytes.
+    pub fn read_u32_be(it: &Self) -> Option<u32> {
+        ArcSlice::peek_array(it).map(u32::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u64` from the first 8 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 8 bytes.
+    pub fn read_u64_le(it: &Self) -> Option<u64> {
+        ArcSlice::peek_array(it).map(u64::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u64` from the first 8 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 8 bytes.
+    pub fn read_u64_be(it: &Self) -> Option<u64> {
+        ArcSlice::peek_array(it).map(u64::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u16` from the first 2 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 2 bytes.
+    ///
+    /// There are `take_*` functions for `u16`, `u32` and `u64`, in both byte orders.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// // A 2-byte tag and a 4-byte length, both little-endian.
+    /// let buffer: Arc<[u8]> = Arc::new([0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0xaa]);
+    /// let mut header = Arcs::new(&buffer, ..);
+    ///
+    /// assert_eq!(Arcs::take_u16_le(&mut header), Some(1));
+    /// assert_eq!(Arcs::take_u32_le(&mut header), Some(16));
+    /// assert_eq!(Arcs::take_u16_le(&mut header), None);
+    /// assert_eq!(*header, [0xaa]);
+    /// ```
+    pub fn take_u16_le(it: &mut Self) -> Option<u16> {
+        let value = ArcSlice::read_u16_le(it)?;
+        ArcSlice::advance(it, 2);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u16` from the first 2 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 2 bytes.
+    pub fn take_u16_be(it: &mut Self) -> Option<u16> {
+        let value = ArcSlice::read_u16_be(it)?;
+        ArcSlice::advance(it, 2);
+        Some(value)
+    }
+
+    /// Reads a little-endian `u32` from the first 4 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 4 bytes.
+    pub fn take_u32_le(it: &mut Self) -> Option<u32> {
+        let value = ArcSlice::read_u32_le(it)?;
+        ArcSlice::advance(it, 4);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u32` from the first 4 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 4 bytes.
+    pub fn take_u32_be(it: &mut Self) -> Option<u32> {
+        let value = ArcSlice::read_u32_be(it)?;
+        ArcSlice::advance(it, 4);
+        Some(value)
+    }
+
+    /// Reads a little-endian `u64` from the first 8 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 8 bytes.
+    pub fn take_u64_le(it: &mut Self) -> Option<u64> {
+        let value = ArcSlice::read_u64_le(it)?;
+        ArcSlice::advance(it, 8);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u64` from the first 8 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 8 bytes.
+    pub fn take_u64_be(it: &mut Self) -> Option<u64> {
+        let value = ArcSlice::read_u64_be(it)?;
+        ArcSlice::advance(it, 8);
+        Some(value)
+    }
+}
+
+/// Iterator returned by [`ArcSlice::lines`]. Each line is a slice of the same buffer.
+pub struct ArcLines<T: ?Sized> {
+    /// The part of the slice that hasn't been split into lines yet.
+    rest: ArcSlice<T>,
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> Iterator for ArcLines<T> {
+    type Item = ArcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if ArcSlice::is_empty(&self.rest) {
+            return None;
+        }
+
+        let line_len = self
+            .rest
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .unwrap_or(ArcSlice::len(&self.rest));
+        let mut line = ArcSlice::split_off_before(&mut self.rest, line_len)?;
+        // Skip the newline and the carriage return before it, if there is one.
+        if ArcSlice::advance(&mut self.rest, 1).is_some() && line.last() == Some(&b'\r') {
+            ArcSlice::retract(&mut line, 1);
+        }
+
+        Some(line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every line except the last one uses up at least one byte for its newline, and the
+        // last line is only produced if there is at least one byte left.
+        let len = ArcSlice::len(&self.rest);
+        (usize::min(len, 1), Some(len))
+    }
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> FusedIterator for ArcLines<T> {}
+
+impl<T: RcSliceContainer + ?Sized> Clone for ArcLines<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for ArcLines<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcLines")
+            .field("rest", &self.rest)
+            .finish()
+    }
+}
+
+/// Debug formatter returned by [`ArcSlice::debug_ascii`].
+struct DebugAscii<'a>(&'a [u8]);
+
+impl fmt::Debug for DebugAscii<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0.escape_ascii())
+    }
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    /// Copies the elements of the slice into a new buffer, and returns a slice of the whole
+    /// new buffer. Unlike [`clone`](Clone::clone), the result does not share memory with `it`,
+    /// so it can be mutated right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// let mut detached = Arcs::clone_detached(&slice);
+    /// assert_eq!(*detached, *slice);
+    /// assert_eq!(**Arcs::inner(&detached), [4, 6, 8]);
+    ///
+    /// // The original buffer is still shared, but the copy is not.
+    /// Arcs::get_mut(&mut detached).unwrap()[0] = 5;
+    /// assert_eq!(*detached, [5, 6, 8]);
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// ```
+    pub fn clone_detached(it: &Self) -> ArcSlice<[T::Item]> {
+        let buffer: Arc<[T::Item]> = Arc::from(it.as_ref());
+        ArcSlice::from(buffer)
+    }
+
+    /// Joins several slices together into a new buffer, placing a copy of `sep` between each
+    /// of them. Returns a slice of the whole new buffer. This is the same as `[T]::join`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([1, 2, 3]);
+    /// let parts = [
+    ///     Arcs::new(&buffer, 0..1),
+    ///     Arcs::new(&buffer, 1..2),
+    ///     Arcs::new(&buffer, 2..3),
+    /// ];
+    ///
+    /// assert_eq!(*Arcs::join(&parts, &[0]), [1, 0, 2, 0, 3]);
+    /// assert_eq!(*Arcs::join(&parts, &[]), [1, 2, 3]);
+    /// assert_eq!(*Arcs::join(&parts[..1], &[0]), [1]);
+    /// assert_eq!(*Arcs::<[u8]>::join(&[], &[0]), []);
+    /// ```
+    pub fn join(slices: &[Self], sep: &[T::Item]) -> ArcSlice<[T::Item]> {
+        let joined: Vec<T::Item> = slices.join(sep);
+        ArcSlice::from(Arc::<[T::Item]>::from(joined))
+    }
+
+    /// Creates a new slice containing a copy of the elements of `a` followed by the elements of
+    /// `b`, in a new buffer. The slices don't need to share a buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let header: Arc<[u8]> = Arc::new([0xff, 1, 2]);
+    /// let body: Arc<[u8]> = Arc::new([3, 4, 5, 0xff]);
+    ///
+    /// let message = Arcs::append(&Arcs::new(&header, 1..), &Arcs::new(&body, ..3));
+    /// assert_eq!(*message, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn append(a: &Self, b: &Self) -> ArcSlice<[T::Item]> {
+        let buffer: Arc<[T::Item]> = a.iter().chain(b.iter()).cloned().collect();
+        ArcSlice::from(buffer)
+    }
+
+    /// Creates a new buffer containing the elements of the slice followed by the items of
+    /// `extra`, and returns a slice of all of it. This is the copying counterpart of
+    /// `Vec::extend`; the original buffer is not changed.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..3);
+    ///
+    /// let grown = Arcs::with_appended(&slice, [12, 14]);
+    /// assert_eq!(*grown, [4, 6, 12, 14]);
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn with_appended<I: IntoIterator<Item = T::Item>>(
+        it: &Self,
+        extra: I,
+    ) -> ArcSlice<[T::Item]> {
+        it.iter().cloned().chain(extra).collect()
+    }
+
+    /// Creates a new buffer containing the items of `prefix` followed by the elements of the
+    /// slice, and returns a slice of all of it. The original buffer is not changed.
+    ///
+    /// The prefix is collected first, so that the rest of the space can be reserved in one go.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let payload: Arc<[u8]> = Arc::from(&b"hello"[..]);
+    /// let body = Arcs::from(payload);
+    ///
+    /// let packet = Arcs::with_prepended(&body, [0xAB, body.len() as u8]);
+    /// assert_eq!(*packet, *b"\xAB\x05hello");
+    /// ```
+    pub fn with_prepended<I: IntoIterator<Item = T::Item>>(
+        it: &Self,
+        prefix: I,
+    ) -> ArcSlice<[T::Item]> {
+        let mut buffer: Vec<T::Item> = prefix.into_iter().collect();
+        buffer.reserve_exact(ArcSlice::len(it));
+        buffer.extend_from_slice(it);
+        ArcSlice::from(Arc::<[T::Item]>::from(buffer))
+    }
+
+    /// Splits off the first `n` elements into a new, independent buffer, and advances `it` past
+    /// them. Returns `None` and leaves `it` unchanged if `n` is greater than the length of the
+    /// slice.
+    ///
+    /// Unlike [`split_off_before_owned`](ArcSlice::split_off_before_owned), the rest of the slice
+    /// stays a view of the original buffer, which is never shrunk or copied. Only the prefix is
+    /// copied, so it can be stored without keeping the original buffer alive.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([3, 1, 2, 3, 9, 9]);
+    /// let mut stream = Arcs::from(buffer.clone());
+    ///
+    /// let header = Arcs::take_prefix(&mut stream, 4).unwrap();
+    /// assert_eq!(*header, [3, 1, 2, 3]);
+    /// assert_eq!(*stream, [9, 9]);
+    ///
+    /// assert!(!Arc::ptr_eq(Arcs::inner(&header), &buffer));
+    /// assert!(Arc::ptr_eq(Arcs::inner(&stream), &buffer));
+    /// assert_eq!(Arcs::take_prefix(&mut stream, 3), None);
+    /// ```
+    pub fn take_prefix(it: &mut Self, n: usize) -> Option<ArcSlice<[T::Item]>> {
+        ArcSlice::advance(it, n).map(ArcSlice::from)
+    }
+
+    /// This is the same as [`advance`](ArcSlice::advance), but returns a copy of the elements that
+    /// were cut off. Unlike `advance`, the result doesn't borrow `it`, so `it` can be modified
+    /// again while the result is still in use.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, ..);
+    ///
+    /// let first = Arcs::advance_owned(&mut slice, 2).unwrap();
+    /// let second = Arcs::advance_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(first, [2, 4]);
+    /// assert_eq!(second, [6, 8]);
+    ///
+    /// assert_eq!(Arcs::advance_owned(&mut slice, 2), None);
+    /// assert_eq!(*slice, [10]);
+    /// ```
+    pub fn advance_owned(it: &mut Self, incr: usize) -> Option<Vec<T::Item>> {
+        ArcSlice::advance(it, incr).map(<[T::Item]>::to_vec)
+    }
+
+    /// This is the same as [`retract`](ArcSlice::retract), but returns a copy of the elements that
+    /// were cut off. Unlike `retract`, the result doesn't borrow `it`, so `it` can be modified
+    /// again while the result is still in use.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, ..);
+    ///
+    /// let last = Arcs::retract_owned(&mut slice, 2).unwrap();
+    /// let second_last = Arcs::retract_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(last, [8, 10]);
+    /// assert_eq!(second_last, [4, 6]);
+    ///
+    /// assert_eq!(Arcs::retract_owned(&mut slice, 2), None);
+    /// assert_eq!(*slice, [2]);
+    /// ```
+    pub fn retract_owned(it: &mut Self, decr: usize) -> Option<Vec<T::Item>> {
+        ArcSlice::retract(it, decr).map(<[T::Item]>::to_vec)
+    }
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Copy,
+{
+    /// Copies the elements of the slice into an array, if the slice is exactly `N` elements long.
+    /// Returns `None` otherwise.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let packet: Arc<[u8]> = Arc::new([0x45, 0x00, 192, 168, 0, 1, 0xff]);
+    /// let address = Arcs::new(&packet, 2..6);
+    ///
+    /// assert_eq!(Arcs::try_into_array(&address), Some([192, 168, 0, 1]));
+    /// assert_eq!(Arcs::try_into_array::<6>(&address), None);
+    /// ```
+    pub fn try_into_array<const N: usize>(it: &Self) -> Option<[T::Item; N]> {
+        <[T::Item; N]>::try_from(it.as_ref()).ok()
+    }
+
+    /// Copies the slice into arrays of `N` elements, starting from the front. The elements that
+    /// don't fill a whole array are returned as a slice of the same buffer, rather than copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let samples: Arc<[i16]> = Arc::new([0, 1, -1, 2, -2, 3, -3, 4]);
+    /// let slice = Arcs::new(&samples, 1..);
+    ///
+    /// let (frames, rest) = Arcs::as_chunks::<3>(&slice);
+    /// assert_eq!(frames, [[1, -1, 2], [-2, 3, -3]]);
+    /// assert_eq!(*rest, [4]);
+    /// assert_eq!(Arcs::bounds_range(&rest), 7..8);
+    /// ```
+    pub fn as_chunks<const N: usize>(it: &Self) -> (Vec<[T::Item; N]>, Self) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let full = ArcSlice::len(it) - ArcSlice::len(it) % N;
+        let chunks = it[..full]
+            .chunks_exact(N)
+            .map(|chunk| <[T::Item; N]>::try_from(chunk).unwrap())
+            .collect();
+        // `full <= len`, so `start + full` is at most `end`.
+        let rest = ArcSlice::from_bounds(&it.underlying, it.start + full, it.end);
+        (chunks, rest)
+    }
+
+    /// Copies all of the elements of `src` into the slice, if there are no other ArcSlice or Arc
+    /// pointers to the buffer (see [`get_mut`](ArcSlice::get_mut)). Returns `false` without
+    /// copying anything if the buffer is shared.
+    ///
+    /// This is for refilling a buffer that is reused, such as one taken from a pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is not the same length as the slice, like [`slice::copy_from_slice`],
+    /// even if the buffer is shared.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([0; 6]);
+    /// let mut frame = Arcs::new(&buffer, 2..);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert!(!Arcs::copy_from_slice(&mut frame, &[1, 2, 3, 4]));
+    /// core::mem::drop(buffer);
+    ///
+    /// assert!(Arcs::copy_from_slice(&mut frame, &[1, 2, 3, 4]));
+    /// assert_eq!(**Arcs::inner(&frame), [0, 0, 1, 2, 3, 4]);
+    /// ```
+    pub fn copy_from_slice(it: &mut Self, src: &[T::Item]) -> bool {
+        assert_eq!(
+            ArcSlice::len(it),
+            src.len(),
+            "source slice length does not match the slice length"
+        );
+        match ArcSlice::get_mut(it) {
+            Some(slice) => {
+                slice.copy_from_slice(src);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Ord,
+{
+    /// Compares the elements of two slices lexicographically, exactly like `[T]::cmp`. This is
+    /// what the `Ord` impl for ArcSlice uses.
+    ///
+    /// Only the elements within each view are compared; the rest of the underlying buffers
+    /// are ignored. If one slice is a prefix of the other, the shorter slice is less.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use core::cmp::Ordering;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 1, 3]);
+    ///
+    /// let a = Arcs::new(&buffer, 0..2);
+    /// let b = Arcs::new(&buffer, 0..3);
+    /// let c = Arcs::new(&buffer, 3..5);
+    ///
+    /// assert_eq!(Arcs::cmp(&a, &b), Ordering::Less);
+    /// assert_eq!(Arcs::cmp(&b, &c), Ordering::Less);
+    /// assert_eq!(Arcs::cmp(&c, &a), Ordering::Greater);
+    /// assert_eq!(Arcs::cmp(&a, &a), Ordering::Equal);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn cmp(a: &Self, b: &Self) -> Ordering {
+        a.as_ref().cmp(b.as_ref())
+    }
+
+    /// Returns a reference to the largest element of the slice, or `None` if it is empty. If
+    /// several elements are equally large, the first one is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([9, 2, 7, 4, 0]);
+    ///
+    /// assert_eq!(Arcs::max(&Arcs::new(&buffer, 1..)), Some(&7));
+    /// assert_eq!(Arcs::max(&Arcs::new(&buffer, 5..)), None);
+    /// ```
+    pub fn max(it: &Self) -> Option<&T::Item> {
+        it.iter().reduce(|max, x| if x > max { x } else { max })
+    }
+
+    /// Returns a reference to the smallest element of the slice, or `None` if it is empty. If
+    /// several elements are equally small, the first one is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([9, 2, 7, 4, 0]);
+    ///
+    /// assert_eq!(Arcs::min(&Arcs::new(&buffer, ..4)), Some(&2));
+    /// assert_eq!(Arcs::min(&Arcs::new(&buffer, 5..)), None);
+    /// ```
+    pub fn min(it: &Self) -> Option<&T::Item> {
+        it.iter().reduce(|min, x| if x < min { x } else { min })
+    }
+}
+
+#[test]
+fn test_recycle_pool() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use ArcSlice as Arcs;
+
+    let mut pool: Vec<Arc<Vec<u8>>> = vec![Arc::new(vec![0; 4]), Arc::new(vec![1; 4])];
+
+    // Take a buffer out of the pool, and hand out slices of it.
+    let buffer = pool.pop().unwrap();
+    let (low, high) = Arcs::split_at(&Arcs::from(buffer), 2);
+    assert_eq!(*low, [1, 1]);
+
+    // The buffer can't be recycled while another slice is alive.
+    assert!(Arcs::recycle(low).is_none());
+    assert_eq!(pool.len(), 1);
+
+    // The last slice returns the buffer to the pool.
+    pool.push(Arcs::recycle(high).unwrap());
+    assert_eq!(pool.len(), 2);
+
+    // The recycled buffer can be sliced again.
+    let buffer = pool.pop().unwrap();
+    assert_eq!(Arc::strong_count(&buffer), 1);
+    assert_eq!(*Arcs::new(&buffer, 1..3), [1, 1]);
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Hash,
+{
+    /// An alias of the `Hash` impl for ArcSlice: hashes the length of the slice, followed by each
+    /// element, like `[T]` and `Vec<T>`. It only needs the elements to be `Hash`, not the
+    /// container.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// use core::hash::{Hash, Hasher};
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..3);
+    ///
+    /// let mut slice_hasher = DefaultHasher::new();
+    /// Arcs::hash_with_len(&slice, &mut slice_hasher);
+    ///
+    /// let mut array_hasher = DefaultHasher::new();
+    /// [4u8, 6].as_slice().hash(&mut array_hasher);
+    ///
+    /// assert_eq!(slice_hasher.finish(), array_hasher.finish());
+    /// ```
+    pub fn hash_with_len<H: Hasher>(it: &Self, state: &mut H) {
+        it.as_ref().hash(state)
+    }
+}
+
+#[test]
+fn test_send_and_sync() {
+    fn assert_send_sync<T: Send + Sync>() {}
+
+    assert_send_sync::<ArcSlice<[u8]>>();
+    assert_send_sync::<ArcSlice<alloc::vec::Vec<u8>>>();
+}
+
+impl<T> ArcSlice<[T]> {
+    /// Creates a slice of `len` elements in a new buffer, where each element is `f(i)` for its
+    /// index `i`. This is like `core::array::from_fn`.
+    ///
+    /// ```
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let squares = Arcs::from_fn(5, |i| (i * i) as u8);
+    /// assert_eq!(*squares, [0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Self {
+        let buffer: Arc<[T]> = (0..len).map(f).collect();
+        ArcSlice::from(buffer)
+    }
+}
+
+impl<T: Clone> ArcSlice<[T]> {
+    /// Creates a slice covering a copy of `data`.
+    ///
+    /// An `Arc` must own its buffer, so the elements are cloned once into a new atomically
+    /// reference-counted buffer; slicing the result afterward does not copy anything. When `data`
+    /// is empty, no elements are cloned, but a (zero-length) buffer is still allocated.
+    ///
+    /// ```
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// static SQUARES: [u32; 6] = [0, 1, 4, 9, 16, 25];
+    ///
+    /// let table = Arcs::from_static(&SQUARES);
+    /// let (low, high) = Arcs::split_at(&table, 3);
+    /// assert_eq!(*low, [0, 1, 4]);
+    /// assert_eq!(*high, [9, 16, 25]);
+    /// ```
+    pub fn from_static(data: &'static [T]) -> Self {
+        ArcSlice::from(Arc::<[T]>::from(data))
+    }
+}
+
+impl<T: RcSliceContainer + Clone> ArcSlice<T> {
+    /// Creates an [`RcSlice`] with the same range as `it`, over a copy of the whole underlying
+    /// buffer.
+    ///
+    /// This always clones the container, even if `it` is the only reference to it. `Rc` and
+    /// `Arc` store their reference counts differently, so an `Arc` allocation can never be reused
+    /// by an `Rc`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::{ArcSlice, RcSlice};
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// let local = Arcs::to_rc_slice(&slice);
+    /// assert_eq!(*local, [4, 6, 8]);
+    /// assert_eq!(RcSlice::bounds_range(&local), 1..4);
+    /// ```
+    pub fn to_rc_slice(it: &Self) -> RcSlice<T> {
+        RcSlice::new(&Rc::new(T::clone(&it.underlying)), it.start..it.end)
+    }
+
+    /// Returns a mutable reference into the given slice, copying the underlying container into a
+    /// new buffer first if needed. Unlike [`get_mut`](ArcSlice::get_mut), this never fails.
+    ///
+    /// This is [`Arc::make_mut`] for slices: if there are other strong references to the buffer,
+    /// the whole container is cloned, and `it` is moved to the copy with the same range. If
+    /// there are only weak references, the container is moved (not cloned) into a new `Arc`, and
+    /// the weak references are disconnected.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    ///
+    /// Arcs::try_make_mut(&mut slice).fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    /// assert_eq!(**Arcs::inner(&slice), [2, 0, 0, 0, 10]);
+    ///
+    /// // The original buffer is unchanged.
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn try_make_mut(it: &mut Self) -> &mut [T::Item] {
+        Arc::make_mut(&mut it.underlying)
+            .get_mut(it.start..it.end)
+            .unwrap()
+    }
+
+    /// Makes sure `it` is the only reference to its buffer, so that a batch of later calls to
+    /// [`get_mut`](ArcSlice::get_mut) or [`get_mut_full`](ArcSlice::get_mut_full) always succeed.
+    ///
+    /// If there are other strong references, the whole container is cloned into a new buffer,
+    /// and `it` keeps the same range into the copy, so elements before and after the slice can
+    /// still be reached with [`change_range`](ArcSlice::change_range). If there are only weak
+    /// references, the container is moved instead, and the weak references are disconnected.
+    /// This is the same copy [`try_make_mut`](ArcSlice::try_make_mut) makes, without borrowing
+    /// the elements.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Arcs::new(&buffer, 1..4);
+    /// assert_eq!(Arcs::get_mut(&mut slice), None);
+    ///
+    /// Arcs::make_unique(&mut slice);
+    /// Arcs::get_mut(&mut slice).unwrap()[0] = 0;
+    /// Arcs::get_mut(&mut slice).unwrap()[2] = 0;
+    /// assert_eq!(**Arcs::inner(&slice), [2, 0, 6, 0, 10]);
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn make_unique(it: &mut Self) {
+        Arc::make_mut(&mut it.underlying);
+    }
+}
+
+#[test]
+fn test_rc_arc_conversion() {
+    use crate::RcSlice;
+
+    let buffer: Arc<Vec<u8>> = Arc::new(alloc::vec![2, 4, 6, 8, 10]);
+    let slice = ArcSlice::new(&buffer, 1..4);
+
+    let local = ArcSlice::to_rc_slice(&slice);
+    assert_eq!(*local, [4, 6, 8]);
+    assert_eq!(RcSlice::bounds_range(&local), 1..4);
+    assert_eq!(**RcSlice::inner(&local), [2, 4, 6, 8, 10]);
+    assert_eq!(Arc::strong_count(&buffer), 2);
+
+    let back = RcSlice::to_arc_slice(&local);
+    assert_eq!(*back, [4, 6, 8]);
+    assert_eq!(ArcSlice::bounds_range(&back), 1..4);
+    assert_eq!(**ArcSlice::inner(&back), [2, 4, 6, 8, 10]);
+    assert!(!Arc::ptr_eq(ArcSlice::inner(&back), &buffer));
+
+    let empty = ArcSlice::new(&buffer, 5..);
+    assert_eq!(RcSlice::bounds_range(&ArcSlice::to_rc_slice(&empty)), 5..5);
+}
+
+#[test]
+fn test_from_arc_range() {
+    let slice = ArcSlice::from_arc_range(Arc::new(alloc::vec![2, 4, 6, 8, 10]), 1..4);
+    assert_eq!(*slice, [4, 6, 8]);
+    assert_eq!(Arc::strong_count(ArcSlice::inner(&slice)), 1);
+
+    let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+    let slice = ArcSlice::from_arc_range(buffer.clone(), 3..);
+    assert_eq!(*slice, [8, 10]);
+    assert_eq!(Arc::strong_count(&buffer), 2);
+
+    // Ranges are handled like `new`.
+    let slice = ArcSlice::from_arc_range(buffer, 2..=100);
+    assert_eq!(ArcSlice::bounds_range(&slice), 2..5);
+}
+
+#[test]
+fn test_usize_max_inclusive_ranges() {
+    use core::ops::Bound;
+    use ArcSlice as Arcs;
+
+    let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10]);
+
+    assert_eq!(
+        Arcs::bounds_range(&Arcs::new(&buffer, 0..=usize::MAX)),
+        0..5
+    );
+    assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, ..=usize::MAX)), 0..5);
+    assert_eq!(Arcs::new(&buffer, ..=usize::MAX), Arcs::new(&buffer, ..));
+    assert_eq!(
+        Arcs::bounds_range(&Arcs::new(&buffer, 3..=usize::MAX)),
+        3..5
+    );
+    assert_eq!(
+        Arcs::bounds_range(&Arcs::new(&buffer, usize::MAX..=usize::MAX)),
+        5..5
+    );
+    let excluded = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    assert_eq!(Arcs::bounds_range(&Arcs::new(&buffer, excluded)), 5..5);
+
+    let mut slice = Arcs::new(&buffer, 1..2);
+    assert_eq!(Arcs::change_range(&mut slice, 0..=usize::MAX), 0..5);
+    assert_eq!(*slice, [2, 4, 6, 8, 10]);
+    assert_eq!(Arcs::change_range(&mut slice, 1..2), 1..2);
+    assert_eq!(Arcs::change_range(&mut slice, ..=usize::MAX), 0..5);
+    assert_eq!(Arcs::change_range(&mut slice, 4..=usize::MAX), 4..5);
+    assert_eq!(
+        Arcs::change_range(&mut slice, usize::MAX..=usize::MAX),
+        5..5
+    );
+    assert_eq!(Arcs::change_range(&mut slice, excluded), 5..5);
+}
+
+#[test]
+fn test_mutation_with_live_weaks() {
+    use alloc::vec;
+    use ArcSlice as Arcs;
+
+    // get_mut_ignoring_weak
+    let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    let weak = Arc::downgrade(&buffer);
+    let mut slice = Arcs::new(&buffer, 1..4);
+    assert_eq!(Arcs::get_mut_ignoring_weak(&mut slice), None);
+    assert!(Arc::ptr_eq(Arcs::inner(&slice), &buffer));
+
+    core::mem::drop(buffer);
+    assert_eq!(Arcs::get_mut(&mut slice), None);
+    Arcs::get_mut_ignoring_weak(&mut slice).unwrap()[0] = 5;
+    assert_eq!(*slice, [5, 6, 8]);
+    assert_eq!(Arcs::bounds_range(&slice), 1..4);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(Arc::weak_count(Arcs::inner(&slice)), 0);
+    assert!(Arcs::get_mut(&mut slice).is_some());
+
+    // try_make_mut, blocked only by a weak reference: moved, not copied.
+    let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10]);
+    let weak = Arc::downgrade(&buffer);
+    let mut slice = Arcs::new(&buffer, 1..4);
+    let data = buffer.as_ptr();
+    core::mem::drop(buffer);
+    Arcs::try_make_mut(&mut slice)[2] = 9;
+    assert_eq!(*slice, [4, 6, 9]);
+    assert_eq!(Arcs::inner(&slice).as_ptr(), data);
+    assert!(weak.upgrade().is_none());
+
+    // try_make_mut, blocked by a strong reference: copied.
+    let buffer = Arc::clone(Arcs::inner(&slice));
+    let weak = Arc::downgrade(&buffer);
+    Arcs::try_make_mut(&mut slice)[0] = 1;
+    assert_eq!(*slice, [1, 6, 9]);
+    assert_eq!(*buffer, [2, 4, 6, 9, 10]);
+    assert!(weak.upgrade().is_some());
+    assert_eq!(Arc::strong_count(Arcs::inner(&slice)), 1);
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + IntoIterator<Item = <T as RcSliceContainer>::Item>,
+    <T as RcSliceContainer>::Item: Clone,
+{
+    /// Consumes the slice, and returns an iterator over its elements by value.
+    ///
+    /// If `it` is the only reference to the buffer (not counting weak references), the container
+    /// is unwrapped and the viewed elements are moved out of it without being cloned; the rest
+    /// of the container is dropped. Otherwise, the buffer is left alone for its other owners, and
+    /// each element is cloned as the iterator reaches it.
+    ///
+    /// Moving elements out requires consuming the container, so this is only available for
+    /// sized containers like `Vec<T>`, not for `[T]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use alloc::string::String;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let words: Vec<String> = ["a", "b", "c", "d"].map(String::from).into();
+    /// let buffer = Arc::new(words);
+    ///
+    /// // Shared: the elements are cloned.
+    /// let slice = Arcs::new(&buffer, 1..3);
+    /// assert_eq!(Arcs::into_iter_owned(slice).collect::<Vec<_>>(), ["b", "c"]);
+    ///
+    /// // Unique: the elements are moved out of the buffer.
+    /// let slice = Arcs::new(&buffer, 2..);
+    /// core::mem::drop(buffer);
+    /// assert_eq!(Arcs::into_iter_owned(slice).collect::<Vec<_>>(), ["c", "d"]);
+    /// ```
+    pub fn into_iter_owned(it: Self) -> ArcIntoIterOwned<T> {
+        let (start, end) = (it.start, it.end);
+        let inner = match Arc::try_unwrap(it.underlying) {
+            Ok(container) => {
+                IntoIterOwned::Unique(container.into_iter().skip(start).take(end - start))
+            }
+            Err(underlying) => {
+                IntoIterOwned::Shared(ArcSlice::from_bounds(&underlying, start, end))
+            }
+        };
+        ArcIntoIterOwned { inner }
+    }
+}
+
+/// Iterator returned by [`ArcSlice::into_iter_owned`].
+pub struct ArcIntoIterOwned<T: IntoIterator> {
+    inner: IntoIterOwned<T::IntoIter, T>,
+}
+
+impl<T> Iterator for ArcIntoIterOwned<T>
+where
+    T: RcSliceContainer + IntoIterator<Item = <T as RcSliceContainer>::Item>,
+    <T as RcSliceContainer>::Item: Clone,
+{
+    type Item = <T as RcSliceContainer>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> Clone for ArcIntoIterOwned<T>
+where
+    T: RcSliceContainer + IntoIterator,
+    T::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        let inner = match &self.inner {
+            IntoIterOwned::Unique(iter) => IntoIterOwned::Unique(iter.clone()),
+            IntoIterOwned::Shared(rest) => IntoIterOwned::Shared(rest.clone()),
+        };
+        Self { inner }
+    }
+}
+
+impl<T> fmt::Debug for ArcIntoIterOwned<T>
+where
+    T: RcSliceContainer + IntoIterator + fmt::Debug,
+    T::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            IntoIterOwned::Unique(iter) => f.debug_tuple("Unique").field(iter).finish(),
+            IntoIterOwned::Shared(rest) => f.debug_tuple("Shared").field(rest).finish(),
+        }
+    }
+}
+
+/// The state of an [`ArcIntoIterOwned`].
+enum IntoIterOwned<I, T> {
+    /// The elements are moved out of the unwrapped container.
+    Unique(core::iter::Take<core::iter::Skip<I>>),
+    /// The buffer is shared; the elements that haven't been cloned yet.
+    Shared(ArcSlice<T>),
+}
+
+impl<I, T> Iterator for IntoIterOwned<I, T>
+where
+    I: Iterator<Item = T::Item>,
+    T: RcSliceContainer,
+    T::Item: Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IntoIterOwned::Unique(iter) => iter.next(),
+            IntoIterOwned::Shared(rest) => {
+                let item = ArcSlice::peek(rest)?.clone();
+                ArcSlice::advance(rest, 1);
+                Some(item)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IntoIterOwned::Unique(iter) => iter.size_hint(),
+            IntoIterOwned::Shared(rest) => (rest.len(), Some(rest.len())),
+        }
+    }
+}
+
+#[test]
+fn test_subslice() {
+    use ArcSlice as Arcs;
+
+    let buffer: Arc<[u8]> = (0..20).collect();
+    let outer = Arcs::new(&buffer, 2..18);
+
+    let first = Arcs::subslice(&outer, 3..);
+    let second = Arcs::subslice(&first, ..10);
+    let third = Arcs::subslice(&second, 4..=6);
+    assert_eq!(*first, [5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17]);
+    assert_eq!(*second, [5, 6, 7, 8, 9, 10, 11, 12, 13, 14]);
+    assert_eq!(*third, [9, 10, 11]);
+    assert_eq!(Arcs::bounds_range(&third), 9..12);
+
+    // Every level shares the same buffer, and the parents are unchanged.
+    assert!(Arc::ptr_eq(Arcs::inner(&third), &buffer));
+    assert_eq!(Arc::strong_count(&buffer), 5);
+    assert_eq!(Arcs::bounds_range(&outer), 2..18);
+    assert_eq!(Arcs::bounds_range(&second), 5..15);
+
+    // Ranges are clamped to the parent, not the buffer.
+    assert_eq!(*Arcs::subslice(&third, 1..100), [10, 11]);
+    assert_eq!(Arcs::bounds_range(&Arcs::subslice(&third, 5..)), 12..12);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "does not match the kept elements")]
+fn test_shrink_checks_container_range() {
+    use alloc::vec;
+    use ArcSlice as Arcs;
+
+    // A container that drops the kept elements it claims to keep.
+    #[derive(Default)]
+    struct Truncating(Vec<u8>);
+
+    impl RcSliceContainer for Truncating {
+        type Item = u8;
+        const IS_SHRINKABLE: bool = true;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+            self.0.get(range)
+        }
+
+        fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]> {
+            self.0.get_mut(range)
+        }
+
+        fn shrink_container_to_range(&mut self, keep_range: Range<usize>) -> Option<Range<usize>> {
+            self.0.truncate(1);
+            Some(0..keep_range.len())
+        }
+    }
+
+    let mut slice = Arcs::new(&Arc::new(Truncating(vec![1, 2, 3, 4, 5])), 1..4);
+    let _ = Arcs::try_shrink(&mut slice);
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "does not match the kept elements")]
+fn test_shrink_checks_container_range_order() {
+    use alloc::vec;
+    use ArcSlice as Arcs;
+
+    // A container that returns a backwards range, which has the right length of zero.
+    #[derive(Default)]
+    struct Backwards(Vec<u8>);
+
+    impl RcSliceContainer for Backwards {
+        type Item = u8;
+        const IS_SHRINKABLE: bool = true;
+
+        fn len(&self) -> usize {
+            self.0.len()
+        }
+
+        fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+            self.0.get(range)
+        }
+
+        fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]> {
+            self.0.get_mut(range)
+        }
+
+        fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+            self.0.truncate(2);
+            Some(Range { start: 2, end: 1 })
+        }
+    }
+
+    let mut slice = Arcs::new(&Arc::new(Backwards(vec![1, 2, 3, 4, 5])), 3..3);
+    let _ = Arcs::try_shrink(&mut slice);
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: TotalCmp,
+{
+    /// Sorts the slice in place by [`TotalCmp::total_cmp`] (which is `f32::total_cmp` or
+    /// `f64::total_cmp`), if there are no other ArcSlice or Arc pointers to the buffer. Returns
+    /// `false` without sorting if the buffer is shared.
+    ///
+    /// Unlike `PartialOrd`, this ordering is total, so the result is deterministic even if the
+    /// slice contains NaN: negative NaN sorts first, then `-inf`, ..., `-0.0`, `0.0`, ...,
+    /// `inf`, and positive NaN sorts last.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
+    ///
+    /// let buffer: Arc<[f64]> = Arc::new([2.5, -1.0, 0.0, -0.0]);
+    /// let mut slice = Arcs::from(buffer);
+    ///
+    /// assert!(Arcs::sort_by_total_cmp(&mut slice));
+    /// assert_eq!(*slice, [-1.0, -0.0, 0.0, 2.5]);
+    /// assert!(slice[1].is_sign_negative());
+    /// ```
+    pub fn sort_by_total_cmp(it: &mut Self) -> bool {
+        match ArcSlice::get_mut(it) {
+            Some(slice) => {
+                slice.sort_by(TotalCmp::total_cmp);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+/// A container whose `len` claims two more elements than it has.
+#[cfg(test)]
+struct OverlongContainer(Vec<u8>);
+
+#[cfg(test)]
+impl RcSliceContainer for OverlongContainer {
+    type Item = u8;
+    const IS_SHRINKABLE: bool = false;
+
+    fn len(&self) -> usize {
+        self.0.len() + 2
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+        self.0.get(range)
+    }
+
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]> {
+        self.0.get_mut(range)
+    }
+
+    fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+        unimplemented!()
+    }
+}
+
+#[test]
+#[should_panic(expected = "slice range is out of bounds for its container")]
+fn test_inconsistent_container_panics() {
+    use alloc::vec;
+    use ArcSlice as Arcs;
+
+    let buffer = Arc::new(OverlongContainer(vec![1, 2, 3]));
+    let slice = Arcs::new(&buffer, 1..);
+    assert_eq!(Arcs::len(&slice), 4);
+    let _ = &*slice;
+}
+
+#[test]
+fn test_boundary_split_strong_counts() {
+    use ArcSlice as Arcs;
+
+    let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 4]);
+    let mut slice = Arcs::new(&buffer, 1..3);
+    assert_eq!(Arc::strong_count(&buffer), 2);
+
+    // Both halves hold a reference, even the empty one.
+    let (low, high) = Arcs::split_at(&slice, 0);
+    assert!(low.is_empty());
+    assert_eq!(*high, [2, 3]);
+    assert_eq!(Arc::strong_count(&buffer), 4);
+    drop(low);
+    assert_eq!(Arc::strong_count(&buffer), 3);
+    drop(high);
+
+    let (low, high) = Arcs::split_at(&slice, 2);
+    assert!(high.is_empty());
+    assert_eq!(Arc::strong_count(&buffer), 4);
+    drop((low, high));
+    assert_eq!(Arc::strong_count(&buffer), 2);
+
+    // The split_off functions reuse `it`, so they only add the returned slice.
+    let front = Arcs::split_off_before(&mut slice, 0).unwrap();
+    assert!(front.is_empty());
+    assert_eq!(Arc::strong_count(&buffer), 3);
+    let back = Arcs::split_off_after(&mut slice, 2).unwrap();
+    assert!(back.is_empty());
+    assert_eq!(Arc::strong_count(&buffer), 4);
+
+    // Failed splits don't touch the count.
+    assert!(Arcs::split_off_before(&mut slice, 3).is_none());
+    assert!(Arcs::split_off_after(&mut slice, 3).is_none());
+    assert_eq!(Arc::strong_count(&buffer), 4);
+}
+
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+{
+    /// Compares the slice element by element against the items of `iter`, without collecting
+    /// them first. Returns `true` if they have the same length and every pair is equal.
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    /// Stops at the first mismatch. If `iter` is longer than the slice, only one item past the
+    /// end of the slice is taken from it.
     ///
     /// ```
     /// # extern crate alloc;
@@ -468,46 +3473,32 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..8);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
-    ///
-    /// // Take the first two
-    /// assert_eq!(Arcs::saturating_retract(&mut slice, 2), [14, 16]);
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
-    ///
-    /// // Take three more
-    /// assert_eq!(Arcs::saturating_retract(&mut slice, 3), [8, 10, 12]);
-    /// assert_eq!(*slice, [4, 6]);
-    ///
-    /// // Try to take three, but can only take 2.
-    /// assert_eq!(Arcs::saturating_retract(&mut slice, 3), [4, 6]);
-    /// assert_eq!(*slice, []);
+    /// let buffer: Arc<[u32]> = Arc::new([1, 4, 9, 16]);
+    /// let slice = Arcs::new(&buffer, 1..);
     ///
-    /// // Try to take two, but slice is empty.
-    /// assert_eq!(Arcs::saturating_retract(&mut slice, 2), []);
-    /// assert_eq!(*slice, []);
+    /// assert!(Arcs::eq_iter(&slice, (2..5).map(|x| x * x)));
+    /// assert!(!Arcs::eq_iter(&slice, (2..4).map(|x| x * x)));
+    /// assert!(!Arcs::eq_iter(&slice, (2..).map(|x| x * x)));
     /// ```
-    pub fn saturating_retract(it: &mut Self, decr: usize) -> &[T::Item] {
-        let cut = usize::max(it.end.saturating_sub(decr), it.start);
-
-        // TODO: Evaluate whether this will panic, and when.
-        // I believe it will only panic if the container trait impl
-        // is implemented weirdly.
-        let shed = it.underlying.get(cut..it.end).unwrap();
-        it.end = cut;
-        shed
+    pub fn eq_iter<I>(it: &Self, iter: I) -> bool
+    where
+        I: IntoIterator,
+        T::Item: PartialEq<I::Item>,
+    {
+        let mut iter = iter.into_iter();
+        for item in ArcSlice::as_slice(it) {
+            match iter.next() {
+                Some(other) if *item == other => {}
+                _ => return false,
+            }
+        }
+        iter.next().is_none()
     }
 
-    /// WARNING: This function is unstable and may change or be removed in future versions!
-    ///
-    /// Adjusts the range of the slice. Roughly equivalent to `ArcSlice::new(it.inner(), new_range)`,
-    /// but the change is made in-place.
-    ///
-    /// Returns the actual range of the new slice.
+    /// Compares the elements before the first `sentinel` in the slice against `other`. If the
+    /// slice doesn't contain `sentinel`, the whole slice is compared.
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](ArcSlice::shrink).
+    /// This is useful for null-terminated data, without finding the terminator first.
     ///
     /// ```
     /// # extern crate alloc;
@@ -515,35 +3506,32 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..4);
+    /// let buffer: Arc<[u8]> = Arc::from(&b"name\0\0\0\0"[..]);
+    /// let field = Arcs::from(buffer);
     ///
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(Arcs::change_range(&mut slice, 3..), 3..9);
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16, 18]);
+    /// assert!(Arcs::eq_until(&field, b"name", &0));
+    /// assert!(!Arcs::eq_until(&field, b"name\0", &0));
+    /// assert!(!Arcs::eq_until(&field, b"nam", &0));
     /// ```
-    pub fn change_range<R: RangeBounds<usize>>(it: &mut Self, new_range: R) -> Range<usize> {
-        let mut start = match new_range.start_bound() {
-            Bound::Excluded(x) => usize::min(x.saturating_add(1), it.underlying.len()),
-            Bound::Included(x) => usize::min(*x, it.underlying.len()),
-            Bound::Unbounded => 0,
-        };
-        let end = match new_range.end_bound() {
-            Bound::Excluded(x) => usize::min(*x, it.underlying.len()),
-            Bound::Included(x) => usize::min(x.saturating_add(1), it.underlying.len()),
-            Bound::Unbounded => it.underlying.len(),
-        };
-        start = usize::min(start, end);
-        it.start = start;
-        it.end = end;
-        start..end
+    pub fn eq_until(it: &Self, other: &[T::Item], sentinel: &T::Item) -> bool
+    where
+        T::Item: PartialEq,
+    {
+        let slice = ArcSlice::as_slice(it);
+        let end = slice
+            .iter()
+            .position(|item| item == sentinel)
+            .unwrap_or(slice.len());
+        slice[..end] == *other
     }
 
-    /////////////////////////////////////////////
-    // Methods related to `Arc`
-
-    /// Returns a mutable reference into the given slice, if there are no other
-    /// ArcSlice pointers to anywhere else in the underlying array.
+    /// Returns `true` if both slices view the same range of the same buffer (see
+    /// [`ptr_eq`](ArcSlice::ptr_eq)), without looking at any elements. Otherwise, compares the
+    /// elements.
+    ///
+    /// This is useful for code such as caches, where most comparisons are between clones. It
+    /// gives the same result as `==` unless the elements' `==` isn't reflexive, like `f64::NAN`:
+    /// a slice is always `same_or_eq` to its clones, even when it isn't `==` to them.
     ///
     /// ```
     /// # extern crate alloc;
@@ -551,43 +3539,35 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Arcs::new(&buffer, 1..8);
-    ///
-    /// // The original Arc buffer is still alive, so get_mut doesn't work.
-    /// assert_eq!(Arcs::get_mut(&mut slice), None);
-    ///
-    /// std::mem::drop(buffer);
-    /// // Now there's only one ArcSlice for this array, so get_mut is allowed.
-    /// assert_eq!(Arcs::get_mut(&mut slice), Some([4, 6, 8, 10, 12, 14, 16].as_mut_slice()));
-    ///
-    /// // We can use the mutable reference.
-    /// Arcs::get_mut(&mut slice).unwrap().reverse();
-    /// assert_eq!(*slice, [16, 14, 12, 10, 8, 6, 4]);
-    ///
-    /// // The mutation changed the original buffer (though we need to create a new reference to see it).
-    /// let buffer = Arc::clone(Arcs::inner(&slice));
-    /// assert_eq!(*buffer, [2, 16, 14, 12, 10, 8, 6, 4, 18]);
+    /// let buffer: Arc<[f64]> = Arc::new([1.0, f64::NAN]);
+    /// let key = Arcs::new(&buffer, ..);
     ///
-    /// // Drop the buffer Arc again.
-    /// std::mem::drop(buffer);
-    /// assert_eq!(Arcs::get_mut(&mut slice), Some([16, 14, 12, 10, 8, 6, 4].as_mut_slice()));
+    /// // Not compared element by element, so NaN doesn't matter, unlike with `==`.
+    /// assert!(Arcs::same_or_eq(&key, &key.clone()));
+    /// assert!(key != key.clone());
     ///
-    /// // Disjoint ArcSlices still prevent mutation.
-    /// let (mut low, high) = Arcs::split_at(&slice, 4);
-    /// assert_eq!(*low, [16, 14, 12, 10]);
-    /// assert_eq!(*high, [8, 6, 4]);
-    /// assert_eq!(Arcs::get_mut(&mut low), None);
+    /// let copy: Arc<[f64]> = Arc::new([1.0, 2.0]);
+    /// assert!(Arcs::same_or_eq(&Arcs::new(&buffer, ..1), &Arcs::new(&copy, ..1)));
+    /// assert!(!Arcs::same_or_eq(&key, &Arcs::new(&copy, ..)));
     /// ```
-    pub fn get_mut(it: &mut Self) -> Option<&mut [T::Item]> {
-        let start = it.start;
-        let end = it.end;
-        Arc::get_mut(&mut it.underlying)
-            .map(|s| s.get_mut(start..end))
-            .flatten()
+    pub fn same_or_eq(a: &Self, b: &Self) -> bool
+    where
+        T::Item: PartialEq,
+    {
+        ArcSlice::ptr_eq(a, b) || ArcSlice::as_slice(a) == ArcSlice::as_slice(b)
     }
+}
 
-    /// Checks if two ArcSlices reference the same slice of the same array in memory.
+impl<T> ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    Arc<T>: Default,
+{
+    /// Takes the slice out of `it`, leaving an empty [`default`](ArcSlice::default) slice in its
+    /// place. This is [`core::mem::take`].
+    ///
+    /// The empty slice left behind uses its own (empty) buffer, so it doesn't keep the taken
+    /// slice's buffer alive, or stop [`get_mut`](ArcSlice::get_mut) from working on it.
     ///
     /// ```
     /// # extern crate alloc;
@@ -595,94 +3575,116 @@ impl<T: RcSliceContainer + ?Sized> ArcSlice<T> {
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let first_slice = Arcs::new(&buffer, ..4);
-    /// let second_slice = Arcs::new(&buffer, ..8);
+    /// let mut pending = Arcs::from(Arc::new(vec![2, 4, 6]));
     ///
-    /// // These slices point to the same buffer, but different ranges.
-    /// assert_eq!(Arcs::ptr_eq(&first_slice, &second_slice), false);
+    /// let mut taken = Arcs::take(&mut pending);
+    /// assert!(pending.is_empty());
+    /// assert_eq!(*taken, [2, 4, 6]);
+    /// assert!(Arcs::get_mut(&mut taken).is_some());
+    /// ```
+    pub fn take(it: &mut Self) -> Self {
+        core::mem::take(it)
+    }
+}
+
+#[test]
+fn test_swap_buffer() {
+    use ArcSlice as Arcs;
+
+    let first: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5]);
+    let mut view = Arcs::new(&first, 1..4);
+
+    // Equal length: the range is kept.
+    let second: Arc<[u8]> = Arc::new([10, 20, 30, 40, 50]);
+    let old = Arcs::swap_buffer(&mut view, second.clone());
+    assert!(Arc::ptr_eq(&old, &first));
+    assert!(Arc::ptr_eq(Arcs::inner(&view), &second));
+    assert_eq!(Arcs::bounds_range(&view), 1..4);
+    assert_eq!(*view, [20, 30, 40]);
+    drop(old);
+    assert_eq!(Arc::strong_count(&first), 1);
+
+    // Longer: the range is kept, and doesn't grow.
+    let old = Arcs::swap_buffer(&mut view, Arc::new([0; 10]));
+    assert!(Arc::ptr_eq(&old, &second));
+    assert_eq!(Arcs::bounds_range(&view), 1..4);
+    assert_eq!(*view, [0, 0, 0]);
+
+    // Shorter: the end is clamped.
+    Arcs::swap_buffer(&mut view, Arc::new([7, 8, 9]));
+    assert_eq!(Arcs::bounds_range(&view), 1..3);
+    assert_eq!(*view, [8, 9]);
+
+    // Shorter than the start: the slice becomes empty at the end of the buffer.
+    Arcs::swap_buffer(&mut view, Arc::new([]));
+    assert_eq!(Arcs::bounds_range(&view), 0..0);
+    assert!(view.is_empty());
+}
+
+impl<T: RcSliceContainer + 'static> ArcSlice<T> {
+    /// Leaks the buffer, and returns the elements of the slice with a `'static` lifetime, if
+    /// there are no other ArcSlice or Arc pointers to the buffer. Returns `None` if the buffer is
+    /// shared; the slice is dropped in that case.
     ///
-    /// let (low, _) = Arcs::split_at(&second_slice, 4);
-    /// assert_eq!(first_slice, low);
+    /// The container is moved out of the `Arc` with [`Arc::try_unwrap`], and then leaked with
+    /// [`Box::leak`]. The whole container is leaked, including any elements outside the slice,
+    /// so [`shrink`](ArcSlice::shrink) first if that matters. Weak pointers don't prevent this,
+    /// but they can't be upgraded afterward.
     ///
-    /// // These slices use the same buffer, and the same range.
-    /// assert_eq!(Arcs::ptr_eq(&first_slice, &low), true);
+    /// This is only available for sized containers like `Vec<T>`, because an `Arc<[T]>` can't be
+    /// unwrapped without `unsafe` code. Slices of an `Arc<[T]>` have their own
+    /// [`leak_copy`](ArcSlice::leak_copy), which leaks a copy of the elements instead.
     ///
-    /// let other_buffer: Arc<[u8]> = Arc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let other_slice = Arcs::new(&other_buffer, ..4);
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::sync::Arc;
+    /// # use rc_slice2::ArcSlice;
+    /// use ArcSlice as Arcs;
     ///
-    /// // The elements for `other_slice` are the same
-    /// assert_eq!(first_slice, other_slice);
+    /// let buffer: Arc<Vec<u8>> = Arc::new(b"interned name".to_vec());
+    /// let name = Arcs::new(&buffer, 9..);
     ///
-    /// // But they use different memory locations.
-    /// assert_eq!(Arcs::ptr_eq(&first_slice, &other_slice), false);
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Arcs::leak(name.clone()), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// let name: &'static [u8] = Arcs::leak(name).unwrap();
+    /// assert_eq!(name, b"name");
     /// ```
-    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
-        Arc::ptr_eq(&this.underlying, &other.underlying)
-            && this.start == other.start
-            && this.end == other.end
+    pub fn leak(it: Self) -> Option<&'static [T::Item]> {
+        let container = Arc::try_unwrap(it.underlying).ok()?;
+        let container: &'static T = Box::leak(Box::new(container));
+        Some(container.get_unchecked_by_invariant(it.start..it.end))
     }
 }
 
-impl<T: RcSliceContainer + ?Sized + Default> ArcSlice<T> {
-    /// Tries to reduce the size of the original buffer, if this is the only
-    /// Arc or ArcSlice referencing the buffer.
+impl<T: Clone + 'static> ArcSlice<[T]> {
+    /// Leaks a copy of the elements of the slice, and returns it with a `'static` lifetime, if
+    /// there are no other ArcSlice, Arc, or Weak pointers to the buffer. Returns `None` if the
+    /// buffer is shared; the slice is dropped in that case.
+    ///
+    /// Only the elements inside the slice are copied and leaked. The buffer itself is dropped
+    /// as usual, so elements outside the slice don't stay alive.
+    ///
     /// ```
     /// # extern crate alloc;
     /// # use alloc::sync::Arc;
     /// # use rc_slice2::ArcSlice;
     /// use ArcSlice as Arcs;
     ///
-    /// let buffer: Arc<Vec<u8>> = Arc::new(vec![2, 4, 6, 8, 10, 12]);
-    /// let mut slice = Arcs::new(&buffer, 1..4);
-    /// let weak_buffer = Arc::downgrade(&buffer);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(**Arcs::inner(&slice), [2, 4, 6, 8, 10, 12]);
-    ///
-    /// // Shrink fails: there are two strong references.
-    /// assert_eq!(Arcs::shrink(&mut slice), false);
+    /// let buffer: Arc<[u8]> = Arc::from(&b"interned name"[..]);
+    /// let name = Arcs::new(&buffer, 9..);
     ///
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Arcs::leak_copy(name.clone()), None);
     /// core::mem::drop(buffer);
     ///
-    /// // Shrink successful: only one strong reference.
-    /// assert_eq!(Arcs::shrink(&mut slice), true);
-    ///
-    /// // The slice is unchanged, and the buffer has shrunk.
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(**Arcs::inner(&slice), [4, 6, 8]);
-    ///
-    /// // Weak references were not preserved. This behavior MAY be
-    /// // changed in a future version.
-    /// assert_eq!(weak_buffer.upgrade(), None);
+    /// let name: &'static [u8] = Arcs::leak_copy(name).unwrap();
+    /// assert_eq!(name, b"name");
     /// ```
-    pub fn shrink(it: &mut Self) -> bool {
-        // This will be optimized away
-        if !T::IS_SHRINKABLE {
-            return false;
-        }
-
-        if it.start == 0 && it.end == it.underlying.len() {
-            return false;
-        }
-
-        let mut temp = Arc::new(T::default());
-        core::mem::swap(&mut temp, &mut it.underlying);
-        match Arc::try_unwrap(temp) {
-            Ok(mut container) => {
-                if let Some(new_range) = container.shrink_container_to_range(it.start..it.end) {
-                    it.start = new_range.start;
-                    it.end = new_range.end;
-                }
-                let mut temp2 = Arc::new(container);
-                core::mem::swap(&mut temp2, &mut it.underlying);
-                true
-            }
-            Err(mut temp2) => {
-                core::mem::swap(&mut temp2, &mut it.underlying);
-                false
-            }
-        }
+    pub fn leak_copy(mut it: Self) -> Option<&'static [T]> {
+        Arc::get_mut(&mut it.underlying)?;
+        Some(Box::leak(Box::from(&*it)))
     }
 }
 
@@ -698,7 +3700,8 @@ impl<T: RcSliceContainer + ?Sized> Clone for ArcSlice<T> {
 
 impl<T: RcSliceContainer + ?Sized> AsRef<[T::Item]> for ArcSlice<T> {
     fn as_ref(&self) -> &[T::Item] {
-        self.underlying.get(self.start..self.end).unwrap()
+        self.underlying
+            .get_unchecked_by_invariant(self.start..self.end)
     }
 }
 
@@ -710,9 +3713,56 @@ impl<T: RcSliceContainer + ?Sized> Deref for ArcSlice<T> {
     }
 }
 
+/// Indexes into the elements viewed by the slice, exactly like indexing a `[T]`.
+impl<T, I> Index<I> for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    I: SliceIndex<[T::Item]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> Sealed for ArcSlice<T> {}
+
+impl<T: RcSliceContainer + ?Sized> SharedSlice for ArcSlice<T> {
+    type Item = T::Item;
+
+    fn len(it: &Self) -> usize {
+        ArcSlice::len(it)
+    }
+
+    fn is_empty(it: &Self) -> bool {
+        ArcSlice::is_empty(it)
+    }
+
+    fn as_slice(it: &Self) -> &[T::Item] {
+        ArcSlice::as_slice(it)
+    }
+
+    fn split_at(it: &Self, mid: usize) -> (Self, Self) {
+        ArcSlice::split_at(it, mid)
+    }
+
+    fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
+        ArcSlice::advance(it, incr)
+    }
+
+    fn bounds_range(it: &Self) -> Range<usize> {
+        ArcSlice::bounds_range(it)
+    }
+
+    fn ptr_eq(this: &Self, other: &Self) -> bool {
+        ArcSlice::ptr_eq(this, other)
+    }
+}
+
 impl<T: RcSliceContainer + ?Sized> From<Arc<T>> for ArcSlice<T> {
     fn from(underlying: Arc<T>) -> Self {
-        Self::new(&underlying, ..)
+        Self::from_arc_range(underlying, ..)
     }
 }
 
@@ -723,29 +3773,134 @@ fn test_from() {
     assert_eq!(*ref_slice, [4, 5, 6, 7]);
 }
 
+impl<T> From<ArcSlice<T>> for Vec<T::Item>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: ArcSlice<T>) -> Self {
+        slice.as_ref().to_vec()
+    }
+}
+
+impl<T> From<ArcSlice<T>> for Arc<[T::Item]>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: ArcSlice<T>) -> Self {
+        Arc::from(slice.as_ref())
+    }
+}
+
+impl<T> From<ArcSlice<T>> for Box<[T::Item]>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: ArcSlice<T>) -> Self {
+        Box::from(slice.as_ref())
+    }
+}
+
+/// Clones the elements into a new buffer, and returns a slice of all of it.
+///
+/// The buffer is allocated once, and the elements are cloned straight into it, without an
+/// intermediate `Vec`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::ArcSlice;
+/// let data = [2, 4, 6];
+/// let slice = ArcSlice::from(&data[1..]);
+/// assert_eq!(*slice, [4, 6]);
+/// ```
+impl<T: Clone> From<&[T]> for ArcSlice<[T]> {
+    fn from(data: &[T]) -> Self {
+        ArcSlice::from(Arc::<[T]>::from(data))
+    }
+}
+
+/// Collects the elements into a new buffer, and returns a slice of all of it.
+///
+/// This is exactly as efficient as collecting into an `Arc<[T]>`: if the iterator's length is
+/// known exactly up front (for example a range, or a `map` over a slice's iterator), the buffer
+/// is allocated once. Otherwise, the elements are first collected into a `Vec` preallocated
+/// from the iterator's `size_hint`, and then moved into the buffer.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::ArcSlice;
+/// let squares: ArcSlice<[u32]> = (1..5).map(|x| x * x).collect();
+/// assert_eq!(*squares, [1, 4, 9, 16]);
+/// ```
+impl<T> FromIterator<T> for ArcSlice<[T]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        ArcSlice::from(iter.into_iter().collect::<Arc<[T]>>())
+    }
+}
+
+/// Copies the referenced elements into a new buffer, and returns a slice of all of it. This
+/// is the same as collecting `iter.into_iter().copied()`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::ArcSlice;
+/// let data = [1, 2, 3, 4, 5, 6];
+/// let even: ArcSlice<[u32]> = data.iter().filter(|&&x| x % 2 == 0).collect();
+/// assert_eq!(*even, [2, 4, 6]);
+/// ```
+impl<'a, T: Copy + 'a> FromIterator<&'a T> for ArcSlice<[T]> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
 impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for ArcSlice<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.underlying.fmt(f)
     }
 }
 
-impl<T: RcSliceContainer + PartialEq + ?Sized> PartialEq for ArcSlice<T> {
+impl<T> PartialEq for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: PartialEq,
+{
+    /// Compares the elements viewed by both slices, the same way `[T]` does. This agrees with
+    /// [`PartialOrd`] and with comparing the borrowed slices.
+    ///
+    /// The elements are compared even if both slices view the same range of the same buffer.
+    /// To skip them in that case, use [`same_or_eq`](ArcSlice::same_or_eq).
     fn eq(&self, other: &Self) -> bool {
-        self.underlying == other.underlying
+        self.as_ref() == other.as_ref()
     }
 }
 
-impl<T: RcSliceContainer + Eq + ?Sized> Eq for ArcSlice<T> {}
+impl<T> Eq for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Eq,
+{
+}
 
-impl<T: RcSliceContainer + PartialOrd + ?Sized> PartialOrd for ArcSlice<T> {
+impl<T> PartialOrd for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: PartialOrd,
+{
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.underlying.partial_cmp(&other.underlying)
+        self.as_ref().partial_cmp(other.as_ref())
     }
 }
 
-impl<T: RcSliceContainer + Ord + ?Sized> Ord for ArcSlice<T> {
+impl<T> Ord for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Ord,
+{
     fn cmp(&self, other: &Self) -> Ordering {
-        self.underlying.cmp(&other.underlying)
+        ArcSlice::cmp(self, other)
     }
 }
 
@@ -755,18 +3910,41 @@ impl<T: RcSliceContainer + ?Sized> Borrow<[T::Item]> for ArcSlice<T> {
     }
 }
 
+/// Hashes the elements viewed by the slice the same way `[T]` does: the length, followed by each
+/// element. This matches the `Borrow<[T]>` impl, so a map with ArcSlice keys can be searched
+/// with a plain slice.
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::sync::Arc;
+/// # use rc_slice2::ArcSlice;
+/// use std::collections::HashMap;
+///
+/// let buffer: Arc<[u8]> = Arc::new([0, 1, 2, 3]);
+/// let mut names = HashMap::new();
+/// names.insert(ArcSlice::new(&buffer, 1..), "counting");
+///
+/// assert_eq!(names.get(&[1, 2, 3][..]), Some(&"counting"));
+/// ```
 impl<T> Hash for ArcSlice<T>
 where
     T: RcSliceContainer + Hash + ?Sized,
     T::Item: Hash,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        Hash::hash_slice(self.deref(), state)
+        <[T::Item] as Hash>::hash(self.as_ref(), state)
     }
 }
 
-impl<T: RcSliceContainer + Default + ?Sized> Default for ArcSlice<T> {
+/// Creates an empty slice over the default (empty) container. This never allocates space for
+/// any elements: `[T]` and `Vec<T>` buffers have a capacity of zero. `SmallVec` and `TinyVec`
+/// buffers report their inline capacity, but don't allocate on the heap.
+impl<T> Default for ArcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    Arc<T>: Default,
+{
     fn default() -> Self {
-        Self::new(&Arc::new(T::default()), ..)
+        ArcSlice::from(Arc::default())
     }
 }
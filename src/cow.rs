@@ -0,0 +1,157 @@
+use alloc::rc::Rc;
+use core::ops::Deref;
+
+use crate::RcSlice;
+
+/// A clone-on-write slice that either borrows its elements or shares them through an
+/// [`RcSlice`]. This is like `Cow<[T]>`, except the owned side is reference-counted, so
+/// functions that only sometimes keep their input don't need to copy it up front.
+///
+/// Like `RcSlice`, this dereferences to `[T]`, so its own functions are associated functions:
+/// `CowRcSlice::to_rc_slice(&cow)` instead of `cow.to_rc_slice()`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::rc::Rc;
+/// use rc_slice2::{CowRcSlice, RcSlice};
+///
+/// fn trim_zeros(data: &[u8]) -> CowRcSlice<'_, u8> {
+///     match data.iter().position(|&b| b != 0) {
+///         Some(0) | None => CowRcSlice::from(data),
+///         Some(start) => CowRcSlice::from(RcSlice::from(Rc::<[u8]>::from(&data[start..]))),
+///     }
+/// }
+///
+/// let borrowed = trim_zeros(&[1, 2]);
+/// assert!(CowRcSlice::is_borrowed(&borrowed));
+/// assert_eq!(*borrowed, [1, 2]);
+///
+/// let owned = trim_zeros(&[0, 0, 3]);
+/// assert!(!CowRcSlice::is_borrowed(&owned));
+/// assert_eq!(*CowRcSlice::to_rc_slice(&owned), [3]);
+/// ```
+#[derive(Debug)]
+pub enum CowRcSlice<'a, T> {
+    /// Borrowed elements, which are copied into a new buffer when an `RcSlice` is needed.
+    Borrowed(&'a [T]),
+    /// Elements that are already shared through an `RcSlice`.
+    Owned(RcSlice<[T]>),
+}
+
+impl<'a, T> CowRcSlice<'a, T> {
+    /// Returns true if the elements are borrowed rather than held in an `RcSlice`.
+    pub fn is_borrowed(it: &Self) -> bool {
+        matches!(it, CowRcSlice::Borrowed(_))
+    }
+
+    /// Returns an `RcSlice` of the elements. If they are borrowed, they are cloned into a new
+    /// buffer; otherwise this only increments the reference count.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// use rc_slice2::{CowRcSlice, RcSlice};
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6]);
+    /// let owned = CowRcSlice::from(RcSlice::new(&buffer, 1..));
+    /// assert!(Rc::ptr_eq(RcSlice::inner(&CowRcSlice::to_rc_slice(&owned)), &buffer));
+    ///
+    /// let borrowed = CowRcSlice::from([2, 4, 6].as_slice());
+    /// assert_eq!(*CowRcSlice::to_rc_slice(&borrowed), [2, 4, 6]);
+    /// ```
+    pub fn to_rc_slice(it: &Self) -> RcSlice<[T]>
+    where
+        T: Clone,
+    {
+        match it {
+            CowRcSlice::Borrowed(slice) => RcSlice::from(Rc::<[T]>::from(*slice)),
+            CowRcSlice::Owned(slice) => slice.clone(),
+        }
+    }
+
+    /// Like [`to_rc_slice`](CowRcSlice::to_rc_slice), but consumes `it`, so the owned case
+    /// doesn't touch the reference count.
+    pub fn into_rc_slice(it: Self) -> RcSlice<[T]>
+    where
+        T: Clone,
+    {
+        match it {
+            CowRcSlice::Borrowed(slice) => RcSlice::from(Rc::<[T]>::from(slice)),
+            CowRcSlice::Owned(slice) => slice,
+        }
+    }
+}
+
+impl<T> Clone for CowRcSlice<'_, T> {
+    fn clone(&self) -> Self {
+        match self {
+            CowRcSlice::Borrowed(slice) => CowRcSlice::Borrowed(slice),
+            CowRcSlice::Owned(slice) => CowRcSlice::Owned(slice.clone()),
+        }
+    }
+}
+
+impl<T> Deref for CowRcSlice<'_, T> {
+    type Target = [T];
+
+    fn deref(&self) -> &[T] {
+        match self {
+            CowRcSlice::Borrowed(slice) => slice,
+            CowRcSlice::Owned(slice) => slice,
+        }
+    }
+}
+
+impl<'a, T> From<&'a [T]> for CowRcSlice<'a, T> {
+    fn from(slice: &'a [T]) -> Self {
+        CowRcSlice::Borrowed(slice)
+    }
+}
+
+impl<T> From<RcSlice<[T]>> for CowRcSlice<'_, T> {
+    fn from(slice: RcSlice<[T]>) -> Self {
+        CowRcSlice::Owned(slice)
+    }
+}
+
+impl<'a, T: Clone> From<CowRcSlice<'a, T>> for RcSlice<[T]> {
+    fn from(cow: CowRcSlice<'a, T>) -> Self {
+        CowRcSlice::into_rc_slice(cow)
+    }
+}
+
+#[test]
+fn test_cow_rc_slice() {
+    let data = [1u8, 2, 3, 4];
+
+    // Borrowed: materializing copies into a new buffer each time.
+    let borrowed = CowRcSlice::from(&data[1..]);
+    let cloned = borrowed.clone();
+    assert!(CowRcSlice::is_borrowed(&cloned));
+    assert_eq!(*cloned, [2, 3, 4]);
+    let first = CowRcSlice::to_rc_slice(&borrowed);
+    let second = CowRcSlice::to_rc_slice(&borrowed);
+    assert_eq!(first, second);
+    assert!(!Rc::ptr_eq(RcSlice::inner(&first), RcSlice::inner(&second)));
+    assert_eq!(Rc::strong_count(RcSlice::inner(&first)), 1);
+
+    // Owned: materializing shares the existing buffer.
+    let buffer: Rc<[u8]> = Rc::new([5, 6, 7]);
+    let owned = CowRcSlice::from(RcSlice::new(&buffer, ..2));
+    let cloned = owned.clone();
+    assert!(!CowRcSlice::is_borrowed(&cloned));
+    assert_eq!(*cloned, [5, 6]);
+    assert_eq!(Rc::strong_count(&buffer), 3);
+
+    let shared = CowRcSlice::to_rc_slice(&owned);
+    assert!(Rc::ptr_eq(RcSlice::inner(&shared), &buffer));
+    assert_eq!(RcSlice::bounds_range(&shared), 0..2);
+    assert_eq!(Rc::strong_count(&buffer), 4);
+
+    let moved = RcSlice::from(owned);
+    assert!(Rc::ptr_eq(RcSlice::inner(&moved), &buffer));
+    assert_eq!(Rc::strong_count(&buffer), 4);
+
+    let materialized = CowRcSlice::into_rc_slice(CowRcSlice::from(data.as_slice()));
+    assert_eq!(*materialized, data);
+}
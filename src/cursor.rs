@@ -0,0 +1,224 @@
+use core::fmt;
+
+use crate::{ArcSlice, RcSlice, RcSliceContainer};
+
+/// A read position in an [`RcSlice`], for parsers that consume a buffer from front to back.
+///
+/// Each [`read`](RcCursor::read) returns a new slice of the same buffer, so nothing is copied,
+/// and the cursor keeps track of how many elements have been consumed.
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::rc::Rc;
+/// use rc_slice2::{RcCursor, RcSlice};
+///
+/// let buffer: Rc<[u8]> = Rc::from(&b"GET /index.html"[..]);
+/// let mut cursor = RcCursor::new(RcSlice::from(buffer));
+///
+/// assert_eq!(*cursor.read(3).unwrap(), *b"GET");
+/// assert_eq!(cursor.peek(2), Some(&b" /"[..]));
+/// cursor.read(1);
+/// assert_eq!(cursor.position(), 4);
+/// assert_eq!(cursor.remaining(), 11);
+/// assert_eq!(*cursor.into_rest(), *b"/index.html");
+/// ```
+pub struct RcCursor<T: ?Sized> {
+    /// The elements that haven't been read yet.
+    rest: RcSlice<T>,
+    /// The number of elements read so far.
+    position: usize,
+}
+
+impl<T: RcSliceContainer + ?Sized> RcCursor<T> {
+    /// Create a cursor at the start of `slice`.
+    pub fn new(slice: RcSlice<T>) -> Self {
+        Self {
+            rest: slice,
+            position: 0,
+        }
+    }
+
+    /// Returns the next `n` elements as a slice of the same buffer, and moves past them.
+    /// Returns `None` and doesn't move if there are fewer than `n` elements left.
+    pub fn read(&mut self, n: usize) -> Option<RcSlice<T>> {
+        let read = RcSlice::split_off_before(&mut self.rest, n)?;
+        self.position += n;
+        Some(read)
+    }
+
+    /// Returns the next `n` elements without moving past them, or `None` if there are fewer
+    /// than `n` elements left.
+    pub fn peek(&self, n: usize) -> Option<&[T::Item]> {
+        RcSlice::peek_n(&self.rest, n)
+    }
+
+    /// Returns the number of elements that haven't been read yet.
+    pub fn remaining(&self) -> usize {
+        RcSlice::len(&self.rest)
+    }
+
+    /// Returns the number of elements read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the elements that haven't been read yet.
+    pub fn rest(&self) -> &RcSlice<T> {
+        &self.rest
+    }
+
+    /// Consumes the cursor, and returns the elements that haven't been read yet.
+    pub fn into_rest(self) -> RcSlice<T> {
+        self.rest
+    }
+}
+
+impl<T: ?Sized> Clone for RcCursor<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            position: self.position,
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for RcCursor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcCursor")
+            .field("rest", &self.rest)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+/// A read position in an [`ArcSlice`], for parsers that consume a buffer from front to back.
+/// This is the thread-safe version of [`RcCursor`].
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::sync::Arc;
+/// use rc_slice2::{ArcCursor, ArcSlice};
+///
+/// let buffer: Arc<[u8]> = Arc::from(&b"GET /index.html"[..]);
+/// let mut cursor = ArcCursor::new(ArcSlice::from(buffer));
+///
+/// assert_eq!(*cursor.read(3).unwrap(), *b"GET");
+/// assert_eq!(cursor.peek(2), Some(&b" /"[..]));
+/// cursor.read(1);
+/// assert_eq!(cursor.position(), 4);
+/// assert_eq!(cursor.remaining(), 11);
+/// assert_eq!(*cursor.into_rest(), *b"/index.html");
+/// ```
+pub struct ArcCursor<T: ?Sized> {
+    /// The elements that haven't been read yet.
+    rest: ArcSlice<T>,
+    /// The number of elements read so far.
+    position: usize,
+}
+
+impl<T: RcSliceContainer + ?Sized> ArcCursor<T> {
+    /// Create a cursor at the start of `slice`.
+    pub fn new(slice: ArcSlice<T>) -> Self {
+        Self {
+            rest: slice,
+            position: 0,
+        }
+    }
+
+    /// Returns the next `n` elements as a slice of the same buffer, and moves past them.
+    /// Returns `None` and doesn't move if there are fewer than `n` elements left.
+    pub fn read(&mut self, n: usize) -> Option<ArcSlice<T>> {
+        let read = ArcSlice::split_off_before(&mut self.rest, n)?;
+        self.position += n;
+        Some(read)
+    }
+
+    /// Returns the next `n` elements without moving past them, or `None` if there are fewer
+    /// than `n` elements left.
+    pub fn peek(&self, n: usize) -> Option<&[T::Item]> {
+        ArcSlice::peek_n(&self.rest, n)
+    }
+
+    /// Returns the number of elements that haven't been read yet.
+    pub fn remaining(&self) -> usize {
+        ArcSlice::len(&self.rest)
+    }
+
+    /// Returns the number of elements read so far.
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the elements that haven't been read yet.
+    pub fn rest(&self) -> &ArcSlice<T> {
+        &self.rest
+    }
+
+    /// Consumes the cursor, and returns the elements that haven't been read yet.
+    pub fn into_rest(self) -> ArcSlice<T> {
+        self.rest
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> Clone for ArcCursor<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            position: self.position,
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for ArcCursor<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ArcCursor")
+            .field("rest", &self.rest)
+            .field("position", &self.position)
+            .finish()
+    }
+}
+
+#[test]
+fn test_cursor_length_prefixed_records() {
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+    use alloc::vec::Vec;
+
+    // Each record is a length byte followed by that many bytes. The last one is truncated.
+    const DATA: &[u8] = b"\x03abc\x00\x02de\x05fg";
+
+    let buffer: Rc<[u8]> = Rc::from(DATA);
+    let mut cursor = RcCursor::new(RcSlice::from(buffer.clone()));
+    let mut records = Vec::new();
+    while let Some(&[len]) = cursor.peek(1) {
+        match cursor.read(1 + len as usize) {
+            Some(mut record) => {
+                RcSlice::advance(&mut record, 1);
+                records.push(record);
+            }
+            None => break,
+        }
+    }
+    assert_eq!(records.len(), 3);
+    assert_eq!(*records[0], *b"abc");
+    assert_eq!(*records[1], *b"");
+    assert_eq!(*records[2], *b"de");
+    assert_eq!(RcSlice::bounds_range(&records[2]), 6..8);
+    assert!(records
+        .iter()
+        .all(|r| Rc::ptr_eq(RcSlice::inner(r), &buffer)));
+    assert_eq!(cursor.position(), 8);
+    assert_eq!(cursor.remaining(), 3);
+    assert_eq!(**cursor.rest(), *b"\x05fg");
+
+    let buffer: Arc<[u8]> = Arc::from(DATA);
+    let mut cursor = ArcCursor::new(ArcSlice::new(&buffer, 4..));
+    assert_eq!(*cursor.read(1).unwrap(), [0]);
+    let mut branch = cursor.clone();
+    assert_eq!(*branch.read(3).unwrap(), *b"\x02de");
+    assert_eq!((branch.position(), cursor.position()), (4, 1));
+    assert_eq!(branch.read(4), None);
+    assert_eq!(branch.peek(4), None);
+    assert_eq!(branch.position(), 4);
+    assert_eq!(*branch.into_rest(), *b"\x05fg");
+}
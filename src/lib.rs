@@ -2,9 +2,11 @@
 //!
 //! The rc-slice2 library provides `RcSlice` and `ArcSlice` types representing
 //! slices of array-like data structures contained within `Rc` and `Arc`.
-//! Supports raw arrays, boxed slices, `Vec`, and `SmallVec` (with feature
-//! `smallvec`). Includes limited support for resizing the original array, to
-//! conserve memory.
+//! Supports raw arrays, boxed slices, `Vec`, `SmallVec` (with feature
+//! `smallvec`), and `TinyVec` (with feature `tinyvec`). Includes limited
+//! support for resizing the original array, to conserve memory. With feature
+//! `heapless`, `heapless::Vec` can also be used as a container. Byte slices
+//! can be serialized as byte strings with feature `serde`.
 //!
 //! The library is fully `no_std`, and has zero `unsafe` blocks. Every function
 //! is now fully tested with examples and thorough documentation.
@@ -85,13 +87,23 @@
 extern crate alloc;
 
 mod arc;
+mod cow;
+mod cursor;
 mod rc;
+#[cfg(feature = "serde")]
+mod serde_impls;
+mod writer;
 
 use alloc::{boxed::Box, vec::Vec};
+use core::cmp::Ordering;
+use core::fmt;
 use core::ops::Range;
 
-pub use arc::ArcSlice;
-pub use rc::RcSlice;
+pub use arc::{ArcIntoIterOwned, ArcLines, ArcRChunks, ArcSlice};
+pub use cow::CowRcSlice;
+pub use cursor::{ArcCursor, RcCursor};
+pub use rc::{gaps, RcIntoIterOwned, RcLines, RcRChunks, RcSlice};
+pub use writer::RcByteWriter;
 
 /// Trait implemented by any RcSlice-able container. Currently implemented for
 /// arrays, boxed arrays, and vectors.
@@ -107,18 +119,40 @@ pub trait RcSliceContainer {
     /// Return the total length of the container.
     fn len(&self) -> usize;
 
+    /// Returns true if the container has a length of 0.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
     /// Get an immutable slice of the elements within the specified range.
     fn get(&self, range: Range<usize>) -> Option<&[Self::Item]>;
 
     /// Get a mutable slice of the elements within the specified range.
     fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [Self::Item]>;
 
+    /// Get an immutable slice of the elements within a range that is already known to be in
+    /// bounds. `RcSlice` and `ArcSlice` read their own view through this function, so it is
+    /// the only place their type invariant (`start <= end <= len`) is checked.
+    ///
+    /// Despite the name, this is still bounds-checked, and panics if `range` is out of
+    /// bounds; that can only happen if the container's other methods are implemented
+    /// inconsistently. The default calls [`get`](RcSliceContainer::get) and unwraps the
+    /// result. Containers that can be indexed directly should override it, which avoids
+    /// building and then unwrapping the `Option`.
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[Self::Item] {
+        match self.get(range) {
+            Some(slice) => slice,
+            None => panic!("slice range is out of bounds for its container"),
+        }
+    }
+
     /// Shrink the container to just keep the specified range. Returns the new range if
     /// the container was completely or partially shrunk, or None if the container wasn't
     /// modified.
     ///
     /// If this returns a range then the new range MUST point the the same elements as
-    /// `keep_range`.
+    /// `keep_range`. In debug builds, `shrink` panics if the returned range has a different
+    /// length or extends past the end of the shrunk container.
     ///
     /// If [`IS_SHRINKABLE`](RcSliceContainer::IS_SHRINKABLE) is false, this function is never called, and implementations
     /// may panic.
@@ -141,6 +175,10 @@ impl<T> RcSliceContainer for [T] {
         Self::get(self, range)
     }
 
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[T] {
+        &self[range]
+    }
+
     fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
         Self::get_mut(self, range)
     }
@@ -150,6 +188,37 @@ impl<T> RcSliceContainer for [T] {
     }
 }
 
+#[test]
+fn test_slice_container_default_is_empty() {
+    struct Counted(usize);
+
+    impl RcSliceContainer for Counted {
+        type Item = ();
+        const IS_SHRINKABLE: bool = false;
+
+        fn len(&self) -> usize {
+            self.0
+        }
+
+        fn get(&self, _range: Range<usize>) -> Option<&[()]> {
+            None
+        }
+
+        fn get_mut(&mut self, _range: Range<usize>) -> Option<&mut [()]> {
+            None
+        }
+
+        fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+            unimplemented!()
+        }
+    }
+
+    assert!(RcSliceContainer::is_empty(&Counted(0)));
+    assert!(!RcSliceContainer::is_empty(&Counted(3)));
+    assert!(RcSliceContainer::is_empty(&Vec::<u8>::new()));
+    assert!(!RcSliceContainer::is_empty(&[1u8][..]));
+}
+
 #[test]
 fn test_slice_container_raw_array() {
     use alloc::rc::Rc;
@@ -175,6 +244,10 @@ impl<T> RcSliceContainer for Box<[T]> {
         self.as_ref().get(range)
     }
 
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[Self::Item] {
+        &self.as_ref()[range]
+    }
+
     fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [Self::Item]> {
         self.as_mut().get_mut(range)
     }
@@ -209,6 +282,10 @@ impl<T> RcSliceContainer for Vec<T> {
         self.as_slice().get(range)
     }
 
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[T] {
+        &self.as_slice()[range]
+    }
+
     fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
         self.as_mut_slice().get_mut(range)
     }
@@ -246,7 +323,7 @@ fn test_slice_container_vec() {
     assert_eq!(Rc::strong_count(RcSlice::inner(&slice)), 1);
     assert_eq!(*slice, [4, 6, 8]);
     assert_eq!(**RcSlice::inner(&slice), [2, 4, 6, 8, 10]);
-    assert_eq!(RcSlice::shrink(&mut slice), true);
+    assert!(RcSlice::shrink(&mut slice));
     assert_eq!(*slice, [4, 6, 8]);
     assert_eq!(**RcSlice::inner(&slice), [4, 6, 8]);
 }
@@ -264,6 +341,10 @@ impl<T: smallvec::Array> RcSliceContainer for smallvec::SmallVec<T> {
         self.as_slice().get(range)
     }
 
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[Self::Item] {
+        &self.as_slice()[range]
+    }
+
     fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [Self::Item]> {
         self.as_mut_slice().get_mut(range)
     }
@@ -310,13 +391,339 @@ fn test_slice_container_smallvec() {
     assert_eq!(Rc::strong_count(RcSlice::inner(&slice)), 1);
     assert_eq!(*slice, [4, 6, 8]);
     assert_eq!(RcSlice::inner(&slice).as_ref().as_ref(), [2, 4, 6, 8, 10]);
-    assert_eq!(RcSlice::inner(&slice).spilled(), true);
+    assert!(RcSlice::inner(&slice).spilled());
 
-    assert_eq!(RcSlice::shrink(&mut slice), true);
+    assert!(RcSlice::shrink(&mut slice));
 
     assert_eq!(*slice, [4, 6, 8]);
     assert_eq!(RcSlice::inner(&slice).as_ref().as_ref(), [4, 6, 8]);
-    assert_eq!(RcSlice::inner(&slice).spilled(), false);
+    assert!(!RcSlice::inner(&slice).spilled());
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_slice_container_smallvec_inline_shrink() {
+    use alloc::sync::Arc;
+    use smallvec::SmallVec;
+    use ArcSlice as Arcs;
+
+    let buffer: Arc<SmallVec<[u8; 8]>> = Arc::new(SmallVec::from_slice(&[2, 4, 6, 8, 10]));
+    let mut slice = Arcs::new(&buffer, 1..4);
+    core::mem::drop(buffer);
+    assert!(!Arcs::inner(&slice).spilled());
+
+    // The container declines to shrink (returns `None`), so the bounds must not change.
+    assert_eq!(Arcs::try_shrink(&mut slice), Ok(1..4));
+    assert!(Arcs::shrink(&mut slice));
+
+    assert_eq!(Arcs::bounds_range(&slice), 1..4);
+    assert_eq!(*slice, [4, 6, 8]);
+    assert_eq!(Arcs::inner(&slice).as_slice(), [2, 4, 6, 8, 10]);
+    assert!(!Arcs::inner(&slice).spilled());
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn test_slice_container_smallvec_default() {
+    use smallvec::SmallVec;
+
+    let slice: ArcSlice<SmallVec<[u8; 16]>> = ArcSlice::default();
+    assert!(slice.is_empty());
+    assert_eq!(ArcSlice::bounds_range(&slice), 0..0);
+    // The inline capacity is reported, but nothing is on the heap.
+    assert!(!ArcSlice::inner(&slice).spilled());
+    assert_eq!(ArcSlice::inner(&slice).capacity(), 16);
+}
+
+/// `TinyVec` requires its elements to implement `Default`, so only containers of
+/// `Default` items can be used.
+#[cfg(feature = "tinyvec")]
+impl<A: tinyvec::Array> RcSliceContainer for tinyvec::TinyVec<A> {
+    type Item = A::Item;
+    const IS_SHRINKABLE: bool = true;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[Self::Item]> {
+        self.as_slice().get(range)
+    }
+
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[Self::Item] {
+        &self.as_slice()[range]
+    }
+
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [Self::Item]> {
+        self.as_mut_slice().get_mut(range)
+    }
+
+    fn shrink_container_to_range(&mut self, keep_range: Range<usize>) -> Option<Range<usize>> {
+        if self.is_inline() {
+            // No point to shrink anything.
+            return None;
+        }
+
+        // Avoid iterating over anything past the kept range.
+        self.truncate(keep_range.end);
+
+        let mut cur_index = 0;
+        self.retain(|_| {
+            let ret = keep_range.contains(&cur_index);
+            cur_index += 1;
+            ret
+        });
+        self.shrink_to_fit();
+        Some(0..Self::len(self))
+    }
+}
+
+// Note: Any other tests for tinyvec must have the word "tinyvec"
+// in the function name (no underscore). `.build.yml` depends on it.
+#[cfg(feature = "tinyvec")]
+#[test]
+fn test_slice_container_tinyvec() {
+    use alloc::rc::Rc;
+    use tinyvec::{tiny_vec, TinyVec};
+    use RcSlice as Rcs;
+
+    let buffer: Rc<TinyVec<[u8; 4]>> = Rc::new(tiny_vec![2, 4, 6, 8, 10]);
+
+    assert_eq!(*Rcs::new(&buffer, 1..4), [4, 6, 8]);
+    assert_eq!(*Rcs::new(&buffer, ..), [2, 4, 6, 8, 10]);
+    assert_eq!(*Rcs::new(&buffer, 0..=2), [2, 4, 6]);
+    assert_eq!(*Rcs::new(&buffer, 10..), []);
+
+    let mut slice = Rcs::new(&buffer, 1..4);
+    core::mem::drop(buffer);
+    assert_eq!(Rc::strong_count(RcSlice::inner(&slice)), 1);
+    assert_eq!(*slice, [4, 6, 8]);
+    assert_eq!(RcSlice::inner(&slice).as_slice(), [2, 4, 6, 8, 10]);
+    assert!(RcSlice::inner(&slice).is_heap());
+
+    assert!(RcSlice::shrink(&mut slice));
+
+    assert_eq!(*slice, [4, 6, 8]);
+    assert_eq!(RcSlice::inner(&slice).as_slice(), [4, 6, 8]);
+    assert!(RcSlice::inner(&slice).is_inline());
+}
+
+/// `heapless::Vec` stores its elements inline with a fixed capacity, so shrinking it would not
+/// free any memory. The `Rc` or `Arc` around it still needs `alloc`.
+#[cfg(feature = "heapless")]
+impl<T, const N: usize> RcSliceContainer for heapless::Vec<T, N> {
+    type Item = T;
+    const IS_SHRINKABLE: bool = false;
+
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[T]> {
+        self.as_slice().get(range)
+    }
+
+    fn get_unchecked_by_invariant(&self, range: Range<usize>) -> &[T] {
+        &self.as_slice()[range]
+    }
+
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [T]> {
+        self.as_mut_slice().get_mut(range)
+    }
+
+    fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+        unimplemented!()
+    }
+}
+
+// Note: Any other tests for heapless must have the word "heapless"
+// in the function name (no underscore). `.build.yml` depends on it.
+#[cfg(feature = "heapless")]
+#[test]
+fn test_slice_container_heapless() {
+    use alloc::sync::Arc;
+    use ArcSlice as Arcs;
+
+    let buffer: Arc<heapless::Vec<u8, 8>> =
+        Arc::new(heapless::Vec::from_slice(&[2, 4, 6, 8, 10]).unwrap());
+
+    assert_eq!(*Arcs::new(&buffer, 1..4), [4, 6, 8]);
+    assert_eq!(*Arcs::new(&buffer, ..), [2, 4, 6, 8, 10]);
+    assert_eq!(*Arcs::new(&buffer, 0..=2), [2, 4, 6]);
+    assert_eq!(*Arcs::new(&buffer, 10..), []);
+
+    let (low, high) = Arcs::split_at(&Arcs::new(&buffer, 1..), 2);
+    assert_eq!(*low, [4, 6]);
+    assert_eq!(*high, [8, 10]);
+
+    let mut slice = Arcs::new(&buffer, 1..4);
+    core::mem::drop((buffer, low, high));
+    Arcs::get_mut(&mut slice).unwrap()[0] = 5;
+    assert_eq!(
+        Arcs::try_shrink(&mut slice),
+        Err(ShrinkError::NotShrinkable)
+    );
+    assert_eq!(**Arcs::inner(&slice), [2, 5, 6, 8, 10]);
+}
+
+/// The operations shared by [`RcSlice`] and [`ArcSlice`], for code that should work with
+/// either kind of pointer.
+///
+/// Like the inherent functions, these are associated functions rather than methods, so they
+/// are called as `S::len(&slice)` and never shadow the methods of the viewed slice.
+///
+/// This trait is sealed, and is only implemented for `RcSlice` and `ArcSlice`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::rc::Rc;
+/// # use alloc::sync::Arc;
+/// use rc_slice2::{ArcSlice, RcSlice, SharedSlice};
+///
+/// fn split_header<S: SharedSlice<Item = u8>>(packet: &mut S) -> Option<u8> {
+///     let len = *S::advance(packet, 1)?.first()?;
+///     if S::len(packet) < len as usize {
+///         return None;
+///     }
+///     Some(len)
+/// }
+///
+/// let mut rc_packet = RcSlice::from(Rc::<[u8]>::from([2, 10, 20]));
+/// let mut arc_packet = ArcSlice::from(Arc::<[u8]>::from([5, 10, 20]));
+///
+/// assert_eq!(split_header(&mut rc_packet), Some(2));
+/// assert_eq!(split_header(&mut arc_packet), None);
+/// assert_eq!(*rc_packet, [10, 20]);
+/// ```
+pub trait SharedSlice: sealed::Sealed + Clone {
+    /// The type of the elements viewed by the slice.
+    type Item;
+
+    /// See [`RcSlice::len`].
+    fn len(it: &Self) -> usize;
+
+    /// See [`RcSlice::is_empty`].
+    fn is_empty(it: &Self) -> bool;
+
+    /// See [`RcSlice::as_slice`].
+    fn as_slice(it: &Self) -> &[Self::Item];
+
+    /// See [`RcSlice::split_at`].
+    fn split_at(it: &Self, mid: usize) -> (Self, Self);
+
+    /// See [`RcSlice::advance`].
+    fn advance(it: &mut Self, incr: usize) -> Option<&[Self::Item]>;
+
+    /// See [`RcSlice::bounds_range`].
+    fn bounds_range(it: &Self) -> Range<usize>;
+
+    /// See [`RcSlice::ptr_eq`].
+    fn ptr_eq(this: &Self, other: &Self) -> bool;
+}
+
+#[test]
+fn test_shared_slice_generic() {
+    use alloc::rc::Rc;
+    use alloc::sync::Arc;
+
+    // Splits off the first `n` elements, then skips one more.
+    fn split_and_skip<S: SharedSlice<Item = u8>>(slice: &mut S, n: usize) -> S {
+        let (front, back) = S::split_at(slice, n);
+        assert!(!S::ptr_eq(&front, slice));
+        *slice = back;
+        assert!(!S::is_empty(slice));
+        assert!(S::advance(slice, 1).is_some());
+        front
+    }
+
+    let rc_buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5]);
+    let mut rc_slice = RcSlice::from(rc_buffer);
+    let front = split_and_skip(&mut rc_slice, 2);
+    assert_eq!(SharedSlice::as_slice(&front), [1, 2]);
+    assert_eq!(SharedSlice::as_slice(&rc_slice), [4, 5]);
+    assert_eq!(SharedSlice::bounds_range(&rc_slice), 3..5);
+    assert_eq!(<RcSlice<[u8]> as SharedSlice>::len(&rc_slice), 2);
+
+    let arc_buffer: Arc<[u8]> = Arc::new([1, 2, 3, 4, 5]);
+    let mut arc_slice = ArcSlice::from(arc_buffer);
+    let front = split_and_skip(&mut arc_slice, 1);
+    assert_eq!(SharedSlice::as_slice(&front), [1]);
+    assert_eq!(SharedSlice::as_slice(&arc_slice), [3, 4, 5]);
+    assert_eq!(SharedSlice::bounds_range(&arc_slice), 2..5);
+    assert_eq!(<ArcSlice<[u8]> as SharedSlice>::len(&arc_slice), 3);
+}
+
+/// Floating-point types with a total order, used by [`RcSlice::sort_by_total_cmp`] and
+/// [`ArcSlice::sort_by_total_cmp`].
+///
+/// This trait is sealed, and is only implemented for `f32` and `f64`.
+pub trait TotalCmp: sealed::Sealed {
+    /// Compares two values with the type's own `total_cmp` method.
+    fn total_cmp(&self, other: &Self) -> Ordering;
+}
+
+impl TotalCmp for f32 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f32::total_cmp(self, other)
+    }
+}
+
+impl TotalCmp for f64 {
+    fn total_cmp(&self, other: &Self) -> Ordering {
+        f64::total_cmp(self, other)
+    }
+}
+
+mod sealed {
+    /// Prevents traits from being implemented outside of this crate.
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+}
+
+/// The reason a call to [`RcSlice::try_shrink`] or [`ArcSlice::try_shrink`] did not shrink
+/// the buffer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ShrinkError {
+    /// The container type can never be shrunk (its
+    /// [`IS_SHRINKABLE`](RcSliceContainer::IS_SHRINKABLE) is false).
+    NotShrinkable,
+    /// There are other strong references to the buffer.
+    Shared,
+    /// The slice already covers the whole buffer.
+    AlreadyMinimal,
+}
+
+impl fmt::Display for ShrinkError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            ShrinkError::NotShrinkable => "container type cannot be shrunk",
+            ShrinkError::Shared => "buffer is shared with other references",
+            ShrinkError::AlreadyMinimal => "slice already covers the whole buffer",
+        })
+    }
+}
+
+/// The reason a call to [`RcSlice::get_mut_or`] or [`ArcSlice::get_mut_or`] could not borrow
+/// the slice mutably.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MutError {
+    /// There are other strong references to the buffer. Copying the buffer (for example with
+    /// [`RcSlice::make_unique`]) is the only way to get a mutable slice.
+    Shared,
+    /// There are no other strong references, but there are weak references. These can be
+    /// disconnected with [`RcSlice::get_mut_ignoring_weak`].
+    HasWeak,
+}
+
+impl fmt::Display for MutError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            MutError::Shared => "buffer is shared with other references",
+            MutError::HasWeak => "buffer has weak references",
+        })
+    }
 }
 
 /// RcSlice over a byte slice.
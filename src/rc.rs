@@ -1,17 +1,52 @@
+use alloc::boxed::Box;
 use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
 use core::borrow::Borrow;
 use core::cmp::Ordering;
 use core::fmt;
 use core::hash::{Hash, Hasher};
-use core::ops::{Bound, Deref, Range, RangeBounds};
+use core::iter::{FusedIterator, Skip, Sum, Zip};
+use core::ops::{Bound, Deref, Index, Range, RangeBounds};
+use core::slice::{self, SliceIndex};
+use core::str::CharIndices;
 
-use crate::RcSliceContainer;
+use crate::sealed::Sealed;
+use crate::{ArcSlice, MutError, RcSliceContainer, SharedSlice, ShrinkError, TotalCmp};
 
 /// A read-only view into part of an underlying reference-counted slice.
 ///
 /// The associated functions provided for this type do not take a receiver to avoid conflicting
 /// with (present or future) methods on `[T]`, since `RcSlice<T>: Deref<Target = [T]>`. To reduce
 /// the length of code, it is recommended to include `use RcSlice as Rcs` where needed.
+///
+/// # Performance
+///
+/// Cloning an RcSlice, or creating another view of the same buffer (for example with
+/// [`split_at`](RcSlice::split_at) or [`new`](RcSlice::new)), never allocates and never copies the
+/// elements; it only costs a (non-atomic) increment of the `Rc`'s strong count.
+///
+/// # Overflow
+///
+/// Functions that take a count, an index, or a range check it against the slice before doing any
+/// arithmetic with it: they return `None`, saturate, or clamp, as documented on each function. The
+/// few that panic instead (such as [`split_at`](RcSlice::split_at)) say so in a "Panics" section.
+/// Other positions are computed from the slice itself and stay between its start and end, so they
+/// can't overflow either. This includes buffers of `usize::MAX` zero-sized elements.
+///
+/// # Inconsistent containers
+///
+/// Reading the elements (through `Deref`, `AsRef`, or indexing) only panics if the container's
+/// [`RcSliceContainer`] implementation is inconsistent, for example if `len` reports more elements
+/// than `get` can return. The panic then comes from
+/// [`get_unchecked_by_invariant`](RcSliceContainer::get_unchecked_by_invariant), exactly as it
+/// would for [`ArcSlice`](crate::ArcSlice). The containers provided by this crate are always
+/// consistent, so this can't happen with them.
+///
+/// # Thread safety
+///
+/// Like `Rc`, RcSlice is neither `Send` nor `Sync`, since cloning a slice changes a reference
+/// count that isn't atomic. Use [`ArcSlice`](crate::ArcSlice) to share a slice between threads.
 pub struct RcSlice<T: ?Sized> {
     /// The underlying container.
     underlying: Rc<T>,
@@ -49,24 +84,112 @@ impl<T: RcSliceContainer + ?Sized> RcSlice<T> {
     /// assert_eq!(*Rcs::new(&buffer, 0..=2), [2, 4, 6]);
     /// assert_eq!(*Rcs::new(&buffer, 10..), []);
     /// ```
+    ///
+    /// Ranges that go past the end of the buffer are clamped to the end of the buffer.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if the start of `range` is greater than the end of `range`
+    /// (for example, `5..2`), since this is almost always a mistake. In release builds, such
+    /// ranges produce an empty slice instead.
     pub fn new<R: RangeBounds<usize>>(underlying: &Rc<T>, range: R) -> Self {
-        let start = match range.start_bound() {
-            Bound::Excluded(x) => usize::min(x.saturating_add(1), underlying.len()),
-            Bound::Included(x) => usize::min(*x, underlying.len()),
+        RcSlice::from_rc_range(underlying.clone(), range)
+    }
+
+    /// Create a new RcSlice, taking ownership of `underlying` instead of cloning it. Otherwise
+    /// the same as [`new`](RcSlice::new), including its handling of `range`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let slice = Rcs::from_rc_range(Rc::new(vec![2, 4, 6, 8, 10]), 1..4);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(Rc::strong_count(Rcs::inner(&slice)), 1);
+    /// ```
+    pub fn from_rc_range<R: RangeBounds<usize>>(underlying: Rc<T>, range: R) -> Self {
+        let requested_start = match range.start_bound() {
+            Bound::Excluded(x) => x.saturating_add(1),
+            Bound::Included(x) => *x,
             Bound::Unbounded => 0,
         };
-        let end = match range.end_bound() {
-            Bound::Excluded(x) => usize::min(*x, underlying.len()),
-            Bound::Included(x) => usize::min(x.saturating_add(1), underlying.len()),
-            Bound::Unbounded => underlying.len(),
+        let requested_end = match range.end_bound() {
+            Bound::Excluded(x) => Some(*x),
+            Bound::Included(x) => Some(x.saturating_add(1)),
+            Bound::Unbounded => None,
+        };
+        if let Some(requested_end) = requested_end {
+            debug_assert!(
+                requested_start <= requested_end,
+                "range start is greater than range end"
+            );
+        }
+
+        let len = underlying.len();
+        let start = usize::min(requested_start, len);
+        let end = match requested_end {
+            Some(x) => usize::min(x, len),
+            None => len,
         };
         Self {
-            underlying: underlying.clone(),
+            underlying,
             start,
             end: usize::max(start, end),
         }
     }
 
+    /// Create a new RcSlice from a buffer and exact bounds, such as those returned by
+    /// [`inner`](RcSlice::inner) and [`bounds_range`](RcSlice::bounds_range). Returns `None` if
+    /// the range is not valid for the buffer, that is, unless
+    /// `range.start <= range.end <= underlying.len()`.
+    ///
+    /// Unlike [`new`](RcSlice::new), the range is never clamped, so the result always views
+    /// exactly `range`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    ///
+    /// let slice = Rcs::from_parts(buffer.clone(), 1..4).unwrap();
+    /// assert_eq!(*slice, [4, 6, 8]);
+    ///
+    /// assert_eq!(Rcs::from_parts(buffer.clone(), 3..6), None);
+    /// assert_eq!(Rcs::from_parts(buffer, 4..3), None);
+    /// ```
+    pub fn from_parts(underlying: Rc<T>, range: Range<usize>) -> Option<Self> {
+        if range.start <= range.end && range.end <= underlying.len() {
+            Some(Self {
+                underlying,
+                start: range.start,
+                end: range.end,
+            })
+        } else {
+            None
+        }
+    }
+
+    /// Create a new RcSlice from bounds that are already known to be valid, skipping the
+    /// range handling done by [`new`](RcSlice::new).
+    ///
+    /// The caller must ensure that `start <= end <= underlying.len()`. This is only checked in
+    /// debug builds.
+    #[inline]
+    fn from_bounds(underlying: &Rc<T>, start: usize, end: usize) -> Self {
+        debug_assert!(start <= end && end <= underlying.len());
+        Self {
+            underlying: underlying.clone(),
+            start,
+            end,
+        }
+    }
+
     /////////////////////////////////////////////
     // Slice methods, but for RcSlice.
     //
@@ -113,16 +236,7 @@ impl<T: RcSliceContainer + ?Sized> RcSlice<T> {
         it.end == it.start
     }
 
-    /// Divides one slice into two at an index.
-    ///
-    /// The first will contain all indices from `[0, mid)` (excluding the index `mid` itself)
-    /// and the second will contain all indices from `[mid, len)` (excluding the index `len` itself).
-    ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
-    ///
-    /// # Panics
-    ///
-    /// Panics if `mid > it.len()`.
+    /// Returns the elements viewed by the slice. This is the same as `&*it` or `it.as_ref()`.
     ///
     /// ```
     /// # extern crate alloc;
@@ -131,29 +245,16 @@ impl<T: RcSliceContainer + ?Sized> RcSlice<T> {
     /// use RcSlice as Rcs;
     ///
     /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
-    /// let slice = Rcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
     ///
-    /// let (low, high) = Rcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// assert_eq!(Rcs::as_slice(&slice), [4, 6, 8]);
     /// ```
-    pub fn split_at(it: &Self, mid: usize) -> (Self, Self) {
-        assert!(mid <= RcSlice::len(it));
-        // This addition is guaranteed not to overflow because of the above
-        // assertion, and the invariant `start <= end`.
-        let real_mid = it.start + mid;
-
-        (
-            RcSlice::new(&it.underlying, it.start..real_mid),
-            RcSlice::new(&it.underlying, real_mid..it.end),
-        )
+    pub fn as_slice(it: &Self) -> &[T::Item] {
+        it.as_ref()
     }
 
-    /// This is the same as [`split_at`](RcSlice::split_at), but returns `None` if `mid > len` instead
-    /// of panicking.
-    ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    /// Returns an iterator over the elements of the slice, paired with their index in the
+    /// underlying buffer (not their index within the slice).
     ///
     /// ```
     /// # extern crate alloc;
@@ -162,387 +263,413 @@ impl<T: RcSliceContainer + ?Sized> RcSlice<T> {
     /// use RcSlice as Rcs;
     ///
     /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
-    /// let slice = Rcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
-    ///
-    /// let (low, high) = Rcs::try_split_at(&slice, 2).unwrap();
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// let slice = Rcs::new(&buffer, 2..4);
     ///
-    /// assert_eq!(Rcs::try_split_at(&slice, 5), None);
+    /// let mut iter = Rcs::iter_indices(&slice);
+    /// assert_eq!(iter.next(), Some((2, &6)));
+    /// assert_eq!(iter.next(), Some((3, &8)));
+    /// assert_eq!(iter.next(), None);
     /// ```
-    pub fn try_split_at(it: &Self, mid: usize) -> Option<(Self, Self)> {
-        if mid <= RcSlice::len(it) {
-            Some(RcSlice::split_at(it, mid))
-        } else {
-            None
-        }
+    pub fn iter_indices(it: &Self) -> Zip<Range<usize>, slice::Iter<'_, T::Item>> {
+        (it.start..it.end).zip(it.as_ref())
     }
 
-    /// This is an in-place version of [`try_split_at`](RcSlice::try_split_at).
+    /// Returns an iterator over each pair of adjacent elements of the slice, in order. This is
+    /// like `it.windows(2)`, but yields tuples. The iterator is empty if the slice has fewer than
+    /// two elements.
     ///
-    /// If `mid` is valid, mutates `it` to the upper half, and returns the lower half.
-    /// Specifically, this will mutate the slice `it` to be `[mid, len)`, and returns the slice `[0, mid)`.
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
     ///
-    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    /// let buffer: Rc<[i64]> = Rc::new([1, 4, 9, 16, 25]);
+    /// let slice = Rcs::new(&buffer, 1..);
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    /// let deltas: Vec<i64> = Rcs::pairwise(&slice).map(|(a, b)| b - a).collect();
+    /// assert_eq!(deltas, [5, 7, 9]);
+    /// ```
+    pub fn pairwise(it: &Self) -> Zip<slice::Iter<'_, T::Item>, Skip<slice::Iter<'_, T::Item>>> {
+        it.iter().zip(it.iter().skip(1))
+    }
+
+    /// Folds every element of the slice into an accumulator, in order. This is the same as
+    /// `it.iter().fold(init, f)`.
     ///
-    ///  ```
+    /// ```
     /// # extern crate alloc;
     /// # use rc_slice2::RcSlice;
     /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
     /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
-    /// let mut slice = Rcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
     ///
-    /// let (low, high) = Rcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// let checksum = Rcs::fold(&slice, 0u8, |acc, x| acc ^ x);
+    /// assert_eq!(checksum, 4 ^ 6 ^ 8);
+    /// ```
+    pub fn fold<B, F: FnMut(B, &T::Item) -> B>(it: &Self, init: B, f: F) -> B {
+        it.as_ref().iter().fold(init, f)
+    }
+
+    /// Sums the elements of the slice. This is the same as `it.iter().sum()`.
     ///
-    /// let other_high = Rcs::split_off_before(&mut slice, 2).unwrap();
-    /// assert_eq!(*other_high, [4, 6]);
-    /// assert_eq!(*slice, [8, 10]);
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
     ///
-    /// assert_eq!(Rcs::split_off_before(&mut slice, 5), None);
-    /// assert_eq!(*slice, [8, 10]);
+    /// let buffer: Rc<[u32]> = Rc::new([2, 4, 6, 8, 10]);
+    ///
+    /// assert_eq!(Rcs::sum::<u32>(&Rcs::new(&buffer, 1..4)), 18);
+    /// assert_eq!(Rcs::sum::<u32>(&Rcs::new(&buffer, 2..2)), 0);
     /// ```
-    pub fn split_off_before(it: &mut Self, index: usize) -> Option<Self> {
-        let cut = it.start.checked_add(index)?;
-
-        if cut <= it.end {
-            let mut front = it.clone();
-            front.end = cut;
-            it.start = cut;
-
-            Some(front)
-        } else {
-            None
-        }
+    pub fn sum<'a, S: Sum<&'a T::Item>>(it: &'a Self) -> S {
+        it.as_ref().iter().sum()
     }
 
-    /// This is an in-place version of [`try_split_at`](RcSlice::try_split_at).
+    /// Applies `f` to each element of the slice, and collects the results into a new buffer.
+    /// Stops at the first error, and returns it; `f` isn't called on any later elements.
     ///
-    /// If `mid` is valid, mutates `it` to the lower half, and returns the upper half.
-    /// Specifically, this will mutate the slice `it` to be `[0, mid)`, and returns the slice `[mid, len)`.
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
     ///
-    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
+    /// #[derive(Debug, PartialEq)]
+    /// enum Op {
+    ///     Push,
+    ///     Pop,
+    /// }
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    /// let parse = |&b: &u8| match b {
+    ///     0 => Ok(Op::Push),
+    ///     1 => Ok(Op::Pop),
+    ///     other => Err(other),
+    /// };
     ///
-    ///  ```
+    /// let buffer: Rc<[u8]> = Rc::new([0, 0, 1, 7]);
+    /// let ops = Rcs::try_map(&Rcs::new(&buffer, ..3), parse);
+    /// assert_eq!(*ops.unwrap(), [Op::Push, Op::Push, Op::Pop]);
+    /// assert_eq!(Rcs::try_map(&Rcs::new(&buffer, ..), parse), Err(7));
+    /// ```
+    pub fn try_map<U, E, F: FnMut(&T::Item) -> Result<U, E>>(
+        it: &Self,
+        f: F,
+    ) -> Result<RcSlice<[U]>, E> {
+        it.as_ref().iter().map(f).collect()
+    }
+
+    /// Like `[T]::partition_point`, but returns the index of the partition point within the
+    /// underlying buffer, rather than within the slice.
+    ///
+    /// ```
     /// # extern crate alloc;
     /// # use rc_slice2::RcSlice;
     /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
-    /// let mut slice = Rcs::new(&buffer, 1..);
-    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    /// let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    /// let slice = Rcs::new(&buffer, 2..6);
     ///
-    /// let (low, high) = Rcs::split_at(&slice, 2);
-    /// assert_eq!(*low, [4, 6]);
-    /// assert_eq!(*high, [8, 10]);
+    /// assert_eq!(slice.partition_point(|&x| x < 5), 2);
+    /// assert_eq!(Rcs::partition_point_absolute(&slice, |&x| x < 5), 4);
+    /// assert_eq!(buffer[4], 5);
+    /// ```
+    pub fn partition_point_absolute<F: FnMut(&T::Item) -> bool>(it: &Self, pred: F) -> usize {
+        it.start + it.as_ref().partition_point(pred)
+    }
+
+    /// Returns a reference to the elements of the slice as an array, if the slice is exactly `N`
+    /// elements long. Returns `None` otherwise.
     ///
-    /// let other_high = Rcs::split_off_after(&mut slice, 2).unwrap();
-    /// assert_eq!(*other_high, [8, 10]);
-    /// assert_eq!(*slice, [4, 6]);
+    /// Unlike [`try_into_array`](RcSlice::try_into_array), this doesn't copy anything, so it
+    /// works for elements that aren't `Copy`.
     ///
-    /// assert_eq!(Rcs::split_off_after(&mut slice, 5), None);
-    /// assert_eq!(*slice, [4, 6]);
     /// ```
-    pub fn split_off_after(it: &mut Self, index: usize) -> Option<Self> {
-        let cut = it.start.checked_add(index)?;
-
-        if cut <= it.end {
-            let mut back = it.clone();
-            back.start = cut;
-            it.end = cut;
-
-            Some(back)
-        } else {
-            None
-        }
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[String]> = Rc::new(["a".into(), "b".into(), "c".into()]);
+    /// let slice = Rcs::new(&buffer, 1..);
+    ///
+    /// let [b, c] = Rcs::as_array(&slice).unwrap();
+    /// assert_eq!(b, "b");
+    /// assert_eq!(c, "c");
+    /// assert_eq!(Rcs::as_array::<3>(&slice), None);
+    /// ```
+    pub fn as_array<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        <&[T::Item; N]>::try_from(it.as_ref()).ok()
     }
 
-    /////////////////////////////////////////////
-    // Methods related to being a view of a
-    // larger container.
-    //
-
-    /// Returns the starting and ending indices of the view `it` within the underlying slice.
+    /// Returns a reference to the first `N` elements of the slice as an array, or `None` if the
+    /// slice has fewer than `N` elements.
+    ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
     /// # use rc_slice2::RcSlice;
     /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
     /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
     ///
-    /// assert_eq!(Rcs::bounds(&Rcs::new(&buffer, ..)), (0, 5));
-    /// assert_eq!(Rcs::bounds(&Rcs::new(&buffer, 1..3)), (1, 3));
+    /// assert_eq!(Rcs::first_chunk::<2>(&slice), Some(&[4, 6]));
+    /// assert_eq!(Rcs::first_chunk::<4>(&slice), None);
     /// ```
-    #[deprecated(since = "0.4.0", note = "Use `bounds_range` instead.")]
-    pub fn bounds(it: &Self) -> (usize, usize) {
-        (it.start, it.end)
-    }
-
-    /// Returns the inner buffer.
-    pub fn inner(it: &Self) -> &Rc<T> {
-        &it.underlying
+    pub fn first_chunk<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        it.as_ref().first_chunk()
     }
 
-    /// Returns the range that this slice represents.
+    /// Returns a reference to the last `N` elements of the slice as an array, or `None` if the
+    /// slice has fewer than `N` elements.
     ///
-    ///  ```
+    /// ```
     /// # extern crate alloc;
     /// # use rc_slice2::RcSlice;
     /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
     /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
     ///
-    /// assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, ..)), 0..5);
-    /// assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, 1..3)), 1..3);
+    /// assert_eq!(Rcs::last_chunk::<2>(&slice), Some(&[6, 8]));
+    /// assert_eq!(Rcs::last_chunk::<4>(&slice), None);
     /// ```
-    pub fn bounds_range(it: &Self) -> Range<usize> {
-        it.start..it.end
+    pub fn last_chunk<const N: usize>(it: &Self) -> Option<&[T::Item; N]> {
+        it.as_ref().last_chunk()
     }
 
-    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
-    /// elements cut off by this operation. The end of the slice is not affected.
-    ///
-    /// Returns `None` and leaves `self` unchanged if this operation would make the starting index
-    /// greater than the ending index.
+    /// Returns a reference to the first `N` elements of the slice as an array, and a new RcSlice
+    /// of the rest of the slice, or `None` if the slice has fewer than `N` elements.
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..8);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
-    ///
-    /// // Take the first two
-    /// assert_eq!(Rcs::advance(&mut slice, 2), Some([4, 6].as_slice()));
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
-    ///
-    /// // Take three more
-    /// assert_eq!(Rcs::advance(&mut slice, 3), Some([8, 10, 12].as_slice()));
-    /// assert_eq!(*slice, [14, 16]);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let packet = Rcs::new(&buffer, ..);
     ///
-    /// // Try to take three, but can't. Slice is unchanged.
-    /// assert_eq!(Rcs::advance(&mut slice, 3), None);
-    /// assert_eq!(*slice, [14, 16]);
+    /// let (header, body) = Rcs::split_first_chunk::<2>(&packet).unwrap();
+    /// assert_eq!(header, &[2, 4]);
+    /// assert_eq!(*body, [6, 8, 10]);
     ///
-    /// // Take the rest.
-    /// assert_eq!(Rcs::advance(&mut slice, 2), Some([14, 16].as_slice()));
-    /// assert_eq!(*slice, []);
+    /// assert_eq!(Rcs::split_first_chunk::<6>(&packet), None);
     /// ```
-    pub fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
-        let cut = it.start.checked_add(incr)?;
-
-        if cut <= it.end {
-            let shed = it.underlying.get(it.start..cut)?;
-            it.start = cut;
-
-            Some(shed)
-        } else {
-            None
-        }
+    pub fn split_first_chunk<const N: usize>(it: &Self) -> Option<(&[T::Item; N], Self)> {
+        let chunk = RcSlice::first_chunk(it)?;
+        // `first_chunk` succeeded, so `N <= len`, and `start + N` is at most `end`.
+        Some((
+            chunk,
+            RcSlice::from_bounds(&it.underlying, it.start + N, it.end),
+        ))
     }
 
-    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
-    /// elements cut off by this operation. The end of the slice is not affected.
-    ///
-    /// If the slice doesn't contain enough elements, returns all available elements.
+    /// Returns a new RcSlice of the start of the slice, and a reference to the last `N` elements
+    /// of the slice as an array, or `None` if the slice has fewer than `N` elements.
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..8);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
-    ///
-    /// // Take the first two
-    /// assert_eq!(Rcs::saturating_advance(&mut slice, 2), [4, 6]);
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
-    ///
-    /// // Take three more
-    /// assert_eq!(Rcs::saturating_advance(&mut slice, 3), [8, 10, 12]);
-    /// assert_eq!(*slice, [14, 16]);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let packet = Rcs::new(&buffer, ..);
     ///
-    /// // Try to take three, but can only take 2.
-    /// assert_eq!(Rcs::saturating_advance(&mut slice, 3), [14, 16]);
-    /// assert_eq!(*slice, []);
+    /// let (body, trailer) = Rcs::split_last_chunk::<2>(&packet).unwrap();
+    /// assert_eq!(*body, [2, 4, 6]);
+    /// assert_eq!(trailer, &[8, 10]);
     ///
-    /// // Try to take two, but slice is empty.
-    /// assert_eq!(Rcs::saturating_advance(&mut slice, 2), []);
-    /// assert_eq!(*slice, []);
+    /// assert_eq!(Rcs::split_last_chunk::<6>(&packet), None);
     /// ```
-    pub fn saturating_advance(it: &mut Self, incr: usize) -> &[T::Item] {
-        let cut = usize::min(it.start.saturating_add(incr), it.end);
-
-        // TODO: Evaluate whether this will panic, and when.
-        // I believe it will only panic if the container trait impl
-        // is implemented weirdly.
-        let shed = it.underlying.get(it.start..cut).unwrap();
-        it.start = cut;
-        shed
+    pub fn split_last_chunk<const N: usize>(it: &Self) -> Option<(Self, &[T::Item; N])> {
+        let chunk = RcSlice::last_chunk(it)?;
+        // `last_chunk` succeeded, so `N <= len`, and `end - N` is at least `start`.
+        Some((
+            RcSlice::from_bounds(&it.underlying, it.start, it.end - N),
+            chunk,
+        ))
     }
 
-    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
-    /// elements cut off by this operation.
+    /// Divides one slice into two at an index.
     ///
-    /// Returns `None` and leaves `it` unchanged if this operation would make the ending index less
-    /// than the starting index.
+    /// The first will contain all indices from `[0, mid)` (excluding the index `mid` itself)
+    /// and the second will contain all indices from `[mid, len)` (excluding the index `len` itself).
     ///
     /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
+    /// Each half holds its own strong reference to the buffer, even if it is empty (when `mid` is
+    /// `0` or `len`), so this always adds exactly two to the strong count. If one half would be
+    /// discarded, [`split_off_before`](RcSlice::split_off_before) and
+    /// [`split_off_after`](RcSlice::split_off_after) only add one, since they reuse `it`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `mid > it.len()`.
+    ///
     /// ```
-    /// # #![allow(deprecated)]
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..8);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
     ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    /// let (low, high) = Rcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    /// ```
+    pub fn split_at(it: &Self, mid: usize) -> (Self, Self) {
+        assert!(mid <= RcSlice::len(it), "split index out of bounds");
+        // This addition should never overflow because of the above assertion, and
+        // the invariant `start <= end`. It is checked anyway, so that a broken
+        // invariant panics instead of silently wrapping around.
+        let real_mid = it
+            .start
+            .checked_add(mid)
+            .expect("split index overflowed usize");
+
+        (
+            RcSlice::from_bounds(&it.underlying, it.start, real_mid),
+            RcSlice::from_bounds(&it.underlying, real_mid, it.end),
+        )
+    }
+
+    /// This is the same as [`split_at`](RcSlice::split_at), but returns `None` if `mid > len` instead
+    /// of panicking.
     ///
-    /// // Take the first two
-    /// assert_eq!(Rcs::retract(&mut slice, 2), Some([14, 16].as_slice()));
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
-    /// // Take three more
-    /// assert_eq!(Rcs::retract(&mut slice, 3), Some([8, 10, 12].as_slice()));
-    /// assert_eq!(*slice, [4, 6]);
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
     ///
-    /// // Try to take three, but can't. Slice is unchanged.
-    /// assert_eq!(Rcs::retract(&mut slice, 3), None);
-    /// assert_eq!(*slice, [4, 6]);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
     ///
-    /// // Take the rest.
-    /// assert_eq!(Rcs::retract(&mut slice, 2), Some([4, 6].as_slice()));
-    /// assert_eq!(*slice, []);
+    /// let (low, high) = Rcs::try_split_at(&slice, 2).unwrap();
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// assert_eq!(Rcs::try_split_at(&slice, 5), None);
     /// ```
-    pub fn retract(it: &mut Self, decr: usize) -> Option<&[T::Item]> {
-        let cut = it.end.checked_sub(decr)?;
-
-        if cut >= it.start {
-            let shed = it.underlying.get(cut..it.end)?;
-            it.end = cut;
-
-            Some(shed)
+    pub fn try_split_at(it: &Self, mid: usize) -> Option<(Self, Self)> {
+        if mid <= RcSlice::len(it) {
+            Some(RcSlice::split_at(it, mid))
         } else {
             None
         }
     }
 
-    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
-    /// elements cut off by this operation.
-    ///
-    /// If the slice doesn't contain enough elements, returns all available elements.
-    ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    /// This is the same as [`try_split_at`](RcSlice::try_split_at). It is provided to match the
+    /// name of `[T]::split_at_checked`.
     ///
     /// ```
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..8);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..);
     ///
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    /// let (low, high) = Rcs::split_at_checked(&slice, 2).unwrap();
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
     ///
-    /// // Take the first two
-    /// assert_eq!(Rcs::saturating_retract(&mut slice, 2), [14, 16]);
-    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    /// assert_eq!(Rcs::split_at_checked(&slice, 5), None);
+    /// ```
+    #[inline]
+    pub fn split_at_checked(it: &Self, mid: usize) -> Option<(Self, Self)> {
+        RcSlice::try_split_at(it, mid)
+    }
+
+    /// Splits the slice at its midpoint. If the length is odd, the first half gets the extra
+    /// element. Both halves share the same buffer.
     ///
-    /// // Take three more
-    /// assert_eq!(Rcs::saturating_retract(&mut slice, 3), [8, 10, 12]);
-    /// assert_eq!(*slice, [4, 6]);
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
     ///
-    /// // Try to take three, but can only take 2.
-    /// assert_eq!(Rcs::saturating_retract(&mut slice, 3), [4, 6]);
-    /// assert_eq!(*slice, []);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let (left, right) = Rcs::halve(&Rcs::from(buffer));
     ///
-    /// // Try to take two, but slice is empty.
-    /// assert_eq!(Rcs::saturating_retract(&mut slice, 2), []);
-    /// assert_eq!(*slice, []);
+    /// assert_eq!(*left, [2, 4, 6]);
+    /// assert_eq!(*right, [8, 10]);
     /// ```
-    pub fn saturating_retract(it: &mut Self, decr: usize) -> &[T::Item] {
-        let cut = usize::max(it.end.saturating_sub(decr), it.start);
-
-        // TODO: Evaluate whether this will panic, and when.
-        // I believe it will only panic if the container trait impl
-        // is implemented weirdly.
-        let shed = it.underlying.get(cut..it.end).unwrap();
-        it.end = cut;
-        shed
+    pub fn halve(it: &Self) -> (Self, Self) {
+        let len = RcSlice::len(it);
+        RcSlice::split_at(it, len - len / 2)
     }
 
-    /// Adjusts the range of the slice. Roughly equivalent to `RcSlice::new(it.inner(), new_range)`,
-    /// but the change is made in-place.
+    /// Divides the slice into `N` consecutive parts of nearly equal length. If the length isn't
+    /// a multiple of `N`, the earlier parts are one element longer than the later ones.
     ///
-    /// Returns the actual range of the new slice.
+    /// Each part is a new RcSlice referencing the same buffer. This function DOES NOT DELETE
+    /// unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
-    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
     ///
     /// ```
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..4);
+    /// let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let slice = Rcs::from(buffer);
     ///
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(Rcs::change_range(&mut slice, 3..), 3..9);
-    /// assert_eq!(*slice, [8, 10, 12, 14, 16, 18]);
+    /// let [a, b, c] = Rcs::split_evenly(&slice);
+    /// assert_eq!(*a, [1, 2, 3]);
+    /// assert_eq!(*b, [4, 5]);
+    /// assert_eq!(*c, [6, 7]);
     /// ```
-    pub fn change_range<R: RangeBounds<usize>>(it: &mut Self, new_range: R) -> Range<usize> {
-        let mut start = match new_range.start_bound() {
-            Bound::Excluded(x) => usize::min(x.saturating_add(1), it.underlying.len()),
-            Bound::Included(x) => usize::min(*x, it.underlying.len()),
-            Bound::Unbounded => 0,
-        };
-        let end = match new_range.end_bound() {
-            Bound::Excluded(x) => usize::min(*x, it.underlying.len()),
-            Bound::Included(x) => usize::min(x.saturating_add(1), it.underlying.len()),
-            Bound::Unbounded => it.underlying.len(),
-        };
-        start = usize::min(start, end);
-        it.start = start;
-        it.end = end;
-        start..end
-    }
+    pub fn split_evenly<const N: usize>(it: &Self) -> [Self; N] {
+        assert!(N != 0, "cannot split a slice into 0 parts");
 
-    /////////////////////////////////////////////
-    // Methods related to `Rc`
+        let len = RcSlice::len(it);
+        let (short_len, extra) = (len / N, len % N);
+        // The parts add up to `len`, so `start` never passes `end`.
+        let mut start = it.start;
+        core::array::from_fn(|i| {
+            let part_len = if i < extra { short_len + 1 } else { short_len };
+            let part = RcSlice::from_bounds(&it.underlying, start, start + part_len);
+            start += part_len;
+            part
+        })
+    }
 
-    /// Returns a mutable reference into the given slice, if there are no other
-    /// RcSlice pointers to anywhere else in the underlying array.
+    /// Returns an iterator over `chunk_size` elements of the slice at a time, starting at the
+    /// end, like [`slice::rchunks`]. Each chunk is a slice of the same buffer. If the length
+    /// isn't a multiple of `chunk_size`, the last chunk (the front of the slice) is shorter.
+    ///
+    /// The iterator is double-ended, so `.rev()` yields the short chunk first, followed by full
+    /// chunks that line up with the end of the slice.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
     ///
     /// ```
     /// # extern crate alloc;
@@ -550,202 +677,4866 @@ impl<T: RcSliceContainer + ?Sized> RcSlice<T> {
     /// # use rc_slice2::RcSlice;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let mut slice = Rcs::new(&buffer, 1..8);
+    /// let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7]);
+    /// let slice = Rcs::from(buffer);
     ///
-    /// // The original Rc buffer is still alive, so get_mut doesn't work.
-    /// assert_eq!(Rcs::get_mut(&mut slice), None);
+    /// let mut chunks = Rcs::rchunks(&slice, 3);
+    /// assert_eq!(*chunks.next().unwrap(), [5, 6, 7]);
+    /// assert_eq!(*chunks.next().unwrap(), [2, 3, 4]);
+    /// assert_eq!(*chunks.next().unwrap(), [1]);
+    /// assert_eq!(chunks.next(), None);
     ///
-    /// std::mem::drop(buffer);
-    /// // Now there's only one RcSlice for this array, so get_mut is allowed.
-    /// assert_eq!(Rcs::get_mut(&mut slice), Some([4, 6, 8, 10, 12, 14, 16].as_mut_slice()));
+    /// let mut aligned = Rcs::rchunks(&slice, 3).rev();
+    /// assert_eq!(*aligned.next().unwrap(), [1]);
+    /// assert_eq!(*aligned.next().unwrap(), [2, 3, 4]);
+    /// ```
+    pub fn rchunks(it: &Self, chunk_size: usize) -> RcRChunks<T> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        RcRChunks {
+            rest: it.clone(),
+            chunk_size,
+        }
+    }
+
+    /// This is an in-place version of [`try_split_at`](RcSlice::try_split_at).
     ///
-    /// // We can use the mutable reference.
-    /// Rcs::get_mut(&mut slice).unwrap().reverse();
-    /// assert_eq!(*slice, [16, 14, 12, 10, 8, 6, 4]);
+    /// If `mid` is valid, mutates `it` to the upper half, and returns the lower half.
+    /// Specifically, this will mutate the slice `it` to be `[mid, len)`, and returns the slice `[0, mid)`.
     ///
-    /// // The mutation changed the original buffer (though we need to create a new reference to see it).
-    /// let buffer = Rc::clone(Rcs::inner(&slice));
-    /// assert_eq!(*buffer, [2, 16, 14, 12, 10, 8, 6, 4, 18]);
+    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
     ///
-    /// // Drop the buffer Rc again.
-    /// std::mem::drop(buffer);
-    /// assert_eq!(Rcs::get_mut(&mut slice), Some([16, 14, 12, 10, 8, 6, 4].as_mut_slice()));
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
     ///
-    /// // Disjoint RcSlices still prevent mutation.
-    /// let (mut low, high) = Rcs::split_at(&slice, 4);
-    /// assert_eq!(*low, [16, 14, 12, 10]);
-    /// assert_eq!(*high, [8, 6, 4]);
-    /// assert_eq!(Rcs::get_mut(&mut low), None);
-    /// ```
-    pub fn get_mut(it: &mut Self) -> Option<&mut [T::Item]> {
-        let start = it.start;
-        let end = it.end;
-        Rc::get_mut(&mut it.underlying)
-            .map(|s| s.get_mut(start..end))
-            .flatten()
-    }
-
-    /// Checks if two RcSlices reference the same slice of the same array in memory.
+    /// The returned slice holds a new strong reference to the buffer, even if it is empty, so
+    /// this adds exactly one to the strong count when it succeeds.
     ///
-    /// ```
+    ///  ```
     /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
     /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
     /// use RcSlice as Rcs;
     ///
-    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let first_slice = Rcs::new(&buffer, ..4);
-    /// let second_slice = Rcs::new(&buffer, ..8);
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
     ///
-    /// // These slices point to the same buffer, but different ranges.
-    /// assert_eq!(Rcs::ptr_eq(&first_slice, &second_slice), false);
+    /// let (low, high) = Rcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
     ///
-    /// let (low, _) = Rcs::split_at(&second_slice, 4);
-    /// assert_eq!(first_slice, low);
+    /// let other_high = Rcs::split_off_before(&mut slice, 2).unwrap();
+    /// assert_eq!(*other_high, [4, 6]);
+    /// assert_eq!(*slice, [8, 10]);
     ///
-    /// // These slices use the same buffer, and the same range.
-    /// assert_eq!(Rcs::ptr_eq(&first_slice, &low), true);
+    /// assert_eq!(Rcs::split_off_before(&mut slice, 5), None);
+    /// assert_eq!(*slice, [8, 10]);
+    /// ```
+    pub fn split_off_before(it: &mut Self, index: usize) -> Option<Self> {
+        let cut = it.start.checked_add(index)?;
+
+        if cut <= it.end {
+            let front = RcSlice::from_bounds(&it.underlying, it.start, cut);
+            it.start = cut;
+
+            Some(front)
+        } else {
+            None
+        }
+    }
+
+    /// This is an in-place version of [`try_split_at`](RcSlice::try_split_at).
     ///
-    /// let other_buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
-    /// let other_slice = Rcs::new(&other_buffer, ..4);
+    /// If `mid` is valid, mutates `it` to the lower half, and returns the upper half.
+    /// Specifically, this will mutate the slice `it` to be `[0, mid)`, and returns the slice `[mid, len)`.
     ///
-    /// // The elements for `other_slice` are the same
-    /// assert_eq!(first_slice, other_slice);
+    /// Returns `None` and leaves `it` unchanged if `mid` is outside the bounds of the slice.
     ///
-    /// // But they use different memory locations.
-    /// assert_eq!(Rcs::ptr_eq(&first_slice, &other_slice), false);
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// The returned slice holds a new strong reference to the buffer, even if it is empty, so
+    /// this adds exactly one to the strong count when it succeeds.
+    ///
+    ///  ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..);
+    /// assert_eq!(*slice, [4, 6, 8, 10]);
+    ///
+    /// let (low, high) = Rcs::split_at(&slice, 2);
+    /// assert_eq!(*low, [4, 6]);
+    /// assert_eq!(*high, [8, 10]);
+    ///
+    /// let other_high = Rcs::split_off_after(&mut slice, 2).unwrap();
+    /// assert_eq!(*other_high, [8, 10]);
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// assert_eq!(Rcs::split_off_after(&mut slice, 5), None);
+    /// assert_eq!(*slice, [4, 6]);
     /// ```
-    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
-        Rc::ptr_eq(&this.underlying, &other.underlying)
-            && this.start == other.start
-            && this.end == other.end
+    pub fn split_off_after(it: &mut Self, index: usize) -> Option<Self> {
+        let cut = it.start.checked_add(index)?;
+
+        if cut <= it.end {
+            let back = RcSlice::from_bounds(&it.underlying, cut, it.end);
+            it.end = cut;
+
+            Some(back)
+        } else {
+            None
+        }
+    }
+
+    /////////////////////////////////////////////
+    // Methods related to being a view of a
+    // larger container.
+    //
+
+    /// Returns the starting and ending indices of the view `it` within the underlying slice.
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    ///
+    /// assert_eq!(Rcs::bounds(&Rcs::new(&buffer, ..)), (0, 5));
+    /// assert_eq!(Rcs::bounds(&Rcs::new(&buffer, 1..3)), (1, 3));
+    /// ```
+    #[deprecated(since = "0.4.0", note = "Use `bounds_range` instead.")]
+    pub fn bounds(it: &Self) -> (usize, usize) {
+        (it.start, it.end)
+    }
+
+    /// Returns the inner buffer.
+    pub fn inner(it: &Self) -> &Rc<T> {
+        &it.underlying
+    }
+
+    /// Returns a new handle to the inner buffer. This is the same as
+    /// `Rc::clone(RcSlice::inner(it))`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let slice = Rcs::from_rc_range(Rc::new(vec![2, 4, 6, 8, 10]), 1..3);
+    /// let buffer = Rcs::to_inner(&slice);
+    ///
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// assert_eq!(Rc::strong_count(&buffer), 2);
+    /// ```
+    pub fn to_inner(it: &Self) -> Rc<T> {
+        Rc::clone(&it.underlying)
+    }
+
+    /// Returns the range that this slice represents.
+    ///
+    ///  ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    ///
+    /// assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, ..)), 0..5);
+    /// assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, 1..3)), 1..3);
+    /// ```
+    pub fn bounds_range(it: &Self) -> Range<usize> {
+        it.start..it.end
+    }
+
+    /// Returns the range of memory addresses occupied by the elements of the slice, from the
+    /// first element to one past the last element. This is the same as `[T]::as_ptr_range`.
+    ///
+    /// This is useful for matching pointers handed back from FFI code to the slice they came
+    /// from. The pointers are only valid while the underlying buffer is alive, and must not be
+    /// used to mutate the buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..3);
+    ///
+    /// let range = Rcs::ptr_range(&slice);
+    /// assert_eq!(range.start, &buffer[1] as *const u8);
+    /// assert_eq!(range.end, &buffer[3] as *const u8);
+    /// assert!(range.contains(&(&buffer[2] as *const u8)));
+    /// ```
+    pub fn ptr_range(it: &Self) -> Range<*const T::Item> {
+        it.as_ref().as_ptr_range()
+    }
+
+    /// Returns the alignment of the elements of the slice, `core::mem::align_of::<T::Item>()`.
+    ///
+    /// A slice always starts a whole number of elements into its buffer, so the pointer to the
+    /// first element (and every other element) is always aligned to this value, no matter how
+    /// the slice was created or split.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u32]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 3..);
+    ///
+    /// assert_eq!(Rcs::data_align(&slice), 4);
+    /// assert_eq!(slice.as_ptr() as usize % Rcs::data_align(&slice), 0);
+    /// ```
+    pub fn data_align(_it: &Self) -> usize {
+        core::mem::align_of::<T::Item>()
+    }
+
+    /// Returns the first element of the slice without advancing past it, or `None` if the slice
+    /// is empty. Equivalent to `it.first()`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// while let Some(&next) = Rcs::peek(&slice) {
+    ///     if next > 4 {
+    ///         break;
+    ///     }
+    ///     Rcs::advance(&mut slice, 1);
+    /// }
+    /// assert_eq!(*slice, [6]);
+    /// ```
+    pub fn peek(it: &Self) -> Option<&T::Item> {
+        it.first()
+    }
+
+    /// Returns the first `n` elements of the slice without advancing past them, or `None` if the
+    /// slice has fewer than `n` elements.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6]);
+    /// let slice = Rcs::from(buffer);
+    ///
+    /// assert_eq!(Rcs::peek_n(&slice, 2), Some([2, 4].as_slice()));
+    /// assert_eq!(Rcs::peek_n(&slice, 4), None);
+    /// assert_eq!(*slice, [2, 4, 6]);
+    /// ```
+    pub fn peek_n(it: &Self, n: usize) -> Option<&[T::Item]> {
+        it.get(..n)
+    }
+
+    /// Returns a reference to the element at index `i` of the slice, or `None` if `i` is out of
+    /// bounds. The index is relative to the start of the slice, not the start of the buffer.
+    ///
+    /// This is the non-panicking form of `slice[i]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Rcs::get_index(&slice, 0), Some(&4));
+    /// assert_eq!(Rcs::get_index(&slice, 2), Some(&8));
+    /// assert_eq!(Rcs::get_index(&slice, 3), None);
+    /// ```
+    pub fn get_index(it: &Self, i: usize) -> Option<&T::Item> {
+        Self::as_slice(it).get(i)
+    }
+
+    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
+    /// elements cut off by this operation. The end of the slice is not affected.
+    ///
+    /// Returns `None` and leaves `self` unchanged if this operation would make the starting index
+    /// greater than the ending index.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Rcs::advance(&mut slice, 2), Some([4, 6].as_slice()));
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Rcs::advance(&mut slice, 3), Some([8, 10, 12].as_slice()));
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Try to take three, but can't. Slice is unchanged.
+    /// assert_eq!(Rcs::advance(&mut slice, 3), None);
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Take the rest.
+    /// assert_eq!(Rcs::advance(&mut slice, 2), Some([14, 16].as_slice()));
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
+        let cut = it.start.checked_add(incr)?;
+
+        if cut <= it.end {
+            let shed = it.underlying.get(it.start..cut)?;
+            it.start = cut;
+
+            Some(shed)
+        } else {
+            None
+        }
+    }
+
+    /// Increases the starting index of `self` by `incr` places, and returns a reference to the
+    /// elements cut off by this operation. The end of the slice is not affected.
+    ///
+    /// If the slice doesn't contain enough elements, returns all available elements.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Rcs::saturating_advance(&mut slice, 2), [4, 6]);
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Rcs::saturating_advance(&mut slice, 3), [8, 10, 12]);
+    /// assert_eq!(*slice, [14, 16]);
+    ///
+    /// // Try to take three, but can only take 2.
+    /// assert_eq!(Rcs::saturating_advance(&mut slice, 3), [14, 16]);
+    /// assert_eq!(*slice, []);
+    ///
+    /// // Try to take two, but slice is empty.
+    /// assert_eq!(Rcs::saturating_advance(&mut slice, 2), []);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_advance(it: &mut Self, incr: usize) -> &[T::Item] {
+        let cut = usize::min(it.start.saturating_add(incr), it.end);
+
+        let shed = it.underlying.get_unchecked_by_invariant(it.start..cut);
+        it.start = cut;
+        shed
     }
 
-    /// This is like a normal slice indexing operation, but produces an RcSlice instead.
-    /// ```
-    /// # extern crate alloc;
-    /// # use rc_slice2::RcSlice;
-    /// # use alloc::vec::Vec;
-    /// # use alloc::rc::Rc;
-    /// use RcSlice as Rcs;
-    ///
-    /// let buffer: Rc<Vec<u8>> = Rc::new((0..10).into_iter().collect());
-    /// let slice = Rcs::new(&buffer, 3..8);
-    /// assert_eq!(*slice, [3, 4, 5, 6, 7]);
-    ///
-    /// // Returns an RcSlice of the same buffer.
-    /// assert_eq!(Rcs::index(&slice, 2..4), Rcs::new(&buffer, 5..7));
-    ///
-    /// // All kinds of ranges work.
-    /// assert_eq!(*Rcs::index(&slice, ..), [3, 4, 5, 6, 7]);
-    /// assert_eq!(*Rcs::index(&slice, 1..), [4, 5, 6, 7]);
-    /// assert_eq!(*Rcs::index(&slice, 8..), []);
-    /// assert_eq!(*Rcs::index(&slice, 5..), []);
-    /// assert_eq!(*Rcs::index(&slice, ..2), [3, 4]);
-    /// assert_eq!(*Rcs::index(&slice, ..70), [3, 4, 5, 6, 7]);
-    /// assert_eq!(*Rcs::index(&slice, 3..8), [6, 7]);
-    ///```
-    pub fn index<R: RangeBounds<usize>>(it: &Self, range: R) -> Self {
-        use Bound::*;
-        let mut start = match range.start_bound() {
-            Unbounded => it.start,
-            Included(s) => it.start.saturating_add(*s),
-            Excluded(s) => it.start.saturating_add(*s).saturating_sub(1),
-        };
-        let end = match range.end_bound() {
-            Unbounded => it.end,
-            Included(s) => usize::min(it.end, it.start.saturating_add(*s).saturating_add(1)),
-            Excluded(s) => usize::min(it.end, it.start.saturating_add(*s)),
-        };
-        start = usize::min(start, end);
-        RcSlice {
-            underlying: it.underlying.clone(),
-            start,
-            end,
+    /// Like [`saturating_advance`](RcSlice::saturating_advance), but returns the new bounds of
+    /// the slice instead of the cut-off elements. Because the result doesn't borrow the buffer,
+    /// the slice can be used mutably again right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Rcs::saturating_advance_to(&mut slice, 2), 3..4);
+    /// assert_eq!(*slice, [8]);
+    /// assert_eq!(Rcs::saturating_advance_to(&mut slice, 2), 4..4);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_advance_to(it: &mut Self, incr: usize) -> Range<usize> {
+        it.start = usize::min(it.start.saturating_add(incr), it.end);
+        it.start..it.end
+    }
+
+    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
+    /// elements cut off by this operation.
+    ///
+    /// Returns `None` and leaves `it` unchanged if this operation would make the ending index less
+    /// than the starting index.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # #![allow(deprecated)]
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Rcs::retract(&mut slice, 2), Some([14, 16].as_slice()));
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Rcs::retract(&mut slice, 3), Some([8, 10, 12].as_slice()));
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Try to take three, but can't. Slice is unchanged.
+    /// assert_eq!(Rcs::retract(&mut slice, 3), None);
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Take the rest.
+    /// assert_eq!(Rcs::retract(&mut slice, 2), Some([4, 6].as_slice()));
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn retract(it: &mut Self, decr: usize) -> Option<&[T::Item]> {
+        let cut = it.end.checked_sub(decr)?;
+
+        if cut >= it.start {
+            let shed = it.underlying.get(cut..it.end)?;
+            it.end = cut;
+
+            Some(shed)
+        } else {
+            None
+        }
+    }
+
+    /// Decreases the ending index of `it` by `decr` places, and returns a reference to the
+    /// elements cut off by this operation.
+    ///
+    /// If the slice doesn't contain enough elements, returns all available elements.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..8);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12, 14, 16]);
+    ///
+    /// // Take the first two
+    /// assert_eq!(Rcs::saturating_retract(&mut slice, 2), [14, 16]);
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    ///
+    /// // Take three more
+    /// assert_eq!(Rcs::saturating_retract(&mut slice, 3), [8, 10, 12]);
+    /// assert_eq!(*slice, [4, 6]);
+    ///
+    /// // Try to take three, but can only take 2.
+    /// assert_eq!(Rcs::saturating_retract(&mut slice, 3), [4, 6]);
+    /// assert_eq!(*slice, []);
+    ///
+    /// // Try to take two, but slice is empty.
+    /// assert_eq!(Rcs::saturating_retract(&mut slice, 2), []);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_retract(it: &mut Self, decr: usize) -> &[T::Item] {
+        let cut = usize::max(it.end.saturating_sub(decr), it.start);
+
+        let shed = it.underlying.get_unchecked_by_invariant(cut..it.end);
+        it.end = cut;
+        shed
+    }
+
+    /// Like [`saturating_retract`](RcSlice::saturating_retract), but returns the new bounds of
+    /// the slice instead of the cut-off elements. Because the result doesn't borrow the buffer,
+    /// the slice can be used mutably again right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Rcs::saturating_retract_to(&mut slice, 2), 1..2);
+    /// assert_eq!(*slice, [4]);
+    /// assert_eq!(Rcs::saturating_retract_to(&mut slice, 2), 1..1);
+    /// assert_eq!(*slice, []);
+    /// ```
+    pub fn saturating_retract_to(it: &mut Self, decr: usize) -> Range<usize> {
+        it.end = usize::max(it.end.saturating_sub(decr), it.start);
+        it.start..it.end
+    }
+
+    /// Increases the starting index of the slice past the leading elements for which `pred`
+    /// returns `true`. Returns the elements that were cut off, which may be empty.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"  let x"[..]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// assert_eq!(Rcs::advance_while(&mut slice, |b| *b == b' '), b"  ");
+    /// assert_eq!(Rcs::advance_while(&mut slice, u8::is_ascii_alphabetic), b"let");
+    /// assert_eq!(Rcs::advance_while(&mut slice, u8::is_ascii_alphabetic), b"");
+    /// assert_eq!(*slice, *b" x");
+    /// ```
+    pub fn advance_while<F: FnMut(&T::Item) -> bool>(it: &mut Self, mut pred: F) -> &[T::Item] {
+        let count = it.iter().position(|x| !pred(x)).unwrap_or(it.len());
+        RcSlice::saturating_advance(it, count)
+    }
+
+    /// Decreases the ending index of the slice past the trailing elements for which `pred`
+    /// returns `true`. Returns the elements that were cut off, which may be empty.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"let x  "[..]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// assert_eq!(Rcs::retract_while(&mut slice, |b| *b == b' '), b"  ");
+    /// assert_eq!(Rcs::retract_while(&mut slice, |b| *b == b' '), b"");
+    /// assert_eq!(*slice, *b"let x");
+    /// ```
+    pub fn retract_while<F: FnMut(&T::Item) -> bool>(it: &mut Self, mut pred: F) -> &[T::Item] {
+        let count = it.iter().rev().position(|x| !pred(x)).unwrap_or(it.len());
+        RcSlice::saturating_retract(it, count)
+    }
+
+    /// Creates a new RcSlice of part of this slice, sharing the same buffer. The range is
+    /// relative to `it`, and is clamped to the bounds of `it`. Unlike
+    /// [`change_range`](RcSlice::change_range), `it` is not modified.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14]);
+    /// let slice = Rcs::new(&buffer, 1..6);
+    ///
+    /// let inner = Rcs::subslice(&slice, 1..);
+    /// assert_eq!(*inner, [6, 8, 10, 12]);
+    /// assert_eq!(*Rcs::subslice(&inner, ..2), [6, 8]);
+    /// assert_eq!(*Rcs::subslice(&inner, 3..10), [12]);
+    /// assert_eq!(*slice, [4, 6, 8, 10, 12]);
+    /// ```
+    pub fn subslice<R: RangeBounds<usize>>(it: &Self, range: R) -> Self {
+        let range = RcSlice::relative_range(it, range);
+        RcSlice::from_bounds(&it.underlying, range.start, range.end)
+    }
+
+    /// Adjusts the range of the slice. Roughly equivalent to `RcSlice::new(it.inner(), new_range)`,
+    /// but the change is made in-place.
+    ///
+    /// Returns the actual range of the new slice.
+    ///
+    /// This function DOES NOT DELETE unused parts of the original buffer. See [`shrink`](RcSlice::shrink).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(Rcs::change_range(&mut slice, 3..), 3..9);
+    /// assert_eq!(*slice, [8, 10, 12, 14, 16, 18]);
+    /// ```
+    pub fn change_range<R: RangeBounds<usize>>(it: &mut Self, new_range: R) -> Range<usize> {
+        let mut start = match new_range.start_bound() {
+            Bound::Excluded(x) => usize::min(x.saturating_add(1), it.underlying.len()),
+            Bound::Included(x) => usize::min(*x, it.underlying.len()),
+            Bound::Unbounded => 0,
+        };
+        let end = match new_range.end_bound() {
+            Bound::Excluded(x) => usize::min(*x, it.underlying.len()),
+            Bound::Included(x) => usize::min(x.saturating_add(1), it.underlying.len()),
+            Bound::Unbounded => it.underlying.len(),
+        };
+        start = usize::min(start, end);
+        it.start = start;
+        it.end = end;
+        start..end
+    }
+
+    /////////////////////////////////////////////
+    // Methods related to `Rc`
+
+    /// Returns a mutable reference into the given slice, if there are no other
+    /// RcSlice pointers to anywhere else in the underlying array.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let mut slice = Rcs::new(&buffer, 1..8);
+    ///
+    /// // The original Rc buffer is still alive, so get_mut doesn't work.
+    /// assert_eq!(Rcs::get_mut(&mut slice), None);
+    ///
+    /// std::mem::drop(buffer);
+    /// // Now there's only one RcSlice for this array, so get_mut is allowed.
+    /// assert_eq!(Rcs::get_mut(&mut slice), Some([4, 6, 8, 10, 12, 14, 16].as_mut_slice()));
+    ///
+    /// // We can use the mutable reference.
+    /// Rcs::get_mut(&mut slice).unwrap().reverse();
+    /// assert_eq!(*slice, [16, 14, 12, 10, 8, 6, 4]);
+    ///
+    /// // The mutation changed the original buffer (though we need to create a new reference to see it).
+    /// let buffer = Rc::clone(Rcs::inner(&slice));
+    /// assert_eq!(*buffer, [2, 16, 14, 12, 10, 8, 6, 4, 18]);
+    ///
+    /// // Drop the buffer Rc again.
+    /// std::mem::drop(buffer);
+    /// assert_eq!(Rcs::get_mut(&mut slice), Some([16, 14, 12, 10, 8, 6, 4].as_mut_slice()));
+    ///
+    /// // Disjoint RcSlices still prevent mutation.
+    /// let (mut low, high) = Rcs::split_at(&slice, 4);
+    /// assert_eq!(*low, [16, 14, 12, 10]);
+    /// assert_eq!(*high, [8, 6, 4]);
+    /// assert_eq!(Rcs::get_mut(&mut low), None);
+    /// ```
+    pub fn get_mut(it: &mut Self) -> Option<&mut [T::Item]> {
+        let start = it.start;
+        let end = it.end;
+        Rc::get_mut(&mut it.underlying).and_then(|s| s.get_mut(start..end))
+    }
+
+    /// Calls `f` on the mutable elements of the slice, and returns its result, if there are no
+    /// other RcSlice or Rc pointers to the buffer (see [`get_mut`](RcSlice::get_mut)).
+    /// Otherwise, returns `None` without calling `f`.
+    ///
+    /// The mutable borrow ends with the closure, which makes it easy to pair a mutation with
+    /// bookkeeping (like setting a dirty flag) at the call site.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Rcs::modify(&mut slice, |s| s.len()), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// let mut dirty = false;
+    /// let old_first = Rcs::modify(&mut slice, |s| core::mem::replace(&mut s[0], 0));
+    /// dirty |= old_first.is_some();
+    /// assert_eq!(old_first, Some(4));
+    /// assert!(dirty);
+    /// assert_eq!(*slice, [0, 6, 8]);
+    /// ```
+    pub fn modify<R, F: FnOnce(&mut [T::Item]) -> R>(it: &mut Self, f: F) -> Option<R> {
+        RcSlice::get_mut(it).map(f)
+    }
+
+    /// Like [`get_mut`](RcSlice::get_mut), but returns the reason the slice can't be borrowed
+    /// mutably: [`MutError::Shared`] if there are other strong references to the buffer, or
+    /// [`MutError::HasWeak`] if there are only weak references.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::{MutError, RcSlice};
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    /// assert_eq!(Rcs::get_mut_or(&mut slice), Err(MutError::Shared));
+    ///
+    /// let weak = Rc::downgrade(&buffer);
+    /// core::mem::drop(buffer);
+    /// assert_eq!(Rcs::get_mut_or(&mut slice), Err(MutError::HasWeak));
+    ///
+    /// core::mem::drop(weak);
+    /// Rcs::get_mut_or(&mut slice).unwrap().fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    /// ```
+    pub fn get_mut_or(it: &mut Self) -> Result<&mut [T::Item], MutError> {
+        // Only look at the counts after `get_mut` fails. Checking them first would race with
+        // other threads upgrading and dropping weak pointers in between.
+        if Rc::get_mut(&mut it.underlying).is_none() {
+            return if Rc::strong_count(&it.underlying) > 1 {
+                Err(MutError::Shared)
+            } else {
+                Err(MutError::HasWeak)
+            };
+        }
+        // Can't fail: nothing else can reach the buffer, so it stays unique.
+        Ok(RcSlice::get_mut(it).unwrap())
+    }
+
+    /// Returns a mutable reference to the entire underlying container, if there are no other
+    /// RcSlice or Rc pointers to it. Unlike [`get_mut`](RcSlice::get_mut), this gives access to
+    /// elements outside of the slice, and allows the container itself to be modified.
+    ///
+    /// The bounds of `it` are not updated when the container is modified. If the container
+    /// becomes shorter than the end of the slice, the caller MUST fix the bounds (for example,
+    /// with [`change_range`](RcSlice::change_range)) before using the slice again, or it will
+    /// panic.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..3);
+    ///
+    /// // The original Rc buffer is still alive.
+    /// assert_eq!(Rcs::get_mut_full(&mut slice), None);
+    /// std::mem::drop(buffer);
+    ///
+    /// // Refill the whole buffer with new data.
+    /// let container = Rcs::get_mut_full(&mut slice).unwrap();
+    /// container.clear();
+    /// container.extend([1, 3, 5, 7]);
+    /// assert_eq!(*slice, [3, 5]);
+    ///
+    /// // Shrinking the container requires fixing the bounds.
+    /// Rcs::get_mut_full(&mut slice).unwrap().truncate(2);
+    /// Rcs::change_range(&mut slice, 1..);
+    /// assert_eq!(*slice, [3]);
+    /// ```
+    pub fn get_mut_full(it: &mut Self) -> Option<&mut T> {
+        Rc::get_mut(&mut it.underlying)
+    }
+
+    /// Returns a mutable reference to part of the slice, if there are no other RcSlice or Rc
+    /// pointers to the underlying buffer. The range is relative to the slice.
+    ///
+    /// Returns `None` if the buffer is shared, or if `range` is out of bounds for the slice.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// // The original Rc buffer is still alive.
+    /// assert_eq!(Rcs::get_range_mut(&mut slice, 1..), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// Rcs::get_range_mut(&mut slice, 1..).unwrap().fill(0);
+    /// assert_eq!(*slice, [4, 0, 0]);
+    ///
+    /// assert_eq!(Rcs::get_range_mut(&mut slice, 2..4), None);
+    /// ```
+    pub fn get_range_mut<R: RangeBounds<usize>>(it: &mut Self, range: R) -> Option<&mut [T::Item]> {
+        let range = (range.start_bound().cloned(), range.end_bound().cloned());
+        RcSlice::get_mut(it)?.get_mut(range)
+    }
+
+    /// Creates a slice over `new_buffer` with the same range as `it`.
+    ///
+    /// Returns `None` if `new_buffer` is too short to contain the range.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let front: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let back: Rc<[u8]> = Rc::new([1, 3, 5, 7, 9]);
+    /// let slice = Rcs::new(&front, 1..4);
+    ///
+    /// let other = Rcs::with_buffer(&slice, &back).unwrap();
+    /// assert_eq!(*other, [3, 5, 7]);
+    /// assert_eq!(Rcs::bounds_range(&other), 1..4);
+    ///
+    /// let short: Rc<[u8]> = Rc::new([1, 3, 5]);
+    /// assert_eq!(Rcs::with_buffer(&slice, &short), None);
+    /// ```
+    pub fn with_buffer(it: &Self, new_buffer: &Rc<T>) -> Option<Self> {
+        if it.end > new_buffer.len() {
+            return None;
+        }
+        Some(RcSlice::from_bounds(new_buffer, it.start, it.end))
+    }
+
+    /// Points the slice at `new_buffer`, keeping the same range, and returns the old buffer.
+    /// This repoints a view at a freshly-produced buffer (e.g. when double-buffering) without
+    /// building a new slice.
+    ///
+    /// If `new_buffer` is too short for the range, the range is clamped to fit it: the end is
+    /// reduced to the length of `new_buffer`, and the start is reduced to the end if needed. Unlike
+    /// [`with_buffer`](RcSlice::with_buffer), this never fails.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let front: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5]);
+    /// let mut view = Rcs::new(&front, 1..4);
+    ///
+    /// let back: Rc<[u8]> = Rc::new([10, 20, 30, 40, 50]);
+    /// let old = Rcs::swap_buffer(&mut view, back);
+    /// assert!(Rc::ptr_eq(&old, &front));
+    /// assert_eq!(*view, [20, 30, 40]);
+    ///
+    /// // Shorter buffer: the range is clamped from 1..4 to 1..3.
+    /// Rcs::swap_buffer(&mut view, Rc::new([7, 8, 9]));
+    /// assert_eq!(*view, [8, 9]);
+    /// assert_eq!(Rcs::bounds_range(&view), 1..3);
+    /// ```
+    pub fn swap_buffer(it: &mut Self, new_buffer: Rc<T>) -> Rc<T> {
+        it.end = usize::min(it.end, new_buffer.len());
+        it.start = usize::min(it.start, it.end);
+        core::mem::replace(&mut it.underlying, new_buffer)
+    }
+
+    /// Replaces `it` with `new`, and returns the previous slice. This is [`core::mem::replace`],
+    /// provided alongside [`take`](RcSlice::take).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut current = Rcs::new(&buffer, ..2);
+    ///
+    /// let previous = Rcs::replace(&mut current, Rcs::new(&buffer, 2..));
+    /// assert_eq!(*previous, [2, 4]);
+    /// assert_eq!(*current, [6, 8, 10]);
+    /// ```
+    pub fn replace(it: &mut Self, new: Self) -> Self {
+        core::mem::replace(it, new)
+    }
+
+    /// Retains only the elements of the slice for which `pred` returns `true`, passing a mutable
+    /// reference to each element in order. This is the slice equivalent of `Vec::retain_mut`.
+    ///
+    /// Only works if the buffer is shrinkable and there are no other RcSlice or Rc pointers to
+    /// it; otherwise, returns `false` without calling `pred`. The kept elements are moved to the
+    /// front of the slice and the end of the slice is reduced, so the rejected elements stay in
+    /// the buffer until it is [`shrink`](RcSlice::shrink)ed.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![1, 2, 3, 4, 5, 6]);
+    /// let mut slice = Rcs::new(&buffer, 1..5);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Rcs::retain_mut(&mut slice, |_| false), false);
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Rcs::retain_mut(&mut slice, |x| { *x *= 10; *x != 30 }), true);
+    /// assert_eq!(*slice, [20, 40, 50]);
+    /// ```
+    pub fn retain_mut<F: FnMut(&mut T::Item) -> bool>(it: &mut Self, mut pred: F) -> bool {
+        // This will be optimized away
+        if !T::IS_SHRINKABLE {
+            return false;
+        }
+
+        let Some(slice) = RcSlice::get_mut(it) else {
+            return false;
+        };
+
+        let mut kept = 0;
+        for i in 0..slice.len() {
+            if pred(&mut slice[i]) {
+                slice.swap(kept, i);
+                kept += 1;
+            }
+        }
+
+        it.end = it.start + kept;
+        true
+    }
+
+    /// Calls `f` on each window of `size` consecutive elements of the slice, as mutable
+    /// references, if there are no other RcSlice or Rc pointers to the buffer. This is a mutable
+    /// version of `[T]::windows`.
+    ///
+    /// The windows are visited in order from left to right, so changes made in one call are
+    /// visible to the later calls whose windows overlap it. If the slice is shorter than `size`,
+    /// `f` is never called.
+    ///
+    /// Returns `false` without calling `f` if the buffer is shared, and `true` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u32]> = Rc::new([1, 2, 3, 4]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// // Turn the slice into its running sums.
+    /// Rcs::for_each_window_mut(&mut slice, 2, |window| window[1] += window[0]);
+    /// assert_eq!(*slice, [1, 3, 6, 10]);
+    /// ```
+    pub fn for_each_window_mut<F: FnMut(&mut [T::Item])>(
+        it: &mut Self,
+        size: usize,
+        mut f: F,
+    ) -> bool {
+        assert!(size != 0, "window size must be non-zero");
+
+        let Some(slice) = RcSlice::get_mut(it) else {
+            return false;
+        };
+
+        // `len - size + 1` windows, without overflowing when `len` is `usize::MAX`.
+        if let Some(last) = slice.len().checked_sub(size) {
+            for start in 0..=last {
+                f(&mut slice[start..start + size]);
+            }
+        }
+        true
+    }
+
+    /// Checks if two RcSlices reference the same slice of the same array in memory.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let first_slice = Rcs::new(&buffer, ..4);
+    /// let second_slice = Rcs::new(&buffer, ..8);
+    ///
+    /// // These slices point to the same buffer, but different ranges.
+    /// assert_eq!(Rcs::ptr_eq(&first_slice, &second_slice), false);
+    ///
+    /// let (low, _) = Rcs::split_at(&second_slice, 4);
+    /// assert_eq!(first_slice, low);
+    ///
+    /// // These slices use the same buffer, and the same range.
+    /// assert_eq!(Rcs::ptr_eq(&first_slice, &low), true);
+    ///
+    /// let other_buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let other_slice = Rcs::new(&other_buffer, ..4);
+    ///
+    /// // The elements for `other_slice` are the same
+    /// assert_eq!(first_slice, other_slice);
+    ///
+    /// // But they use different memory locations.
+    /// assert_eq!(Rcs::ptr_eq(&first_slice, &other_slice), false);
+    /// ```
+    pub fn ptr_eq(this: &Self, other: &Self) -> bool {
+        Rc::ptr_eq(&this.underlying, &other.underlying)
+            && this.start == other.start
+            && this.end == other.end
+    }
+
+    /// Checks if `inner` is a view of the same buffer as `outer`, and is entirely contained within
+    /// `outer`. An empty slice is contained in `outer` if it is within or at the ends of `outer`.
+    ///
+    /// Always returns `false` if the slices use different buffers, even if the buffers are equal.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let outer = Rcs::new(&buffer, 2..6);
+    ///
+    /// assert!(Rcs::contains_slice(&outer, &Rcs::new(&buffer, 3..5)));
+    /// assert!(Rcs::contains_slice(&outer, &outer));
+    /// assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, 4..8)));
+    /// assert!(!Rcs::contains_slice(&Rcs::new(&buffer, 3..5), &outer));
+    ///
+    /// let other_buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// assert!(!Rcs::contains_slice(&outer, &Rcs::new(&other_buffer, 3..5)));
+    /// ```
+    pub fn contains_slice(outer: &Self, inner: &Self) -> bool {
+        Rc::ptr_eq(&outer.underlying, &inner.underlying)
+            && outer.start <= inner.start
+            && inner.end <= outer.end
+    }
+
+    /// Returns the range of `child` relative to the start of `parent`, if `child` is a view of
+    /// the same buffer and is entirely contained within `parent` (see
+    /// [`contains_slice`](RcSlice::contains_slice)). Otherwise, returns `None`.
+    ///
+    /// This is like [`bounds_range`](RcSlice::bounds_range), but in the coordinates of `parent`
+    /// instead of the buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12, 14, 16, 18]);
+    /// let parent = Rcs::new(&buffer, 2..6);
+    ///
+    /// assert_eq!(Rcs::range_within(&Rcs::new(&buffer, 3..5), &parent), Some(1..3));
+    /// assert_eq!(Rcs::range_within(&parent, &parent), Some(0..4));
+    /// assert_eq!(Rcs::range_within(&Rcs::new(&buffer, 4..8), &parent), None);
+    /// ```
+    pub fn range_within(child: &Self, parent: &Self) -> Option<Range<usize>> {
+        if RcSlice::contains_slice(parent, child) {
+            Some(child.start - parent.start..child.end - parent.start)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the parts of `parent` that aren't covered by any of `children`, as slices of the
+    /// same buffer, sorted by their start. Children may overlap or touch each other. This is
+    /// also available as the free function [`gaps`](crate::gaps).
+    ///
+    /// Only children that use the same buffer as `parent` and are entirely within it (see
+    /// [`contains_slice`](RcSlice::contains_slice)) are counted; the rest are ignored.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([0; 16]);
+    /// let arena = Rcs::new(&buffer, 2..14);
+    /// let used = [Rcs::new(&buffer, 8..10), Rcs::new(&buffer, 2..4), Rcs::new(&buffer, 3..5)];
+    ///
+    /// let free: Vec<_> = Rcs::gaps(&arena, &used).iter().map(Rcs::bounds_range).collect();
+    /// assert_eq!(free, [5..8, 10..14]);
+    /// ```
+    pub fn gaps(parent: &Self, children: &[Self]) -> Vec<Self> {
+        let mut covered: Vec<Range<usize>> = children
+            .iter()
+            .filter(|child| RcSlice::contains_slice(parent, child))
+            .map(RcSlice::bounds_range)
+            .collect();
+        covered.sort_unstable_by_key(|range| range.start);
+
+        let mut gaps = Vec::new();
+        let mut start = parent.start;
+        for range in covered {
+            if start < range.start {
+                gaps.push(RcSlice::from_bounds(&parent.underlying, start, range.start));
+            }
+            start = usize::max(start, range.end);
+        }
+        if start < parent.end {
+            gaps.push(RcSlice::from_bounds(&parent.underlying, start, parent.end));
+        }
+        gaps
+    }
+
+    /// Consumes the slice and returns the underlying buffer, if this was the only strong
+    /// reference to it. Otherwise, the slice is dropped and `None` is returned.
+    ///
+    /// This is meant for returning buffers to a pool, so they can be sliced again later. Weak
+    /// references to the buffer are not affected.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..3);
+    ///
+    /// // `buffer` is still alive.
+    /// assert_eq!(Rcs::recycle(slice), None);
+    ///
+    /// let slice = Rcs::new(&buffer, 1..3);
+    /// core::mem::drop(buffer);
+    /// let buffer = Rcs::recycle(slice).unwrap();
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn recycle(it: Self) -> Option<Rc<T>> {
+        if Rc::strong_count(&it.underlying) == 1 {
+            Some(it.underlying)
+        } else {
+            None
+        }
+    }
+
+    /// This is like a normal slice indexing operation, but produces an RcSlice instead. This is
+    /// the same as [`subslice`](RcSlice::subslice).
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::vec::Vec;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new((0..10).collect());
+    /// let slice = Rcs::new(&buffer, 3..8);
+    /// assert_eq!(*slice, [3, 4, 5, 6, 7]);
+    ///
+    /// // Returns an RcSlice of the same buffer.
+    /// assert_eq!(Rcs::index(&slice, 2..4), Rcs::new(&buffer, 5..7));
+    ///
+    /// // All kinds of ranges work.
+    /// assert_eq!(*Rcs::index(&slice, ..), [3, 4, 5, 6, 7]);
+    /// assert_eq!(*Rcs::index(&slice, 1..), [4, 5, 6, 7]);
+    /// assert_eq!(*Rcs::index(&slice, 8..), []);
+    /// assert_eq!(*Rcs::index(&slice, 5..), []);
+    /// assert_eq!(*Rcs::index(&slice, ..2), [3, 4]);
+    /// assert_eq!(*Rcs::index(&slice, ..70), [3, 4, 5, 6, 7]);
+    /// assert_eq!(*Rcs::index(&slice, 3..8), [6, 7]);
+    ///```
+    pub fn index<R: RangeBounds<usize>>(it: &Self, range: R) -> Self {
+        RcSlice::subslice(it, range)
+    }
+
+    /// Converts a range relative to the slice into a range of the underlying buffer, clamped
+    /// to the bounds of the slice.
+    fn relative_range<R: RangeBounds<usize>>(it: &Self, range: R) -> Range<usize> {
+        use Bound::*;
+        let mut start = match range.start_bound() {
+            Unbounded => it.start,
+            Included(s) => it.start.saturating_add(*s),
+            Excluded(s) => it.start.saturating_add(*s).saturating_add(1),
+        };
+        let end = match range.end_bound() {
+            Unbounded => it.end,
+            Included(s) => usize::min(it.end, it.start.saturating_add(*s).saturating_add(1)),
+            Excluded(s) => usize::min(it.end, it.start.saturating_add(*s)),
+        };
+        start = usize::min(start, end);
+        start..end
+    }
+}
+
+/// Iterator returned by [`RcSlice::rchunks`]. Each chunk is a slice of the same buffer.
+pub struct RcRChunks<T: ?Sized> {
+    /// The part of the slice that hasn't been split into chunks yet.
+    rest: RcSlice<T>,
+    /// The length of every chunk, except possibly the one at the front.
+    chunk_size: usize,
+}
+
+impl<T: RcSliceContainer + ?Sized> Iterator for RcRChunks<T> {
+    type Item = RcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let len = RcSlice::len(&self.rest);
+        if len == 0 {
+            return None;
+        }
+        RcSlice::split_off_after(&mut self.rest, len.saturating_sub(self.chunk_size))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let count = RcSlice::len(&self.rest).div_ceil(self.chunk_size);
+        (count, Some(count))
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> DoubleEndedIterator for RcRChunks<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let len = RcSlice::len(&self.rest);
+        if len == 0 {
+            return None;
+        }
+        // The front chunk holds the remainder, or a full chunk if there isn't one.
+        let front_len = match len % self.chunk_size {
+            0 => self.chunk_size,
+            remainder => remainder,
+        };
+        RcSlice::split_off_before(&mut self.rest, front_len)
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> ExactSizeIterator for RcRChunks<T> {}
+
+impl<T: RcSliceContainer + ?Sized> FusedIterator for RcRChunks<T> {}
+
+impl<T: ?Sized> Clone for RcRChunks<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+            chunk_size: self.chunk_size,
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for RcRChunks<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcRChunks")
+            .field("rest", &self.rest)
+            .field("chunk_size", &self.chunk_size)
+            .finish()
+    }
+}
+
+/// Returns the parts of `parent` that aren't covered by any of `children`, as slices of the same
+/// buffer, sorted by their start. This is the same as [`RcSlice::gaps`].
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::rc::Rc;
+/// use rc_slice2::{gaps, RcSlice};
+/// use RcSlice as Rcs;
+///
+/// let buffer: Rc<[u8]> = Rc::new([0; 16]);
+/// let arena = Rcs::new(&buffer, 2..14);
+/// let used = [Rcs::new(&buffer, 8..10), Rcs::new(&buffer, 2..4), Rcs::new(&buffer, 3..5)];
+///
+/// let free: Vec<_> = gaps(&arena, &used).iter().map(Rcs::bounds_range).collect();
+/// assert_eq!(free, [5..8, 10..14]);
+/// ```
+pub fn gaps<T: RcSliceContainer + ?Sized>(
+    parent: &RcSlice<T>,
+    children: &[RcSlice<T>],
+) -> Vec<RcSlice<T>> {
+    RcSlice::gaps(parent, children)
+}
+
+impl<T: RcSliceContainer + Default> RcSlice<T> {
+    /// Tries to reduce the size of the original buffer, if this is the only
+    /// Rc or RcSlice referencing the buffer.
+    ///
+    /// This operation doesn't preserve weak references.
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    /// let weak_buffer = Rc::downgrade(&buffer);
+    ///
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(**Rcs::inner(&slice), [2, 4, 6, 8, 10, 12]);
+    ///
+    /// // Shrink fails: there are two strong references.
+    /// assert_eq!(Rcs::shrink(&mut slice), false);
+    ///
+    /// core::mem::drop(buffer);
+    ///
+    /// // Shrink successful: only one strong reference.
+    /// assert_eq!(Rcs::shrink(&mut slice), true);
+    ///
+    /// // The slice is unchanged, and the buffer has shrunk.
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// assert_eq!(**Rcs::inner(&slice), [4, 6, 8]);
+    ///
+    /// // Weak references were not preserved.
+    /// assert_eq!(weak_buffer.upgrade(), None);
+    ///
+    /// ```
+    pub fn shrink(it: &mut Self) -> bool {
+        RcSlice::try_shrink(it).is_ok()
+    }
+
+    /// Like [`shrink`](RcSlice::shrink), but reports why the buffer couldn't be shrunk. On
+    /// success, returns the new range of the slice within the buffer.
+    ///
+    /// Some containers may decline to shrink even when uniquely owned (for example, a `SmallVec`
+    /// that is stored inline). In that case the buffer is left unchanged, and the current range
+    /// is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::{RcSlice, ShrinkError};
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// assert_eq!(Rcs::try_shrink(&mut slice), Err(ShrinkError::Shared));
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Rcs::try_shrink(&mut slice), Ok(0..3));
+    /// assert_eq!(**Rcs::inner(&slice), [4, 6, 8]);
+    ///
+    /// assert_eq!(Rcs::try_shrink(&mut slice), Err(ShrinkError::AlreadyMinimal));
+    /// ```
+    pub fn try_shrink(it: &mut Self) -> Result<Range<usize>, ShrinkError> {
+        // This will be optimized away
+        if !T::IS_SHRINKABLE {
+            return Err(ShrinkError::NotShrinkable);
+        }
+
+        if it.start == 0 && it.end == it.underlying.len() {
+            return Err(ShrinkError::AlreadyMinimal);
+        }
+
+        let mut temp = Rc::new(T::default());
+        core::mem::swap(&mut temp, &mut it.underlying);
+        match Rc::try_unwrap(temp) {
+            Ok(mut container) => {
+                if let Some(new_range) = container.shrink_container_to_range(it.start..it.end) {
+                    debug_assert!(
+                        new_range.start <= new_range.end
+                            && new_range.len() == it.end - it.start
+                            && new_range.end <= container.len(),
+                        "shrink_container_to_range returned a range that does not match the kept elements"
+                    );
+                    it.start = new_range.start;
+                    it.end = new_range.end;
+                }
+                let mut temp2 = Rc::new(container);
+                core::mem::swap(&mut temp2, &mut it.underlying);
+                Ok(it.start..it.end)
+            }
+            Err(mut temp2) => {
+                core::mem::swap(&mut temp2, &mut it.underlying);
+                Err(ShrinkError::Shared)
+            }
+        }
+    }
+
+    /// Narrows the slice to `range` (relative to the current slice, and clamped to it), and then
+    /// tries to reduce the size of the original buffer to just that range. This is the same as
+    /// changing the range and then calling [`shrink`](RcSlice::shrink), and returns the same
+    /// value as `shrink`.
+    ///
+    /// The slice is narrowed even if the buffer can't be shrunk.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10, 12]);
+    /// let mut slice = Rcs::new(&buffer, 1..5);
+    /// core::mem::drop(buffer);
+    ///
+    /// assert_eq!(Rcs::shrink_to(&mut slice, 1..3), true);
+    /// assert_eq!(*slice, [6, 8]);
+    /// assert_eq!(**Rcs::inner(&slice), [6, 8]);
+    /// ```
+    pub fn shrink_to<R: RangeBounds<usize>>(it: &mut Self, range: R) -> bool {
+        let range = RcSlice::relative_range(it, range);
+        it.start = range.start;
+        it.end = range.end;
+        RcSlice::shrink(it)
+    }
+
+    /// Like [`split_off_before`](RcSlice::split_off_before), but the front part is copied into a
+    /// new, independent buffer, and then `it` is [`shrink`](RcSlice::shrink)ed so the original
+    /// buffer no longer holds the front part.
+    ///
+    /// If the original buffer is shared or can't be shrunk, it is left as-is (other references
+    /// still need those elements), and `it` is only narrowed. The returned slice always uses its
+    /// own buffer, so in that case the copy is wasted: the front part stays in the original
+    /// buffer anyway. The copy only frees memory when [`Rc::strong_count`] is 1 and
+    /// [`IS_SHRINKABLE`](RcSliceContainer::IS_SHRINKABLE) is true; otherwise, use
+    /// [`split_off_before`](RcSlice::split_off_before) instead.
+    ///
+    /// Returns `None` and leaves `it` unchanged if `index` is outside the bounds of the slice.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// let front = Rcs::split_off_before_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(*front, [2, 4]);
+    /// assert_eq!(*slice, [6, 8, 10]);
+    ///
+    /// // The original buffer was shrunk to the tail.
+    /// assert_eq!(**Rcs::inner(&slice), [6, 8, 10]);
+    ///
+    /// assert_eq!(Rcs::split_off_before_owned(&mut slice, 4), None);
+    /// ```
+    pub fn split_off_before_owned(it: &mut Self, index: usize) -> Option<RcSlice<[T::Item]>>
+    where
+        T::Item: Clone,
+    {
+        let front = RcSlice::clone_detached(&RcSlice::split_off_before(it, index)?);
+        RcSlice::shrink(it);
+        Some(front)
+    }
+
+    /// Like [`get_mut`](RcSlice::get_mut), but weak references to the buffer don't prevent
+    /// mutation. Returns `None` if there are other strong references to the buffer.
+    ///
+    /// `Rc::get_mut` refuses to hand out a mutable reference while weak references exist, since
+    /// they could be upgraded later. So when there are weak references, the container is moved
+    /// (not copied) into a new `Rc`, and the old weak references are disconnected: they will
+    /// never upgrade again, just as after [`shrink`](RcSlice::shrink). This requires moving the
+    /// container out of the `Rc`, so it is only available for `Default` containers, and not
+    /// for unsized ones like `[T]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let observer = Rc::downgrade(&buffer);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    /// core::mem::drop(buffer);
+    ///
+    /// // The weak reference blocks `get_mut`, but not `get_mut_ignoring_weak`.
+    /// assert_eq!(Rcs::get_mut(&mut slice), None);
+    /// Rcs::get_mut_ignoring_weak(&mut slice).unwrap().fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    ///
+    /// // The weak reference was disconnected.
+    /// assert_eq!(observer.upgrade(), None);
+    /// ```
+    pub fn get_mut_ignoring_weak(it: &mut Self) -> Option<&mut [T::Item]> {
+        if Rc::get_mut(&mut it.underlying).is_none() {
+            // Don't allocate a placeholder just to find out the buffer is shared.
+            if Rc::strong_count(&it.underlying) != 1 {
+                return None;
+            }
+            let temp = core::mem::replace(&mut it.underlying, Rc::new(T::default()));
+            match Rc::try_unwrap(temp) {
+                Ok(container) => it.underlying = Rc::new(container),
+                Err(temp) => {
+                    it.underlying = temp;
+                    return None;
+                }
+            }
+        }
+        RcSlice::get_mut(it)
+    }
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> RcSlice<T> {
+    /// Create a new RcSlice over UTF-8 encoded bytes, like [`new`](RcSlice::new), but returns
+    /// `None` if either end of the (clamped) range would split a multibyte character.
+    ///
+    /// The buffer itself is not validated; only the bytes at the boundaries of the range are
+    /// checked, using the same rule as [`str::is_char_boundary`].
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use rc_slice2::RcSlice;
+    /// # use alloc::rc::Rc;
+    /// use RcSlice as Rcs;
+    ///
+    /// // 'é' is two bytes long.
+    /// let buffer: Rc<[u8]> = Rc::from("héllo".as_bytes());
+    ///
+    /// assert_eq!(*Rcs::new_utf8(&buffer, 1..3).unwrap(), *"é".as_bytes());
+    /// assert_eq!(*Rcs::new_utf8(&buffer, 3..).unwrap(), *b"llo");
+    ///
+    /// // Both of these ranges cut 'é' in half.
+    /// assert_eq!(Rcs::new_utf8(&buffer, 2..), None);
+    /// assert_eq!(Rcs::new_utf8(&buffer, ..2), None);
+    /// ```
+    pub fn new_utf8<R: RangeBounds<usize>>(underlying: &Rc<T>, range: R) -> Option<Self> {
+        let slice = RcSlice::new(underlying, range);
+        let bytes = underlying.get(0..underlying.len())?;
+        let is_char_boundary = |index: usize| match bytes.get(index) {
+            // UTF-8 continuation bytes all look like `0b10xx_xxxx`.
+            Some(&byte) => (byte as i8) >= -0x40,
+            None => index == bytes.len(),
+        };
+
+        if is_char_boundary(slice.start) && is_char_boundary(slice.end) {
+            Some(slice)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the lines of the slice, as slices of the same buffer.
+    ///
+    /// Lines are split on `\n`, and a `\r` just before the `\n` is removed, like
+    /// [`str::lines`]. The final line does not need to end with a newline, and a newline at the
+    /// very end of the slice does not produce an extra empty line.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"first\r\nsecond\n\nlast"[..]);
+    /// let slice = Rcs::new(&buffer, ..);
+    ///
+    /// let mut lines = Rcs::lines(&slice);
+    /// assert_eq!(*lines.next().unwrap(), *b"first");
+    /// assert_eq!(*lines.next().unwrap(), *b"second");
+    /// assert_eq!(*lines.next().unwrap(), *b"");
+    /// assert_eq!(*lines.next().unwrap(), *b"last");
+    /// assert_eq!(lines.next(), None);
+    /// ```
+    pub fn lines(it: &Self) -> RcLines<T> {
+        RcLines { rest: it.clone() }
+    }
+
+    /// Returns an iterator over the characters of the slice and their byte offsets, like
+    /// [`str::char_indices`], or `None` if the slice is not valid UTF-8.
+    ///
+    /// Offsets are relative to the start of the slice, not the start of the buffer, so they can
+    /// be passed straight to [`split_at`](RcSlice::split_at) or
+    /// [`subslice`](RcSlice::subslice) to get a slice of the same buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from("añb€".as_bytes());
+    /// let slice = Rcs::new(&buffer, 1..);
+    ///
+    /// let chars: Vec<_> = Rcs::char_indices(&slice).unwrap().collect();
+    /// assert_eq!(chars, [(0, 'ñ'), (2, 'b'), (3, '€')]);
+    /// assert_eq!(*Rcs::subslice(&slice, 3..), *"€".as_bytes());
+    ///
+    /// // Cuts 'ñ' in half.
+    /// assert!(Rcs::char_indices(&Rcs::new(&buffer, 2..)).is_none());
+    /// ```
+    pub fn char_indices(it: &Self) -> Option<CharIndices<'_>> {
+        core::str::from_utf8(RcSlice::as_slice(it))
+            .ok()
+            .map(str::char_indices)
+    }
+
+    /// Returns a value whose `Debug` output shows the slice as an escaped byte string, instead
+    /// of a list of numbers. Printable ASCII is shown as-is, and other bytes are escaped like
+    /// [`u8::escape_ascii`] does (for example `\n` or `\xff`).
+    ///
+    /// This doesn't change the slice's own `Debug` output, which is still a list of numbers.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"GET /\r\n\x00\xff"[..]);
+    /// let slice = Rcs::from(buffer);
+    ///
+    /// assert_eq!(format!("{:?}", Rcs::debug_ascii(&slice)), r#""GET /\r\n\x00\xff""#);
+    /// ```
+    pub fn debug_ascii(it: &Self) -> impl fmt::Debug + '_ {
+        DebugAscii(RcSlice::as_slice(it))
+    }
+
+    /// Copies the first `N` bytes of the slice, or returns `None` if it is shorter than that.
+    fn peek_array<const N: usize>(it: &Self) -> Option<[u8; N]> {
+        RcSlice::peek_n(it, N).map(|bytes| bytes.try_into().unwrap())
+    }
+
+    /// Reads a little-endian `u16` from the first 2 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 2 bytes.
+    ///
+    /// There are `read_*` functions for `u16`, `u32` and `u64`, in both byte orders. To advance
+    /// past the value as well, use the matching `take_*` function, like
+    /// [`take_u16_le`](RcSlice::take_u16_le).
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([0xff, 0x34, 0x12]);
+    /// let slice = Rcs::new(&buffer, 1..);
+    ///
+    /// assert_eq!(Rcs::read_u16_le(&slice), Some(0x1234));
+    /// assert_eq!(Rcs::read_u16_be(&slice), Some(0x3412));
+    /// assert_eq!(Rcs::read_u32_le(&slice), None);
+    /// ```
+    pub fn read_u16_le(it: &Self) -> Option<u16> {
+        RcSlice::peek_array(it).map(u16::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u16` from the first 2 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 2 bytes.
+    pub fn read_u16_be(it: &Self) -> Option<u16> {
+        RcSlice::peek_array(it).map(u16::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u32` from the first 4 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 4 bytes.
+    pub fn read_u32_le(it: &Self) -> Option<u32> {
+        RcSlice::peek_array(it).map(u32::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u32` from the first 4 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 4 bytes.
+    pub fn read_u32_be(it: &Self) -> Option<u32> {
+        RcSlice::peek_array(it).map(u32::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u64` from the first 8 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 8 bytes.
+    pub fn read_u64_le(it: &Self) -> Option<u64> {
+        RcSlice::peek_array(it).map(u64::from_le_bytes)
+    }
+
+    /// Reads a big-endian `u64` from the first 8 bytes of the slice, without advancing it.
+    /// Returns `None` if the slice is shorter than 8 bytes.
+    pub fn read_u64_be(it: &Self) -> Option<u64> {
+        RcSlice::peek_array(it).map(u64::from_be_bytes)
+    }
+
+    /// Reads a little-endian `u16` from the first 2 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 2 bytes.
+    ///
+    /// There are `take_*` functions for `u16`, `u32` and `u64`, in both byte orders.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// // A 2-byte tag and a 4-byte length, both little-endian.
+    /// let buffer: Rc<[u8]> = Rc::new([0x01, 0x00, 0x10, 0x00, 0x00, 0x00, 0xaa]);
+    /// let mut header = Rcs::new(&buffer, ..);
+    ///
+    /// assert_eq!(Rcs::take_u16_le(&mut header), Some(1));
+    /// assert_eq!(Rcs::take_u32_le(&mut header), Some(16));
+    /// assert_eq!(Rcs::take_u16_le(&mut header), None);
+    /// assert_eq!(*header, [0xaa]);
+    /// ```
+    pub fn take_u16_le(it: &mut Self) -> Option<u16> {
+        let value = RcSlice::read_u16_le(it)?;
+        RcSlice::advance(it, 2);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u16` from the first 2 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 2 bytes.
+    pub fn take_u16_be(it: &mut Self) -> Option<u16> {
+        let value = RcSlice::read_u16_be(it)?;
+        RcSlice::advance(it, 2);
+        Some(value)
+    }
+
+    /// Reads a little-endian `u32` from the first 4 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 4 bytes.
+    pub fn take_u32_le(it: &mut Self) -> Option<u32> {
+        let value = RcSlice::read_u32_le(it)?;
+        RcSlice::advance(it, 4);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u32` from the first 4 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 4 bytes.
+    pub fn take_u32_be(it: &mut Self) -> Option<u32> {
+        let value = RcSlice::read_u32_be(it)?;
+        RcSlice::advance(it, 4);
+        Some(value)
+    }
+
+    /// Reads a little-endian `u64` from the first 8 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 8 bytes.
+    pub fn take_u64_le(it: &mut Self) -> Option<u64> {
+        let value = RcSlice::read_u64_le(it)?;
+        RcSlice::advance(it, 8);
+        Some(value)
+    }
+
+    /// Reads a big-endian `u64` from the first 8 bytes of the slice, and advances the slice
+    /// past them. Returns `None` and doesn't move if the slice is shorter than 8 bytes.
+    pub fn take_u64_be(it: &mut Self) -> Option<u64> {
+        let value = RcSlice::read_u64_be(it)?;
+        RcSlice::advance(it, 8);
+        Some(value)
+    }
+}
+
+/// Iterator returned by [`RcSlice::lines`]. Each line is a slice of the same buffer.
+pub struct RcLines<T: ?Sized> {
+    /// The part of the slice that hasn't been split into lines yet.
+    rest: RcSlice<T>,
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> Iterator for RcLines<T> {
+    type Item = RcSlice<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if RcSlice::is_empty(&self.rest) {
+            return None;
+        }
+
+        let line_len = self
+            .rest
+            .iter()
+            .position(|&byte| byte == b'\n')
+            .unwrap_or(RcSlice::len(&self.rest));
+        let mut line = RcSlice::split_off_before(&mut self.rest, line_len)?;
+        // Skip the newline and the carriage return before it, if there is one.
+        if RcSlice::advance(&mut self.rest, 1).is_some() && line.last() == Some(&b'\r') {
+            RcSlice::retract(&mut line, 1);
+        }
+
+        Some(line)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // Every line except the last one uses up at least one byte for its newline, and the
+        // last line is only produced if there is at least one byte left.
+        let len = RcSlice::len(&self.rest);
+        (usize::min(len, 1), Some(len))
+    }
+}
+
+impl<T: RcSliceContainer<Item = u8> + ?Sized> FusedIterator for RcLines<T> {}
+
+impl<T: ?Sized> Clone for RcLines<T> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest.clone(),
+        }
+    }
+}
+
+impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for RcLines<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RcLines").field("rest", &self.rest).finish()
+    }
+}
+
+/// Debug formatter returned by [`RcSlice::debug_ascii`].
+struct DebugAscii<'a>(&'a [u8]);
+
+impl fmt::Debug for DebugAscii<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self.0.escape_ascii())
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    /// Copies the elements of the slice into a new buffer, and returns a slice of the whole
+    /// new buffer. Unlike [`clone`](Clone::clone), the result does not share memory with `it`,
+    /// so it can be mutated right away.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// let mut detached = Rcs::clone_detached(&slice);
+    /// assert_eq!(*detached, *slice);
+    /// assert_eq!(**Rcs::inner(&detached), [4, 6, 8]);
+    ///
+    /// // The original buffer is still shared, but the copy is not.
+    /// Rcs::get_mut(&mut detached).unwrap()[0] = 5;
+    /// assert_eq!(*detached, [5, 6, 8]);
+    /// assert_eq!(*slice, [4, 6, 8]);
+    /// ```
+    pub fn clone_detached(it: &Self) -> RcSlice<[T::Item]> {
+        let buffer: Rc<[T::Item]> = Rc::from(it.as_ref());
+        RcSlice::from(buffer)
+    }
+
+    /// Joins several slices together into a new buffer, placing a copy of `sep` between each
+    /// of them. Returns a slice of the whole new buffer. This is the same as `[T]::join`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    /// let parts = [
+    ///     Rcs::new(&buffer, 0..1),
+    ///     Rcs::new(&buffer, 1..2),
+    ///     Rcs::new(&buffer, 2..3),
+    /// ];
+    ///
+    /// assert_eq!(*Rcs::join(&parts, &[0]), [1, 0, 2, 0, 3]);
+    /// assert_eq!(*Rcs::join(&parts, &[]), [1, 2, 3]);
+    /// assert_eq!(*Rcs::join(&parts[..1], &[0]), [1]);
+    /// assert_eq!(*Rcs::<[u8]>::join(&[], &[0]), []);
+    /// ```
+    pub fn join(slices: &[Self], sep: &[T::Item]) -> RcSlice<[T::Item]> {
+        let joined: Vec<T::Item> = slices.join(sep);
+        RcSlice::from(Rc::<[T::Item]>::from(joined))
+    }
+
+    /// Creates a new slice containing a copy of the elements of `a` followed by the elements of
+    /// `b`, in a new buffer. The slices don't need to share a buffer.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let header: Rc<[u8]> = Rc::new([0xff, 1, 2]);
+    /// let body: Rc<[u8]> = Rc::new([3, 4, 5, 0xff]);
+    ///
+    /// let message = Rcs::append(&Rcs::new(&header, 1..), &Rcs::new(&body, ..3));
+    /// assert_eq!(*message, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn append(a: &Self, b: &Self) -> RcSlice<[T::Item]> {
+        let buffer: Rc<[T::Item]> = a.iter().chain(b.iter()).cloned().collect();
+        RcSlice::from(buffer)
+    }
+
+    /// Creates a new buffer containing the elements of the slice followed by the items of
+    /// `extra`, and returns a slice of all of it. This is the copying counterpart of
+    /// `Vec::extend`; the original buffer is not changed.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..3);
+    ///
+    /// let grown = Rcs::with_appended(&slice, [12, 14]);
+    /// assert_eq!(*grown, [4, 6, 12, 14]);
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn with_appended<I: IntoIterator<Item = T::Item>>(
+        it: &Self,
+        extra: I,
+    ) -> RcSlice<[T::Item]> {
+        it.iter().cloned().chain(extra).collect()
+    }
+
+    /// Creates a new buffer containing the items of `prefix` followed by the elements of the
+    /// slice, and returns a slice of all of it. The original buffer is not changed.
+    ///
+    /// The prefix is collected first, so that the rest of the space can be reserved in one go.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let payload: Rc<[u8]> = Rc::from(&b"hello"[..]);
+    /// let body = Rcs::from(payload);
+    ///
+    /// let packet = Rcs::with_prepended(&body, [0xAB, body.len() as u8]);
+    /// assert_eq!(*packet, *b"\xAB\x05hello");
+    /// ```
+    pub fn with_prepended<I: IntoIterator<Item = T::Item>>(
+        it: &Self,
+        prefix: I,
+    ) -> RcSlice<[T::Item]> {
+        let mut buffer: Vec<T::Item> = prefix.into_iter().collect();
+        buffer.reserve_exact(RcSlice::len(it));
+        buffer.extend_from_slice(it);
+        RcSlice::from(Rc::<[T::Item]>::from(buffer))
+    }
+
+    /// Splits off the first `n` elements into a new, independent buffer, and advances `it` past
+    /// them. Returns `None` and leaves `it` unchanged if `n` is greater than the length of the
+    /// slice.
+    ///
+    /// Unlike [`split_off_before_owned`](RcSlice::split_off_before_owned), the rest of the slice
+    /// stays a view of the original buffer, which is never shrunk or copied. Only the prefix is
+    /// copied, so it can be stored without keeping the original buffer alive.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([3, 1, 2, 3, 9, 9]);
+    /// let mut stream = Rcs::from(buffer.clone());
+    ///
+    /// let header = Rcs::take_prefix(&mut stream, 4).unwrap();
+    /// assert_eq!(*header, [3, 1, 2, 3]);
+    /// assert_eq!(*stream, [9, 9]);
+    ///
+    /// assert!(!Rc::ptr_eq(Rcs::inner(&header), &buffer));
+    /// assert!(Rc::ptr_eq(Rcs::inner(&stream), &buffer));
+    /// assert_eq!(Rcs::take_prefix(&mut stream, 3), None);
+    /// ```
+    pub fn take_prefix(it: &mut Self, n: usize) -> Option<RcSlice<[T::Item]>> {
+        RcSlice::advance(it, n).map(RcSlice::from)
+    }
+
+    /// This is the same as [`advance`](RcSlice::advance), but returns a copy of the elements that
+    /// were cut off. Unlike `advance`, the result doesn't borrow `it`, so `it` can be modified
+    /// again while the result is still in use.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, ..);
+    ///
+    /// let first = Rcs::advance_owned(&mut slice, 2).unwrap();
+    /// let second = Rcs::advance_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(first, [2, 4]);
+    /// assert_eq!(second, [6, 8]);
+    ///
+    /// assert_eq!(Rcs::advance_owned(&mut slice, 2), None);
+    /// assert_eq!(*slice, [10]);
+    /// ```
+    pub fn advance_owned(it: &mut Self, incr: usize) -> Option<Vec<T::Item>> {
+        RcSlice::advance(it, incr).map(<[T::Item]>::to_vec)
+    }
+
+    /// This is the same as [`retract`](RcSlice::retract), but returns a copy of the elements that
+    /// were cut off. Unlike `retract`, the result doesn't borrow `it`, so `it` can be modified
+    /// again while the result is still in use.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, ..);
+    ///
+    /// let last = Rcs::retract_owned(&mut slice, 2).unwrap();
+    /// let second_last = Rcs::retract_owned(&mut slice, 2).unwrap();
+    /// assert_eq!(last, [8, 10]);
+    /// assert_eq!(second_last, [4, 6]);
+    ///
+    /// assert_eq!(Rcs::retract_owned(&mut slice, 2), None);
+    /// assert_eq!(*slice, [2]);
+    /// ```
+    pub fn retract_owned(it: &mut Self, decr: usize) -> Option<Vec<T::Item>> {
+        RcSlice::retract(it, decr).map(<[T::Item]>::to_vec)
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Copy,
+{
+    /// Copies the elements of the slice into an array, if the slice is exactly `N` elements long.
+    /// Returns `None` otherwise.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let packet: Rc<[u8]> = Rc::new([0x45, 0x00, 192, 168, 0, 1, 0xff]);
+    /// let address = Rcs::new(&packet, 2..6);
+    ///
+    /// assert_eq!(Rcs::try_into_array(&address), Some([192, 168, 0, 1]));
+    /// assert_eq!(Rcs::try_into_array::<6>(&address), None);
+    /// ```
+    pub fn try_into_array<const N: usize>(it: &Self) -> Option<[T::Item; N]> {
+        <[T::Item; N]>::try_from(it.as_ref()).ok()
+    }
+
+    /// Copies the slice into arrays of `N` elements, starting from the front. The elements that
+    /// don't fill a whole array are returned as a slice of the same buffer, rather than copied.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `N` is 0.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let samples: Rc<[i16]> = Rc::new([0, 1, -1, 2, -2, 3, -3, 4]);
+    /// let slice = Rcs::new(&samples, 1..);
+    ///
+    /// let (frames, rest) = Rcs::as_chunks::<3>(&slice);
+    /// assert_eq!(frames, [[1, -1, 2], [-2, 3, -3]]);
+    /// assert_eq!(*rest, [4]);
+    /// assert_eq!(Rcs::bounds_range(&rest), 7..8);
+    /// ```
+    pub fn as_chunks<const N: usize>(it: &Self) -> (Vec<[T::Item; N]>, Self) {
+        assert!(N != 0, "chunk size must be non-zero");
+        let full = RcSlice::len(it) - RcSlice::len(it) % N;
+        let chunks = it[..full]
+            .chunks_exact(N)
+            .map(|chunk| <[T::Item; N]>::try_from(chunk).unwrap())
+            .collect();
+        // `full <= len`, so `start + full` is at most `end`.
+        let rest = RcSlice::from_bounds(&it.underlying, it.start + full, it.end);
+        (chunks, rest)
+    }
+
+    /// Copies all of the elements of `src` into the slice, if there are no other RcSlice or Rc
+    /// pointers to the buffer (see [`get_mut`](RcSlice::get_mut)). Returns `false` without
+    /// copying anything if the buffer is shared.
+    ///
+    /// This is for refilling a buffer that is reused, such as one taken from a pool.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `src` is not the same length as the slice, like [`slice::copy_from_slice`],
+    /// even if the buffer is shared.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([0; 6]);
+    /// let mut frame = Rcs::new(&buffer, 2..);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert!(!Rcs::copy_from_slice(&mut frame, &[1, 2, 3, 4]));
+    /// core::mem::drop(buffer);
+    ///
+    /// assert!(Rcs::copy_from_slice(&mut frame, &[1, 2, 3, 4]));
+    /// assert_eq!(**Rcs::inner(&frame), [0, 0, 1, 2, 3, 4]);
+    /// ```
+    pub fn copy_from_slice(it: &mut Self, src: &[T::Item]) -> bool {
+        assert_eq!(
+            RcSlice::len(it),
+            src.len(),
+            "source slice length does not match the slice length"
+        );
+        match RcSlice::get_mut(it) {
+            Some(slice) => {
+                slice.copy_from_slice(src);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Ord,
+{
+    /// Compares the elements of two slices lexicographically, exactly like `[T]::cmp`. This is
+    /// what the `Ord` impl for RcSlice uses.
+    ///
+    /// Only the elements within each view are compared; the rest of the underlying buffers
+    /// are ignored. If one slice is a prefix of the other, the shorter slice is less.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// # use core::cmp::Ordering;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 1, 3]);
+    ///
+    /// let a = Rcs::new(&buffer, 0..2);
+    /// let b = Rcs::new(&buffer, 0..3);
+    /// let c = Rcs::new(&buffer, 3..5);
+    ///
+    /// assert_eq!(Rcs::cmp(&a, &b), Ordering::Less);
+    /// assert_eq!(Rcs::cmp(&b, &c), Ordering::Less);
+    /// assert_eq!(Rcs::cmp(&c, &a), Ordering::Greater);
+    /// assert_eq!(Rcs::cmp(&a, &a), Ordering::Equal);
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn cmp(a: &Self, b: &Self) -> Ordering {
+        a.as_ref().cmp(b.as_ref())
+    }
+
+    /// Returns a reference to the largest element of the slice, or `None` if it is empty. If
+    /// several elements are equally large, the first one is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([9, 2, 7, 4, 0]);
+    ///
+    /// assert_eq!(Rcs::max(&Rcs::new(&buffer, 1..)), Some(&7));
+    /// assert_eq!(Rcs::max(&Rcs::new(&buffer, 5..)), None);
+    /// ```
+    pub fn max(it: &Self) -> Option<&T::Item> {
+        it.iter().reduce(|max, x| if x > max { x } else { max })
+    }
+
+    /// Returns a reference to the smallest element of the slice, or `None` if it is empty. If
+    /// several elements are equally small, the first one is returned.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([9, 2, 7, 4, 0]);
+    ///
+    /// assert_eq!(Rcs::min(&Rcs::new(&buffer, ..4)), Some(&2));
+    /// assert_eq!(Rcs::min(&Rcs::new(&buffer, 5..)), None);
+    /// ```
+    pub fn min(it: &Self) -> Option<&T::Item> {
+        it.iter().reduce(|min, x| if x < min { x } else { min })
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Hash,
+{
+    /// An alias of the `Hash` impl for RcSlice: hashes the length of the slice, followed by each
+    /// element, like `[T]` and `Vec<T>`. It only needs the elements to be `Hash`, not the
+    /// container.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// # use std::collections::hash_map::DefaultHasher;
+    /// use core::hash::{Hash, Hasher};
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..3);
+    ///
+    /// let mut slice_hasher = DefaultHasher::new();
+    /// Rcs::hash_with_len(&slice, &mut slice_hasher);
+    ///
+    /// let mut array_hasher = DefaultHasher::new();
+    /// [4u8, 6].as_slice().hash(&mut array_hasher);
+    ///
+    /// assert_eq!(slice_hasher.finish(), array_hasher.finish());
+    /// ```
+    pub fn hash_with_len<H: Hasher>(it: &Self, state: &mut H) {
+        it.as_ref().hash(state)
+    }
+}
+
+impl<T> RcSlice<[T]> {
+    /// Creates a slice of `len` elements in a new buffer, where each element is `f(i)` for its
+    /// index `i`. This is like `core::array::from_fn`.
+    ///
+    /// ```
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let squares = Rcs::from_fn(5, |i| (i * i) as u8);
+    /// assert_eq!(*squares, [0, 1, 4, 9, 16]);
+    /// ```
+    pub fn from_fn<F: FnMut(usize) -> T>(len: usize, f: F) -> Self {
+        let buffer: Rc<[T]> = (0..len).map(f).collect();
+        RcSlice::from(buffer)
+    }
+}
+
+impl<T: Clone> RcSlice<[T]> {
+    /// Creates a slice covering a copy of `data`.
+    ///
+    /// An `Rc` must own its buffer, so the elements are cloned once into a new reference-counted
+    /// buffer; slicing the result afterward does not copy anything. When `data` is empty, no
+    /// elements are cloned, but a (zero-length) buffer is still allocated.
+    ///
+    /// ```
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// static SQUARES: [u32; 6] = [0, 1, 4, 9, 16, 25];
+    ///
+    /// let table = Rcs::from_static(&SQUARES);
+    /// let (low, high) = Rcs::split_at(&table, 3);
+    /// assert_eq!(*low, [0, 1, 4]);
+    /// assert_eq!(*high, [9, 16, 25]);
+    /// ```
+    pub fn from_static(data: &'static [T]) -> Self {
+        RcSlice::from(Rc::<[T]>::from(data))
+    }
+}
+
+impl<T: RcSliceContainer + Clone> RcSlice<T> {
+    /// Creates an [`ArcSlice`] with the same range as `it`, over a copy of the whole underlying
+    /// buffer.
+    ///
+    /// This always clones the container, even if `it` is the only reference to it. `Rc` and
+    /// `Arc` store their reference counts differently, so an `Rc` allocation can never be reused
+    /// by an `Arc`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::{ArcSlice, RcSlice};
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// let shared = Rcs::to_arc_slice(&slice);
+    /// assert_eq!(*shared, [4, 6, 8]);
+    /// assert_eq!(ArcSlice::bounds_range(&shared), 1..4);
+    /// ```
+    pub fn to_arc_slice(it: &Self) -> ArcSlice<T> {
+        ArcSlice::new(&Arc::new(T::clone(&it.underlying)), it.start..it.end)
+    }
+
+    /// Returns a mutable reference into the given slice, copying the underlying container into a
+    /// new buffer first if needed. Unlike [`get_mut`](RcSlice::get_mut), this never fails.
+    ///
+    /// This is [`Rc::make_mut`] for slices: if there are other strong references to the buffer,
+    /// the whole container is cloned, and `it` is moved to the copy with the same range. If
+    /// there are only weak references, the container is moved (not cloned) into a new `Rc`, and
+    /// the weak references are disconnected.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    ///
+    /// Rcs::try_make_mut(&mut slice).fill(0);
+    /// assert_eq!(*slice, [0, 0, 0]);
+    /// assert_eq!(**Rcs::inner(&slice), [2, 0, 0, 0, 10]);
+    ///
+    /// // The original buffer is unchanged.
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn try_make_mut(it: &mut Self) -> &mut [T::Item] {
+        Rc::make_mut(&mut it.underlying)
+            .get_mut(it.start..it.end)
+            .unwrap()
+    }
+
+    /// Makes sure `it` is the only reference to its buffer, so that a batch of later calls to
+    /// [`get_mut`](RcSlice::get_mut) or [`get_mut_full`](RcSlice::get_mut_full) always succeed.
+    ///
+    /// If there are other strong references, the whole container is cloned into a new buffer,
+    /// and `it` keeps the same range into the copy, so elements before and after the slice can
+    /// still be reached with [`change_range`](RcSlice::change_range). If there are only weak
+    /// references, the container is moved instead, and the weak references are disconnected.
+    /// This is the same copy [`try_make_mut`](RcSlice::try_make_mut) makes, without borrowing
+    /// the elements.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    /// let mut slice = Rcs::new(&buffer, 1..4);
+    /// assert_eq!(Rcs::get_mut(&mut slice), None);
+    ///
+    /// Rcs::make_unique(&mut slice);
+    /// Rcs::get_mut(&mut slice).unwrap()[0] = 0;
+    /// Rcs::get_mut(&mut slice).unwrap()[2] = 0;
+    /// assert_eq!(**Rcs::inner(&slice), [2, 0, 6, 0, 10]);
+    /// assert_eq!(*buffer, [2, 4, 6, 8, 10]);
+    /// ```
+    pub fn make_unique(it: &mut Self) {
+        Rc::make_mut(&mut it.underlying);
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + IntoIterator<Item = <T as RcSliceContainer>::Item>,
+    <T as RcSliceContainer>::Item: Clone,
+{
+    /// Consumes the slice, and returns an iterator over its elements by value.
+    ///
+    /// If `it` is the only reference to the buffer (not counting weak references), the container
+    /// is unwrapped and the viewed elements are moved out of it without being cloned; the rest
+    /// of the container is dropped. Otherwise, the buffer is left alone for its other owners, and
+    /// each element is cloned as the iterator reaches it.
+    ///
+    /// Moving elements out requires consuming the container, so this is only available for
+    /// sized containers like `Vec<T>`, not for `[T]`.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use alloc::string::String;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let words: Vec<String> = ["a", "b", "c", "d"].map(String::from).into();
+    /// let buffer = Rc::new(words);
+    ///
+    /// // Shared: the elements are cloned.
+    /// let slice = Rcs::new(&buffer, 1..3);
+    /// assert_eq!(Rcs::into_iter_owned(slice).collect::<Vec<_>>(), ["b", "c"]);
+    ///
+    /// // Unique: the elements are moved out of the buffer.
+    /// let slice = Rcs::new(&buffer, 2..);
+    /// core::mem::drop(buffer);
+    /// assert_eq!(Rcs::into_iter_owned(slice).collect::<Vec<_>>(), ["c", "d"]);
+    /// ```
+    pub fn into_iter_owned(it: Self) -> RcIntoIterOwned<T> {
+        let (start, end) = (it.start, it.end);
+        let inner = match Rc::try_unwrap(it.underlying) {
+            Ok(container) => {
+                IntoIterOwned::Unique(container.into_iter().skip(start).take(end - start))
+            }
+            Err(underlying) => IntoIterOwned::Shared(RcSlice::from_bounds(&underlying, start, end)),
+        };
+        RcIntoIterOwned { inner }
+    }
+}
+
+/// Iterator returned by [`RcSlice::into_iter_owned`].
+pub struct RcIntoIterOwned<T: IntoIterator> {
+    inner: IntoIterOwned<T::IntoIter, T>,
+}
+
+impl<T> Iterator for RcIntoIterOwned<T>
+where
+    T: RcSliceContainer + IntoIterator<Item = <T as RcSliceContainer>::Item>,
+    <T as RcSliceContainer>::Item: Clone,
+{
+    type Item = <T as RcSliceContainer>::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T> Clone for RcIntoIterOwned<T>
+where
+    T: IntoIterator,
+    T::IntoIter: Clone,
+{
+    fn clone(&self) -> Self {
+        let inner = match &self.inner {
+            IntoIterOwned::Unique(iter) => IntoIterOwned::Unique(iter.clone()),
+            IntoIterOwned::Shared(rest) => IntoIterOwned::Shared(rest.clone()),
+        };
+        Self { inner }
+    }
+}
+
+impl<T> fmt::Debug for RcIntoIterOwned<T>
+where
+    T: RcSliceContainer + IntoIterator + fmt::Debug,
+    T::IntoIter: fmt::Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.inner {
+            IntoIterOwned::Unique(iter) => f.debug_tuple("Unique").field(iter).finish(),
+            IntoIterOwned::Shared(rest) => f.debug_tuple("Shared").field(rest).finish(),
+        }
+    }
+}
+
+/// The state of an [`RcIntoIterOwned`].
+enum IntoIterOwned<I, T> {
+    /// The elements are moved out of the unwrapped container.
+    Unique(core::iter::Take<core::iter::Skip<I>>),
+    /// The buffer is shared; the elements that haven't been cloned yet.
+    Shared(RcSlice<T>),
+}
+
+impl<I, T> Iterator for IntoIterOwned<I, T>
+where
+    I: Iterator<Item = T::Item>,
+    T: RcSliceContainer,
+    T::Item: Clone,
+{
+    type Item = T::Item;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match self {
+            IntoIterOwned::Unique(iter) => iter.next(),
+            IntoIterOwned::Shared(rest) => {
+                let item = RcSlice::peek(rest)?.clone();
+                RcSlice::advance(rest, 1);
+                Some(item)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self {
+            IntoIterOwned::Unique(iter) => iter.size_hint(),
+            IntoIterOwned::Shared(rest) => (rest.len(), Some(rest.len())),
+        }
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: TotalCmp,
+{
+    /// Sorts the slice in place by [`TotalCmp::total_cmp`] (which is `f32::total_cmp` or
+    /// `f64::total_cmp`), if there are no other RcSlice or Rc pointers to the buffer. Returns
+    /// `false` without sorting if the buffer is shared.
+    ///
+    /// Unlike `PartialOrd`, this ordering is total, so the result is deterministic even if the
+    /// slice contains NaN: negative NaN sorts first, then `-inf`, ..., `-0.0`, `0.0`, ...,
+    /// `inf`, and positive NaN sorts last.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[f64]> = Rc::new([2.5, -1.0, 0.0, -0.0]);
+    /// let mut slice = Rcs::from(buffer);
+    ///
+    /// assert!(Rcs::sort_by_total_cmp(&mut slice));
+    /// assert_eq!(*slice, [-1.0, -0.0, 0.0, 2.5]);
+    /// assert!(slice[1].is_sign_negative());
+    /// ```
+    pub fn sort_by_total_cmp(it: &mut Self) -> bool {
+        match RcSlice::get_mut(it) {
+            Some(slice) => {
+                slice.sort_by(TotalCmp::total_cmp);
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+{
+    /// Compares the slice element by element against the items of `iter`, without collecting
+    /// them first. Returns `true` if they have the same length and every pair is equal.
+    ///
+    /// Stops at the first mismatch. If `iter` is longer than the slice, only one item past the
+    /// end of the slice is taken from it.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u32]> = Rc::new([1, 4, 9, 16]);
+    /// let slice = Rcs::new(&buffer, 1..);
+    ///
+    /// assert!(Rcs::eq_iter(&slice, (2..5).map(|x| x * x)));
+    /// assert!(!Rcs::eq_iter(&slice, (2..4).map(|x| x * x)));
+    /// assert!(!Rcs::eq_iter(&slice, (2..).map(|x| x * x)));
+    /// ```
+    pub fn eq_iter<I>(it: &Self, iter: I) -> bool
+    where
+        I: IntoIterator,
+        T::Item: PartialEq<I::Item>,
+    {
+        let mut iter = iter.into_iter();
+        for item in RcSlice::as_slice(it) {
+            match iter.next() {
+                Some(other) if *item == other => {}
+                _ => return false,
+            }
+        }
+        iter.next().is_none()
+    }
+
+    /// Compares the elements before the first `sentinel` in the slice against `other`. If the
+    /// slice doesn't contain `sentinel`, the whole slice is compared.
+    ///
+    /// This is useful for null-terminated data, without finding the terminator first.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"name\0\0\0\0"[..]);
+    /// let field = Rcs::from(buffer);
+    ///
+    /// assert!(Rcs::eq_until(&field, b"name", &0));
+    /// assert!(!Rcs::eq_until(&field, b"name\0", &0));
+    /// assert!(!Rcs::eq_until(&field, b"nam", &0));
+    /// ```
+    pub fn eq_until(it: &Self, other: &[T::Item], sentinel: &T::Item) -> bool
+    where
+        T::Item: PartialEq,
+    {
+        let slice = RcSlice::as_slice(it);
+        let end = slice
+            .iter()
+            .position(|item| item == sentinel)
+            .unwrap_or(slice.len());
+        slice[..end] == *other
+    }
+
+    /// Returns `true` if both slices view the same range of the same buffer (see
+    /// [`ptr_eq`](RcSlice::ptr_eq)), without looking at any elements. Otherwise, compares the
+    /// elements.
+    ///
+    /// This is useful for code such as caches, where most comparisons are between clones. It
+    /// gives the same result as `==` unless the elements' `==` isn't reflexive, like `f64::NAN`:
+    /// a slice is always `same_or_eq` to its clones, even when it isn't `==` to them.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[f64]> = Rc::new([1.0, f64::NAN]);
+    /// let key = Rcs::new(&buffer, ..);
+    ///
+    /// // Not compared element by element, so NaN doesn't matter, unlike with `==`.
+    /// assert!(Rcs::same_or_eq(&key, &key.clone()));
+    /// assert!(key != key.clone());
+    ///
+    /// let copy: Rc<[f64]> = Rc::new([1.0, 2.0]);
+    /// assert!(Rcs::same_or_eq(&Rcs::new(&buffer, ..1), &Rcs::new(&copy, ..1)));
+    /// assert!(!Rcs::same_or_eq(&key, &Rcs::new(&copy, ..)));
+    /// ```
+    pub fn same_or_eq(a: &Self, b: &Self) -> bool
+    where
+        T::Item: PartialEq,
+    {
+        RcSlice::ptr_eq(a, b) || RcSlice::as_slice(a) == RcSlice::as_slice(b)
+    }
+}
+
+impl<T> RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    Rc<T>: Default,
+{
+    /// Takes the slice out of `it`, leaving an empty [`default`](RcSlice::default) slice in its
+    /// place. This is [`core::mem::take`].
+    ///
+    /// The empty slice left behind uses its own (empty) buffer, so it doesn't keep the taken
+    /// slice's buffer alive, or stop [`get_mut`](RcSlice::get_mut) from working on it.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let mut pending = Rcs::from(Rc::new(vec![2, 4, 6]));
+    ///
+    /// let mut taken = Rcs::take(&mut pending);
+    /// assert!(pending.is_empty());
+    /// assert_eq!(*taken, [2, 4, 6]);
+    /// assert!(Rcs::get_mut(&mut taken).is_some());
+    /// ```
+    pub fn take(it: &mut Self) -> Self {
+        core::mem::take(it)
+    }
+}
+
+impl<T: RcSliceContainer + 'static> RcSlice<T> {
+    /// Leaks the buffer, and returns the elements of the slice with a `'static` lifetime, if
+    /// there are no other RcSlice or Rc pointers to the buffer. Returns `None` if the buffer is
+    /// shared; the slice is dropped in that case.
+    ///
+    /// The container is moved out of the `Rc` with [`Rc::try_unwrap`], and then leaked with
+    /// [`Box::leak`]. The whole container is leaked, including any elements outside the slice,
+    /// so [`shrink`](RcSlice::shrink) first if that matters. Weak pointers don't prevent this,
+    /// but they can't be upgraded afterward.
+    ///
+    /// This is only available for sized containers like `Vec<T>`, because an `Rc<[T]>` can't be
+    /// unwrapped without `unsafe` code. Slices of an `Rc<[T]>` have their own
+    /// [`leak_copy`](RcSlice::leak_copy), which leaks a copy of the elements instead.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<Vec<u8>> = Rc::new(b"interned name".to_vec());
+    /// let name = Rcs::new(&buffer, 9..);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Rcs::leak(name.clone()), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// let name: &'static [u8] = Rcs::leak(name).unwrap();
+    /// assert_eq!(name, b"name");
+    /// ```
+    pub fn leak(it: Self) -> Option<&'static [T::Item]> {
+        let container = Rc::try_unwrap(it.underlying).ok()?;
+        let container: &'static T = Box::leak(Box::new(container));
+        Some(container.get_unchecked_by_invariant(it.start..it.end))
+    }
+}
+
+impl<T: Clone + 'static> RcSlice<[T]> {
+    /// Leaks a copy of the elements of the slice, and returns it with a `'static` lifetime, if
+    /// there are no other RcSlice, Rc, or Weak pointers to the buffer. Returns `None` if the
+    /// buffer is shared; the slice is dropped in that case.
+    ///
+    /// Only the elements inside the slice are copied and leaked. The buffer itself is dropped
+    /// as usual, so elements outside the slice don't stay alive.
+    ///
+    /// ```
+    /// # extern crate alloc;
+    /// # use alloc::rc::Rc;
+    /// # use rc_slice2::RcSlice;
+    /// use RcSlice as Rcs;
+    ///
+    /// let buffer: Rc<[u8]> = Rc::from(&b"interned name"[..]);
+    /// let name = Rcs::new(&buffer, 9..);
+    ///
+    /// // Fails: the buffer is shared.
+    /// assert_eq!(Rcs::leak_copy(name.clone()), None);
+    /// core::mem::drop(buffer);
+    ///
+    /// let name: &'static [u8] = Rcs::leak_copy(name).unwrap();
+    /// assert_eq!(name, b"name");
+    /// ```
+    pub fn leak_copy(mut it: Self) -> Option<&'static [T]> {
+        Rc::get_mut(&mut it.underlying)?;
+        Some(Box::leak(Box::from(&*it)))
+    }
+}
+
+#[test]
+fn test_index_ranges() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<alloc::vec::Vec<u8>> = Rc::new((0..10).collect());
+    let slice = Rcs::new(&buffer, 3..8);
+    assert_eq!(*slice, [3, 4, 5, 6, 7]);
+
+    assert_eq!(*Rcs::index(&slice, ..), [3, 4, 5, 6, 7]);
+    assert_eq!(*Rcs::index(&slice, 1..), [4, 5, 6, 7]);
+    assert_eq!(*Rcs::index(&slice, 8..), []);
+    assert_eq!(*Rcs::index(&slice, 5..), []);
+    assert_eq!(*Rcs::index(&slice, ..2), [3, 4]);
+    assert_eq!(*Rcs::index(&slice, ..70), [3, 4, 5, 6, 7]);
+    assert_eq!(*Rcs::index(&slice, 3..8), [6, 7]);
+}
+
+#[test]
+fn test_index_excluded_start() {
+    use core::ops::Bound::{Excluded, Included, Unbounded};
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let slice = Rcs::new(&buffer, 3..8);
+
+    // An excluded start skips the element at that index.
+    assert_eq!(*Rcs::index(&slice, (Excluded(0), Unbounded)), [4, 5, 6, 7]);
+    assert_eq!(*Rcs::index(&slice, (Excluded(1), Included(3))), [5, 6]);
+    assert_eq!(*Rcs::index(&slice, (Excluded(4), Unbounded)), []);
+    assert_eq!(*Rcs::index(&slice, (Excluded(usize::MAX), Unbounded)), []);
+}
+
+#[test]
+fn test_iter_indices() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    let (first_index, first) = Rcs::iter_indices(&slice).next().unwrap();
+    assert_eq!(first_index, Rcs::bounds_range(&slice).start);
+    assert_eq!(*first, slice[0]);
+    assert!(Rcs::iter_indices(&slice).eq(slice.iter().enumerate().map(|(i, x)| (i + 1, x))));
+
+    let empty = Rcs::new(&buffer, 5..);
+    assert_eq!(Rcs::iter_indices(&empty).next(), None);
+}
+
+#[test]
+fn test_new_utf8() {
+    use RcSlice as Rcs;
+
+    // Contains 1, 2, 3, and 4 byte characters.
+    let text = "aé€😀b";
+    let buffer: Rc<[u8]> = Rc::from(text.as_bytes());
+
+    for start in 0..=text.len() + 1 {
+        for end in start..=text.len() + 1 {
+            let clamped_start = usize::min(start, text.len());
+            let clamped_end = usize::min(end, text.len());
+            let expected =
+                text.is_char_boundary(clamped_start) && text.is_char_boundary(clamped_end);
+            assert_eq!(Rcs::new_utf8(&buffer, start..end).is_some(), expected);
+        }
+    }
+
+    let euro = Rcs::new_utf8(&buffer, 3..6).unwrap();
+    assert_eq!(core::str::from_utf8(&euro), Ok("€"));
+    assert_eq!(*Rcs::new_utf8(&buffer, ..).unwrap(), *text.as_bytes());
+    assert_eq!(Rcs::new_utf8(&buffer, 4..), None);
+    assert_eq!(Rcs::new_utf8(&buffer, ..7), None);
+}
+
+#[test]
+fn test_clone_detached() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+    let detached = Rcs::clone_detached(&slice);
+
+    assert_eq!(*detached, [4, 6, 8]);
+    assert_eq!(Rcs::bounds_range(&detached), 0..3);
+    assert_eq!(Rc::strong_count(Rcs::inner(&detached)), 1);
+    assert_eq!(Rc::strong_count(&buffer), 2);
+    assert!(!Rcs::ptr_eq(&slice, &detached));
+}
+
+#[test]
+fn test_try_into_array() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    assert_eq!(Rcs::try_into_array::<3>(&slice), Some([4, 6, 8]));
+    assert_eq!(Rcs::try_into_array::<2>(&slice), None);
+    assert_eq!(Rcs::try_into_array::<4>(&slice), None);
+    assert_eq!(Rcs::try_into_array::<0>(&Rcs::new(&buffer, 5..)), Some([]));
+}
+
+#[test]
+fn test_cmp() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let other_buffer: Rc<[u8]> = Rc::new([0, 1, 2]);
+    let short = Rcs::new(&buffer, ..2);
+    let long = Rcs::new(&buffer, ..);
+    let other_short = Rcs::new(&other_buffer, 1..);
+
+    // A prefix is always less than the full slice.
+    assert_eq!(Rcs::cmp(&short, &long), Ordering::Less);
+    assert_eq!(Rcs::cmp(&long, &short), Ordering::Greater);
+    assert!(short < long);
+
+    // Equal elements in different buffers, or different parts of a buffer, compare equal.
+    assert_eq!(Rcs::cmp(&short, &other_short), Ordering::Equal);
+    assert_eq!(short, other_short);
+    assert_eq!(short.partial_cmp(&other_short), Some(Ordering::Equal));
+
+    let empty = Rcs::new(&buffer, 3..);
+    assert_eq!(Rcs::cmp(&empty, &short), Ordering::Less);
+    assert_eq!(
+        Rcs::cmp(&empty, &Rcs::new(&other_buffer, 0..0)),
+        Ordering::Equal
+    );
+    assert_eq!(Ord::cmp(&long, &short), [1, 2, 3][..].cmp(&[1, 2][..]));
+}
+
+#[test]
+fn test_join() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let first: Rc<Vec<u8>> = Rc::new(vec![1, 2]);
+    let second: Rc<Vec<u8>> = Rc::new(vec![3]);
+    let parts = [
+        Rcs::new(&first, ..1),
+        Rcs::new(&first, 1..),
+        Rcs::new(&second, ..),
+    ];
+
+    let joined = Rcs::join(&parts, &[0]);
+    assert_eq!(*joined, [1, 0, 2, 0, 3]);
+    assert_eq!(Rcs::bounds_range(&joined), 0..5);
+    assert_eq!(*Rcs::join(&parts, &[7, 7]), [1, 7, 7, 2, 7, 7, 3]);
+}
+
+/// A container that claims to be as large as possible, without storing anything.
+/// Used to test bounds arithmetic near `usize::MAX`.
+#[cfg(test)]
+struct HugeContainer;
+
+#[cfg(test)]
+impl RcSliceContainer for HugeContainer {
+    type Item = u8;
+    const IS_SHRINKABLE: bool = false;
+
+    fn len(&self) -> usize {
+        usize::MAX
+    }
+
+    fn get(&self, _range: Range<usize>) -> Option<&[u8]> {
+        None
+    }
+
+    fn get_mut(&mut self, _range: Range<usize>) -> Option<&mut [u8]> {
+        None
+    }
+
+    fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+        unimplemented!()
+    }
+}
+
+#[test]
+fn test_split_at_near_usize_max() {
+    use RcSlice as Rcs;
+
+    let buffer = Rc::new(HugeContainer);
+    let slice = Rcs::new(&buffer, usize::MAX - 2..);
+    assert_eq!(Rcs::len(&slice), 2);
+
+    let (low, high) = Rcs::split_at(&slice, 1);
+    assert_eq!(Rcs::bounds_range(&low), usize::MAX - 2..usize::MAX - 1);
+    assert_eq!(Rcs::bounds_range(&high), usize::MAX - 1..usize::MAX);
+
+    let (low, high) = Rcs::split_at(&slice, 2);
+    assert_eq!(Rcs::bounds_range(&low), usize::MAX - 2..usize::MAX);
+    assert_eq!(Rcs::bounds_range(&high), usize::MAX..usize::MAX);
+
+    assert!(Rcs::try_split_at(&slice, 3).is_none());
+    assert!(Rcs::try_split_at(&slice, usize::MAX).is_none());
+}
+
+#[test]
+#[should_panic(expected = "split index out of bounds")]
+fn test_split_at_overflow_panics() {
+    let buffer = Rc::new(HugeContainer);
+    let slice = RcSlice::new(&buffer, usize::MAX - 2..);
+    RcSlice::split_at(&slice, usize::MAX);
+}
+
+#[test]
+fn test_get_mut_full() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![0; 8]);
+    let mut slice = Rcs::new(&buffer, 2..6);
+    let other = slice.clone();
+    assert!(Rcs::get_mut_full(&mut slice).is_none());
+    core::mem::drop(buffer);
+    assert!(Rcs::get_mut_full(&mut slice).is_none());
+    core::mem::drop(other);
+
+    for (i, x) in Rcs::get_mut_full(&mut slice)
+        .unwrap()
+        .iter_mut()
+        .enumerate()
+    {
+        *x = i as u8;
+    }
+    assert_eq!(*slice, [2, 3, 4, 5]);
+    assert_eq!(**Rcs::inner(&slice), [0, 1, 2, 3, 4, 5, 6, 7]);
+}
+
+#[test]
+fn test_contains_slice() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([0; 10]);
+    let outer = Rcs::new(&buffer, 2..8);
+
+    // Nested.
+    assert!(Rcs::contains_slice(&outer, &Rcs::new(&buffer, 2..8)));
+    assert!(Rcs::contains_slice(&outer, &Rcs::new(&buffer, 4..6)));
+    assert!(Rcs::contains_slice(&outer, &Rcs::new(&buffer, 8..8)));
+    assert!(Rcs::contains_slice(&Rcs::new(&buffer, ..), &outer));
+
+    // Partially overlapping.
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, 1..5)));
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, 5..9)));
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, ..)));
+
+    // Disjoint.
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, ..2)));
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&buffer, 9..)));
+
+    // Different buffers.
+    let other_buffer: Rc<[u8]> = Rc::new([0; 10]);
+    assert!(!Rcs::contains_slice(&outer, &Rcs::new(&other_buffer, 4..6)));
+}
+
+#[test]
+fn test_partition_point_absolute() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 3, 5, 7, 2, 4, 6, 8]);
+    let odds = Rcs::new(&buffer, ..4);
+    let evens = Rcs::new(&buffer, 4..);
+
+    assert_eq!(Rcs::partition_point_absolute(&odds, |&x| x < 4), 2);
+    assert_eq!(Rcs::partition_point_absolute(&evens, |&x| x < 4), 5);
+    assert_eq!(Rcs::partition_point_absolute(&odds, |_| true), 4);
+    assert_eq!(Rcs::partition_point_absolute(&evens, |_| true), 8);
+    assert_eq!(Rcs::partition_point_absolute(&evens, |_| false), 4);
+}
+
+#[test]
+fn test_as_array() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    assert_eq!(Rcs::as_array::<3>(&slice), Some(&[4, 6, 8]));
+    assert_eq!(Rcs::as_array::<2>(&slice), None);
+    assert_eq!(Rcs::as_array::<4>(&slice), None);
+    assert_eq!(Rcs::as_array::<0>(&Rcs::new(&buffer, 5..)), Some(&[]));
+
+    // The array points into the original buffer.
+    let array = Rcs::as_array::<3>(&slice).unwrap();
+    assert!(core::ptr::eq(&array[0], &buffer[1]));
+}
+
+#[test]
+fn test_advance_retract_owned() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7]);
+    let mut slice = Rcs::new(&buffer, ..);
+
+    let front = Rcs::advance_owned(&mut slice, 2).unwrap();
+    let back = Rcs::retract_owned(&mut slice, 2).unwrap();
+    Rcs::advance(&mut slice, 1);
+    assert_eq!(front, [1, 2]);
+    assert_eq!(back, [6, 7]);
+    assert_eq!(*slice, [4, 5]);
+
+    // Failed operations leave the slice unchanged.
+    assert_eq!(Rcs::advance_owned(&mut slice, 3), None);
+    assert_eq!(Rcs::retract_owned(&mut slice, 3), None);
+    assert_eq!(*slice, [4, 5]);
+
+    assert_eq!(Rcs::advance_owned(&mut slice, 0), Some(Vec::new()));
+    assert_eq!(Rcs::retract_owned(&mut slice, 2), Some(alloc::vec![4, 5]));
+    assert!(Rcs::is_empty(&slice));
+}
+
+#[cfg(debug_assertions)]
+#[test]
+#[should_panic(expected = "range start is greater than range end")]
+fn test_new_inverted_range() {
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let (start, end) = (5, 2);
+    RcSlice::new(&buffer, start..end);
+}
+
+/// A hasher that records everything written to it.
+#[cfg(test)]
+#[derive(Default)]
+struct RecordingHasher(Vec<u8>);
+
+#[cfg(test)]
+impl Hasher for RecordingHasher {
+    fn finish(&self) -> u64 {
+        0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        self.0.extend_from_slice(bytes);
+    }
+}
+
+#[test]
+fn test_hash_matches_slice() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..3);
+
+    // Hashes like the borrowed slice, including its length.
+    let mut expected = RecordingHasher::default();
+    [4u8, 6][..].hash(&mut expected);
+    assert!(expected.0.ends_with(&[4, 6]));
+    assert!(expected.0.len() > 2);
+
+    let mut hashed = RecordingHasher::default();
+    slice.hash(&mut hashed);
+    assert_eq!(hashed.0, expected.0);
+
+    // hash_with_len is an alias.
+    let mut with_len = RecordingHasher::default();
+    Rcs::hash_with_len(&slice, &mut with_len);
+    assert_eq!(with_len.0, expected.0);
+}
+
+#[test]
+fn test_lines() {
+    use alloc::vec::Vec;
+    use RcSlice as Rcs;
+
+    fn lines_of(text: &[u8]) -> Vec<Vec<u8>> {
+        let buffer: Rc<[u8]> = Rc::from(text);
+        let lines: Vec<_> = Rcs::lines(&Rcs::new(&buffer, ..)).collect();
+        for line in &lines {
+            assert!(Rc::ptr_eq(Rcs::inner(line), &buffer));
+        }
+        lines.into_iter().map(Vec::from).collect()
+    }
+
+    let expected: Vec<Vec<u8>> = alloc::vec![b"a".to_vec(), b"".to_vec(), b"bc".to_vec()];
+    assert_eq!(lines_of(b"a\n\nbc"), expected);
+    assert_eq!(lines_of(b"a\n\nbc\n"), expected);
+    assert_eq!(lines_of(b"a\r\n\r\nbc\r\n"), expected);
+    assert_eq!(lines_of(b"a\r\n\nbc"), expected);
+
+    assert!(lines_of(b"").is_empty());
+    assert_eq!(lines_of(b"\n"), [b""]);
+    assert_eq!(lines_of(b"\r"), [b"\r"]);
+    assert_eq!(lines_of(b"a\r\nb\r"), [&b"a"[..], &b"b\r"[..]]);
+    assert_eq!(lines_of(b"a\rb"), [b"a\rb"]);
+
+    // Lines are found relative to the slice, not the whole buffer.
+    let buffer: Rc<[u8]> = Rc::from(&b"ab\ncd\nef"[..]);
+    let lines: Vec<_> = Rcs::lines(&Rcs::new(&buffer, 1..7)).collect();
+    assert_eq!(lines.len(), 3);
+    assert_eq!(Rcs::bounds_range(&lines[0]), 1..2);
+    assert_eq!(Rcs::bounds_range(&lines[1]), 3..5);
+    assert_eq!(Rcs::bounds_range(&lines[2]), 6..7);
+}
+
+#[test]
+fn test_shrink_to() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<Vec<u8>> = Rc::new((0..10).collect());
+    let mut slice = Rcs::new(&buffer, 2..8);
+
+    // Shared, so the buffer isn't shrunk, but the slice still changes.
+    assert!(!Rcs::shrink_to(&mut slice, 1..));
+    assert_eq!(*slice, [3, 4, 5, 6, 7]);
+    assert_eq!(buffer.len(), 10);
+
+    core::mem::drop(buffer);
+    assert!(Rcs::shrink_to(&mut slice, ..=1));
+    assert_eq!(*slice, [3, 4]);
+    assert_eq!(**Rcs::inner(&slice), [3, 4]);
+    assert_eq!(Rcs::inner(&slice).capacity(), 2);
+    assert_eq!(Rcs::bounds_range(&slice), 0..2);
+
+    // Ranges are clamped to the current slice.
+    let mut slice = Rcs::new(&Rc::new(vec![1u8, 2, 3, 4]), 1..3);
+    assert!(Rcs::shrink_to(&mut slice, 1..10));
+    assert_eq!(**Rcs::inner(&slice), [3]);
+}
+
+#[test]
+fn test_iterator_size_hints() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::from(&b"a\nbc\n\n\r\nd"[..]);
+    for start in 0..=buffer.len() {
+        for end in start..=buffer.len() {
+            let slice = Rcs::new(&buffer, start..end);
+
+            let mut iter_indices = Rcs::iter_indices(&slice);
+            for remaining in (0..=end - start).rev() {
+                assert_eq!(iter_indices.size_hint(), (remaining, Some(remaining)));
+                iter_indices.next();
+            }
+
+            let mut lines = Rcs::lines(&slice);
+            let mut remaining = Rcs::lines(&slice).count();
+            loop {
+                let (lower, upper) = lines.size_hint();
+                assert!(lower <= remaining);
+                assert!(remaining <= upper.unwrap());
+                if lines.next().is_none() {
+                    break;
+                }
+                remaining -= 1;
+            }
+            assert_eq!(remaining, 0);
+        }
+    }
+}
+
+#[test]
+fn test_not_send_or_sync() {
+    // If `RcSlice` implemented `Send` (or `Sync`), both impls of the trait would apply, and
+    // the type of `Marker` couldn't be inferred, which fails to compile.
+    trait AmbiguousIfImpl<Marker> {
+        fn some_item() {}
+    }
+    impl<T: ?Sized> AmbiguousIfImpl<()> for T {}
+
+    struct SendMarker;
+    impl<T: ?Sized + Send> AmbiguousIfImpl<SendMarker> for T {}
+
+    struct SyncMarker;
+    impl<T: ?Sized + Sync> AmbiguousIfImpl<SyncMarker> for T {}
+
+    <RcSlice<[u8]> as AmbiguousIfImpl<_>>::some_item();
+}
+
+#[test]
+fn test_get_range_mut() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let mut slice = Rcs::new(&buffer, 1..4);
+
+    // Shared.
+    assert_eq!(Rcs::get_range_mut(&mut slice, ..), None);
+    core::mem::drop(buffer);
+
+    // Out of range.
+    assert_eq!(Rcs::get_range_mut(&mut slice, ..4), None);
+    assert_eq!(Rcs::get_range_mut(&mut slice, 4..), None);
+    assert_eq!(Rcs::get_range_mut(&mut slice, 0..=3), None);
+
+    // Success.
+    assert_eq!(Rcs::get_range_mut(&mut slice, 3..), Some(&mut [][..]));
+    assert_eq!(Rcs::get_range_mut(&mut slice, ..), Some(&mut [4, 6, 8][..]));
+    Rcs::get_range_mut(&mut slice, 1..=1).unwrap()[0] = 7;
+    assert_eq!(*slice, [4, 7, 8]);
+    assert_eq!(**Rcs::inner(&slice), [2, 4, 7, 8, 10]);
+}
+
+#[test]
+fn test_eq_compares_elements() {
+    use core::cell::Cell;
+
+    struct Counting<'a>(u8, &'a Cell<usize>);
+
+    impl PartialEq for Counting<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.1.set(self.1.get() + 1);
+            self.0 == other.0
+        }
+    }
+
+    let count = Cell::new(0);
+    let buffer: Rc<[Counting]> = (0..100).map(|i| Counting(i, &count)).collect();
+    let slice = RcSlice::new(&buffer, 10..90);
+
+    // Same range of the same buffer; the elements are still compared.
+    assert!(slice == slice.clone());
+    assert_eq!(count.get(), 80);
+
+    // Different ranges with different elements.
+    assert!(RcSlice::new(&buffer, 10..11) != RcSlice::new(&buffer, 11..12));
+    assert_eq!(count.get(), 81);
+
+    // `==` agrees with `partial_cmp` and with comparing the borrowed slices.
+    let nan: Rc<[f64]> = Rc::new([f64::NAN]);
+    let nan = RcSlice::from(nan);
+    assert!(nan != nan.clone());
+    assert_eq!(nan.partial_cmp(&nan.clone()), None);
+    assert!(*nan != *nan.clone());
+}
+
+#[test]
+fn test_with_buffer() {
+    use RcSlice as Rcs;
+
+    let front: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let back: Rc<[u8]> = Rc::new([1, 3, 5, 7, 9]);
+    let slice = Rcs::new(&front, 1..4);
+
+    let other = Rcs::with_buffer(&slice, &back).unwrap();
+    assert_eq!(*other, [3, 5, 7]);
+    assert!(Rc::ptr_eq(Rcs::inner(&other), &back));
+    assert_eq!(Rc::strong_count(&back), 2);
+
+    // Exactly long enough.
+    let exact: Rc<[u8]> = Rc::new([1, 3, 5, 7]);
+    assert_eq!(*Rcs::with_buffer(&slice, &exact).unwrap(), [3, 5, 7]);
+
+    // Too short.
+    let short: Rc<[u8]> = Rc::new([1, 3, 5]);
+    assert_eq!(Rcs::with_buffer(&slice, &short), None);
+    assert_eq!(Rc::strong_count(&short), 1);
+
+    let empty: Rc<[u8]> = Rc::new([]);
+    let empty_slice = Rcs::new(&front, 0..0);
+    assert!(Rcs::with_buffer(&empty_slice, &empty).unwrap().is_empty());
+}
+
+#[test]
+fn test_from_static() {
+    use RcSlice as Rcs;
+
+    static TABLE: [u8; 8] = [1, 2, 4, 8, 16, 32, 64, 128];
+    static EMPTY: [u8; 0] = [];
+
+    let table = Rcs::from_static(&TABLE);
+    assert_eq!(*table, TABLE);
+    assert_ne!(Rcs::inner(&table).as_ptr(), TABLE.as_ptr());
+
+    let mut middle = table.clone();
+    Rcs::change_range(&mut middle, 2..5);
+    assert_eq!(*middle, [4, 8, 16]);
+    assert_eq!(Rc::strong_count(Rcs::inner(&table)), 2);
+
+    let empty = Rcs::from_static(&EMPTY);
+    assert!(empty.is_empty());
+    assert_eq!(Rcs::bounds_range(&empty), 0..0);
+}
+
+#[test]
+fn test_try_shrink() {
+    use crate::ShrinkError;
+    use alloc::boxed::Box;
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // Not shrinkable.
+    let buffer: Rc<Box<[u8]>> = Rc::new(Box::new([2, 4, 6, 8, 10]));
+    let mut slice = Rcs::new(&buffer, 1..3);
+    core::mem::drop(buffer);
+    assert_eq!(Rcs::try_shrink(&mut slice), Err(ShrinkError::NotShrinkable));
+    assert!(!Rcs::shrink(&mut slice));
+
+    // Shared.
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    let mut slice = Rcs::new(&buffer, 1..3);
+    assert_eq!(Rcs::try_shrink(&mut slice), Err(ShrinkError::Shared));
+    assert!(!Rcs::shrink(&mut slice));
+    assert_eq!(*slice, [4, 6]);
+    assert!(Rc::ptr_eq(Rcs::inner(&slice), &buffer));
+
+    // Already minimal.
+    let mut full = Rcs::new(&buffer, ..);
+    core::mem::drop(buffer);
+    core::mem::drop(slice);
+    assert_eq!(Rcs::try_shrink(&mut full), Err(ShrinkError::AlreadyMinimal));
+    assert!(!Rcs::shrink(&mut full));
+
+    // Success.
+    Rcs::change_range(&mut full, 2..4);
+    assert_eq!(Rcs::try_shrink(&mut full), Ok(0..2));
+    assert_eq!(*full, [6, 8]);
+    assert_eq!(**Rcs::inner(&full), [6, 8]);
+}
+
+#[test]
+fn test_advance_retract_while() {
+    use RcSlice as Rcs;
+
+    let is_space = |b: &u8| b.is_ascii_whitespace();
+    let buffer: Rc<[u8]> = Rc::from(&b" \t key = value \n"[..]);
+    let mut slice = Rcs::from(buffer);
+
+    assert_eq!(Rcs::advance_while(&mut slice, is_space), b" \t ");
+    assert_eq!(Rcs::retract_while(&mut slice, is_space), b" \n");
+    assert_eq!(*slice, *b"key = value");
+    assert_eq!(Rcs::bounds_range(&slice), 3..14);
+
+    // No match.
+    assert_eq!(Rcs::advance_while(&mut slice, is_space), b"");
+    assert_eq!(Rcs::retract_while(&mut slice, is_space), b"");
+    assert_eq!(*slice, *b"key = value");
+
+    // All match.
+    assert_eq!(Rcs::advance_while(&mut slice, |_| true), b"key = value");
+    assert!(slice.is_empty());
+    assert_eq!(Rcs::bounds_range(&slice), 14..14);
+    assert_eq!(Rcs::advance_while(&mut slice, |_| true), b"");
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let mut slice = Rcs::from(buffer);
+    assert_eq!(Rcs::retract_while(&mut slice, |_| true), [1, 2, 3]);
+    assert_eq!(Rcs::bounds_range(&slice), 0..0);
+}
+
+#[test]
+fn test_peek() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let mut slice = Rcs::new(&buffer, 1..4);
+
+    assert_eq!(Rcs::peek(&slice), Some(&4));
+    assert_eq!(Rcs::peek_n(&slice, 0), Some([].as_slice()));
+    assert_eq!(Rcs::peek_n(&slice, 3), Some([4, 6, 8].as_slice()));
+    assert_eq!(Rcs::peek_n(&slice, 4), None);
+    assert_eq!(Rcs::peek_n(&slice, usize::MAX), None);
+    assert_eq!(Rcs::bounds_range(&slice), 1..4);
+
+    Rcs::advance(&mut slice, 2);
+    assert_eq!(Rcs::peek(&slice), Some(&8));
+    assert_eq!(Rcs::peek_n(&slice, 2), None);
+
+    Rcs::advance(&mut slice, 1);
+    assert_eq!(Rcs::peek(&slice), None);
+    assert_eq!(Rcs::peek_n(&slice, 0), Some([].as_slice()));
+    assert_eq!(Rcs::peek_n(&slice, 1), None);
+}
+
+#[test]
+fn test_retain_mut() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    let mut slice = Rcs::new(&buffer, 1..7);
+
+    // Shared.
+    let mut calls = 0;
+    assert!(!Rcs::retain_mut(&mut slice, |_| {
+        calls += 1;
+        true
+    }));
+    assert_eq!(calls, 0);
+    core::mem::drop(buffer);
+
+    // Increment the odd values, and keep them.
+    assert!(Rcs::retain_mut(&mut slice, |x| {
+        let odd = *x % 2 == 1;
+        if odd {
+            *x += 1;
+        }
+        odd
+    }));
+    assert_eq!(*slice, [4, 6, 8]);
+    assert_eq!(Rcs::bounds_range(&slice), 1..4);
+    assert_eq!(Rcs::inner(&slice)[..4], [1, 4, 6, 8]);
+    assert_eq!(Rcs::inner(&slice)[7], 8);
+
+    assert!(Rcs::shrink(&mut slice));
+    assert_eq!(**Rcs::inner(&slice), [4, 6, 8]);
+
+    // Not shrinkable.
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let mut slice = Rcs::from(buffer);
+    assert!(!Rcs::retain_mut(&mut slice, |_| false));
+    assert_eq!(*slice, [1, 2, 3]);
+}
+
+#[test]
+fn test_split_evenly() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11]);
+    let slice = Rcs::new(&buffer, 1..11);
+
+    // 10 elements into 4 parts.
+    let [a, b, c, d] = Rcs::split_evenly(&slice);
+    assert_eq!(*a, [1, 2, 3]);
+    assert_eq!(*b, [4, 5, 6]);
+    assert_eq!(*c, [7, 8]);
+    assert_eq!(*d, [9, 10]);
+    assert_eq!(Rcs::bounds_range(&d), 9..11);
+    assert_eq!(Rc::strong_count(&buffer), 6);
+
+    let [whole] = Rcs::split_evenly(&slice);
+    assert!(Rcs::ptr_eq(&whole, &slice));
+
+    // More parts than elements.
+    let short = Rcs::new(&buffer, 4..6);
+    let parts: [_; 4] = Rcs::split_evenly(&short);
+    let lens = parts.each_ref().map(|part| part.len());
+    assert_eq!(lens, [1, 1, 0, 0]);
+    assert_eq!(Rcs::bounds_range(&parts[3]), 6..6);
+}
+
+#[test]
+#[should_panic(expected = "cannot split a slice into 0 parts")]
+fn test_split_evenly_zero_panics() {
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let _: [RcSlice<[u8]>; 0] = RcSlice::split_evenly(&RcSlice::from(buffer));
+}
+
+#[test]
+fn test_split_off_before_owned() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // Shared: the original buffer keeps every element.
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    let mut slice = Rcs::new(&buffer, 1..);
+    let front = Rcs::split_off_before_owned(&mut slice, 2).unwrap();
+    assert_eq!(*front, [4, 6]);
+    assert_eq!(*slice, [8, 10]);
+    assert!(Rc::ptr_eq(Rcs::inner(&slice), &buffer));
+    assert_eq!(Rc::strong_count(Rcs::inner(&front)), 1);
+    assert_eq!(Rcs::bounds_range(&front), 0..2);
+
+    // Exclusive: the original buffer is shrunk to the tail.
+    core::mem::drop(buffer);
+    let front = Rcs::split_off_before_owned(&mut slice, 1).unwrap();
+    assert_eq!(*front, [8]);
+    assert_eq!(*slice, [10]);
+    assert_eq!(**Rcs::inner(&slice), [10]);
+    assert_eq!(Rc::strong_count(Rcs::inner(&front)), 1);
+    assert_eq!(Rc::strong_count(Rcs::inner(&slice)), 1);
+
+    // Out of bounds.
+    assert_eq!(Rcs::split_off_before_owned(&mut slice, 2), None);
+    assert_eq!(*slice, [10]);
+}
+
+#[test]
+fn test_index_trait() {
+    use core::ops::Index;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12]);
+    let slice = Rcs::new(&buffer, 1..5);
+
+    assert_eq!(slice[0], 4);
+    assert_eq!(slice[3], 10);
+    assert_eq!(slice[1..3], [6, 8]);
+    assert_eq!(slice[2..], [8, 10]);
+    assert_eq!(slice[..2], [4, 6]);
+    assert_eq!(slice[..], [4, 6, 8, 10]);
+    assert_eq!(slice[1..=2], [6, 8]);
+
+    // Works where deref coercion doesn't apply.
+    fn first_two<S: Index<core::ops::RangeTo<usize>, Output = [u8]>>(s: &S) -> &[u8] {
+        &s[..2]
+    }
+    assert_eq!(first_two(&slice), [4, 6]);
+
+    // The associated function is unaffected.
+    assert_eq!(Rcs::index(&slice, 1..3), Rcs::new(&buffer, 2..4));
+}
+
+#[test]
+#[should_panic]
+fn test_index_trait_out_of_bounds() {
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10, 12]);
+    let slice = RcSlice::new(&buffer, 1..5);
+    let _ = slice[4];
+}
+
+#[test]
+fn test_first_last_chunk() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    // Exact.
+    assert_eq!(Rcs::first_chunk::<3>(&slice), Some(&[4, 6, 8]));
+    assert_eq!(Rcs::last_chunk::<3>(&slice), Some(&[4, 6, 8]));
+
+    // Longer view.
+    assert_eq!(Rcs::first_chunk::<1>(&slice), Some(&[4]));
+    assert_eq!(Rcs::last_chunk::<1>(&slice), Some(&[8]));
+    assert_eq!(Rcs::first_chunk::<0>(&slice), Some(&[]));
+    assert_eq!(Rcs::last_chunk::<0>(&slice), Some(&[]));
+
+    // Shorter view.
+    assert_eq!(Rcs::first_chunk::<4>(&slice), None);
+    assert_eq!(Rcs::last_chunk::<4>(&slice), None);
+
+    let empty = Rcs::new(&buffer, 5..);
+    assert_eq!(Rcs::first_chunk::<1>(&empty), None);
+    assert_eq!(Rcs::last_chunk::<0>(&empty), Some(&[]));
+}
+
+#[test]
+fn test_data_align() {
+    use RcSlice as Rcs;
+
+    #[derive(Clone, Copy, Default)]
+    #[repr(align(64))]
+    struct Aligned(#[allow(dead_code)] u8);
+
+    let buffer: Rc<[Aligned]> = Rc::new([Aligned::default(); 7]);
+    let slice = Rcs::new(&buffer, 1..6);
+    assert_eq!(Rcs::data_align(&slice), 64);
+
+    let (low, high) = Rcs::split_at(&slice, 3);
+    for view in [&slice, &low, &high, &Rcs::new(&buffer, 6..)] {
+        assert_eq!(view.as_ref().as_ptr() as usize % 64, 0);
+    }
+}
+
+#[test]
+fn test_append() {
+    use RcSlice as Rcs;
+
+    let first: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let second: Rc<[u8]> = Rc::new([1, 3, 5, 7, 9]);
+
+    let joined = Rcs::append(&Rcs::new(&first, 1..3), &Rcs::new(&second, 2..));
+    assert_eq!(*joined, [4, 6, 5, 7, 9]);
+    assert_eq!(Rcs::bounds_range(&joined), 0..5);
+    assert_eq!(Rc::strong_count(Rcs::inner(&joined)), 1);
+    assert_eq!(Rc::strong_count(&first), 1);
+
+    let empty = Rcs::new(&first, 5..);
+    assert_eq!(*Rcs::append(&empty, &Rcs::new(&second, ..1)), [1]);
+    assert_eq!(*Rcs::append(&Rcs::new(&second, ..1), &empty), [1]);
+    assert!(Rcs::append(&empty, &empty).is_empty());
+}
+
+#[test]
+fn test_pairwise() {
+    use alloc::vec::Vec;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[i64]> = Rc::new([-3, 0, 2, 7, 7, 20]);
+    let slice = Rcs::new(&buffer, ..);
+
+    let deltas: Vec<i64> = Rcs::pairwise(&slice).map(|(a, b)| b - a).collect();
+    assert_eq!(deltas, [3, 2, 5, 0, 13]);
+    assert_eq!(Rcs::pairwise(&slice).len(), 5);
+    assert_eq!(Rcs::pairwise(&slice).next_back(), Some((&7, &20)));
+
+    let short = Rcs::new(&buffer, 2..4);
+    assert!(Rcs::pairwise(&short).eq([(&2, &7)]));
+
+    let single = Rcs::new(&buffer, 2..3);
+    assert_eq!(Rcs::pairwise(&single).next(), None);
+    assert_eq!(Rcs::pairwise(&Rcs::new(&buffer, 6..)).len(), 0);
+}
+
+#[test]
+fn test_usize_max_inclusive_ranges() {
+    use core::ops::Bound;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+
+    assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, 0..=usize::MAX)), 0..5);
+    assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, ..=usize::MAX)), 0..5);
+    assert_eq!(Rcs::new(&buffer, ..=usize::MAX), Rcs::new(&buffer, ..));
+    assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, 3..=usize::MAX)), 3..5);
+    assert_eq!(
+        Rcs::bounds_range(&Rcs::new(&buffer, usize::MAX..=usize::MAX)),
+        5..5
+    );
+    let excluded = (Bound::Excluded(usize::MAX), Bound::Unbounded);
+    assert_eq!(Rcs::bounds_range(&Rcs::new(&buffer, excluded)), 5..5);
+
+    let mut slice = Rcs::new(&buffer, 1..2);
+    assert_eq!(Rcs::change_range(&mut slice, 0..=usize::MAX), 0..5);
+    assert_eq!(*slice, [2, 4, 6, 8, 10]);
+    assert_eq!(Rcs::change_range(&mut slice, 1..2), 1..2);
+    assert_eq!(Rcs::change_range(&mut slice, ..=usize::MAX), 0..5);
+    assert_eq!(Rcs::change_range(&mut slice, 4..=usize::MAX), 4..5);
+    assert_eq!(Rcs::change_range(&mut slice, usize::MAX..=usize::MAX), 5..5);
+    assert_eq!(Rcs::change_range(&mut slice, excluded), 5..5);
+}
+
+#[test]
+fn test_zero_sized_elements() {
+    use alloc::vec::Vec;
+    use RcSlice as Rcs;
+
+    const LEN: usize = 1 << 20;
+
+    let buffer: Rc<[()]> = core::iter::repeat_n((), LEN).collect();
+    let mut slice = Rcs::new(&buffer, 10..);
+    assert_eq!(Rcs::len(&slice), LEN - 10);
+    assert_eq!(slice.len(), LEN - 10);
+
+    let (low, high) = Rcs::split_at(&slice, 100);
+    assert_eq!(low.len(), 100);
+    assert_eq!(high.len(), LEN - 110);
+    assert_eq!(Rcs::bounds_range(&high), 110..LEN);
+
+    assert_eq!(Rcs::advance(&mut slice, 5).map(<[()]>::len), Some(5));
+    assert_eq!(Rcs::retract(&mut slice, 5).map(<[()]>::len), Some(5));
+    assert_eq!(Rcs::bounds_range(&slice), 15..LEN - 5);
+    assert_eq!(Rcs::advance(&mut slice, LEN), None);
+    assert_eq!(Rcs::saturating_advance(&mut slice, LEN).len(), LEN - 20);
+    assert!(slice.is_empty());
+    assert_eq!(Rcs::bounds_range(&slice), LEN - 5..LEN - 5);
+
+    // get_mut
+    core::mem::drop((buffer, low, high));
+    Rcs::change_range(&mut slice, 1..4);
+    assert_eq!(Rcs::get_mut(&mut slice).map(|s| s.len()), Some(3));
+    assert_eq!(
+        Rcs::get_range_mut(&mut slice, 1..).map(|s| s.len()),
+        Some(2)
+    );
+    assert_eq!(Rcs::get_range_mut(&mut slice, 1..4), None);
+
+    // shrink
+    let buffer: Rc<Vec<()>> = Rc::new(core::iter::repeat_n((), LEN).collect());
+    let mut slice = Rcs::new(&buffer, 7..LEN - 7);
+    core::mem::drop(buffer);
+    assert!(Rcs::shrink(&mut slice));
+    assert_eq!(Rcs::bounds_range(&slice), 0..LEN - 14);
+    assert_eq!(Rcs::inner(&slice).len(), LEN - 14);
+    assert!(!Rcs::shrink(&mut slice));
+}
+
+#[test]
+fn test_mutation_with_live_weaks() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // get_mut_ignoring_weak
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    let weak = Rc::downgrade(&buffer);
+    let mut slice = Rcs::new(&buffer, 1..4);
+    assert_eq!(Rcs::get_mut_ignoring_weak(&mut slice), None);
+    assert!(Rc::ptr_eq(Rcs::inner(&slice), &buffer));
+
+    core::mem::drop(buffer);
+    assert_eq!(Rcs::get_mut(&mut slice), None);
+    Rcs::get_mut_ignoring_weak(&mut slice).unwrap()[0] = 5;
+    assert_eq!(*slice, [5, 6, 8]);
+    assert_eq!(Rcs::bounds_range(&slice), 1..4);
+    assert!(weak.upgrade().is_none());
+    assert_eq!(Rc::weak_count(Rcs::inner(&slice)), 0);
+    assert!(Rcs::get_mut(&mut slice).is_some());
+
+    // try_make_mut, blocked only by a weak reference: moved, not copied.
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    let weak = Rc::downgrade(&buffer);
+    let mut slice = Rcs::new(&buffer, 1..4);
+    let data = buffer.as_ptr();
+    core::mem::drop(buffer);
+    Rcs::try_make_mut(&mut slice)[2] = 9;
+    assert_eq!(*slice, [4, 6, 9]);
+    assert_eq!(Rcs::inner(&slice).as_ptr(), data);
+    assert!(weak.upgrade().is_none());
+
+    // try_make_mut, blocked by a strong reference: copied.
+    let buffer = Rc::clone(Rcs::inner(&slice));
+    let weak = Rc::downgrade(&buffer);
+    Rcs::try_make_mut(&mut slice)[0] = 1;
+    assert_eq!(*slice, [1, 6, 9]);
+    assert_eq!(*buffer, [2, 4, 6, 9, 10]);
+    assert!(weak.upgrade().is_some());
+    assert_eq!(Rc::strong_count(Rcs::inner(&slice)), 1);
+}
+
+#[test]
+fn test_from_fn() {
+    use RcSlice as Rcs;
+
+    let mut calls = alloc::vec::Vec::new();
+    let slice = Rcs::from_fn(4, |i| {
+        calls.push(i);
+        i as u8 * 3
+    });
+    assert_eq!(*slice, [0, 3, 6, 9]);
+    assert_eq!(calls, [0, 1, 2, 3]);
+    assert_eq!(Rcs::bounds_range(&slice), 0..4);
+    assert_eq!(Rc::strong_count(Rcs::inner(&slice)), 1);
+
+    let empty = Rcs::<[u8]>::from_fn(0, |_| unreachable!());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_max_min() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([3, 1, 4, 1, 5, 9, 2, 6]);
+    let slice = Rcs::new(&buffer, 1..7);
+    assert_eq!(Rcs::max(&slice), Some(&9));
+    assert_eq!(Rcs::min(&slice), Some(&1));
+
+    let single = Rcs::new(&buffer, 2..3);
+    assert_eq!(Rcs::max(&single), Some(&4));
+    assert_eq!(Rcs::min(&single), Some(&4));
+
+    let empty = Rcs::new(&buffer, 8..);
+    assert_eq!(Rcs::max(&empty), None);
+    assert_eq!(Rcs::min(&empty), None);
+
+    // Ties return the first occurrence. `Tagged` is ordered only by its key.
+    #[derive(Debug)]
+    struct Tagged(u8, char);
+
+    impl PartialEq for Tagged {
+        fn eq(&self, other: &Self) -> bool {
+            self.0 == other.0
+        }
+    }
+    impl Eq for Tagged {}
+    impl PartialOrd for Tagged {
+        fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+    impl Ord for Tagged {
+        fn cmp(&self, other: &Self) -> Ordering {
+            self.0.cmp(&other.0)
+        }
+    }
+
+    let buffer: Rc<[Tagged]> = Rc::new([
+        Tagged(2, 'a'),
+        Tagged(1, 'b'),
+        Tagged(2, 'c'),
+        Tagged(1, 'd'),
+    ]);
+    let slice = Rcs::from(buffer);
+    assert_eq!(Rcs::max(&slice).map(|t| t.1), Some('a'));
+    assert_eq!(Rcs::min(&slice).map(|t| t.1), Some('b'));
+}
+
+#[test]
+fn test_into_iter_owned() {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use core::cell::Cell;
+    use RcSlice as Rcs;
+
+    #[derive(Debug, PartialEq)]
+    struct Counting<'a>(u8, &'a Cell<usize>);
+
+    impl Clone for Counting<'_> {
+        fn clone(&self) -> Self {
+            self.1.set(self.1.get() + 1);
+            Counting(self.0, self.1)
+        }
+    }
+
+    let clones = Cell::new(0);
+    let buffer = Rc::new((0..6).map(|i| Counting(i, &clones)).collect::<Vec<_>>());
+
+    // Shared: every yielded element is cloned, and the buffer is untouched.
+    let slice = Rcs::new(&buffer, 1..4);
+    let mut iter = Rcs::into_iter_owned(slice);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    assert_eq!(iter.next().map(|c| c.0), Some(1));
+    assert_eq!(clones.get(), 1);
+    let rest: Vec<u8> = iter.map(|c| c.0).collect();
+    assert_eq!(rest, [2, 3]);
+    assert_eq!(clones.get(), 3);
+    assert_eq!(buffer.len(), 6);
+    assert_eq!(Rc::strong_count(&buffer), 1);
+
+    // Unique: nothing is cloned.
+    clones.set(0);
+    let slice = Rcs::new(&buffer, 2..5);
+    core::mem::drop(buffer);
+    let iter = Rcs::into_iter_owned(slice);
+    assert_eq!(iter.size_hint(), (3, Some(3)));
+    let moved: Vec<u8> = iter.map(|c| c.0).collect();
+    assert_eq!(moved, [2, 3, 4]);
+    assert_eq!(clones.get(), 0);
+
+    let buffer = Rc::new(vec![1u8, 2, 3]);
+    let empty = Rcs::new(&buffer, 3..);
+    core::mem::drop(buffer);
+    assert_eq!(Rcs::into_iter_owned(empty).next(), None);
+}
+
+#[test]
+fn test_default_capacity() {
+    use alloc::vec::Vec;
+    use RcSlice as Rcs;
+
+    let slice: RcSlice<[u8]> = RcSlice::default();
+    assert!(slice.is_empty());
+    assert_eq!(Rcs::bounds_range(&slice), 0..0);
+    assert_eq!(Rcs::inner(&slice).len(), 0);
+
+    let slice: RcSlice<Vec<u64>> = RcSlice::default();
+    assert!(slice.is_empty());
+    assert_eq!(Rcs::bounds_range(&slice), 0..0);
+    assert_eq!(Rcs::inner(&slice).capacity(), 0);
+    assert_eq!(Rc::strong_count(Rcs::inner(&slice)), 1);
+}
+
+#[test]
+fn test_for_each_window_mut() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[i32]> = Rc::new([0, 3, 6, 0, 9, 3, 0, 100]);
+    let mut slice = Rcs::new(&buffer, 1..7);
+
+    // Shared.
+    assert!(!Rcs::for_each_window_mut(&mut slice, 2, |_| unreachable!()));
+    core::mem::drop(buffer);
+
+    // A smoother that replaces the last element of each window with the window's mean, using
+    // the already-smoothed values to its left.
+    let mut windows = 0;
+    assert!(Rcs::for_each_window_mut(&mut slice, 3, |window| {
+        windows += 1;
+        window[2] = (window[0] + window[1] + window[2]) / 3;
+    }));
+    assert_eq!(windows, 4);
+    assert_eq!(*slice, [3, 6, 3, 6, 4, 3]);
+
+    // Elements outside the view are untouched.
+    assert_eq!(Rcs::inner(&slice)[0], 0);
+    assert_eq!(Rcs::inner(&slice)[7], 100);
+
+    // Windows larger than the slice are never visited.
+    assert!(Rcs::for_each_window_mut(&mut slice, 7, |_| unreachable!()));
+    let mut count = 0;
+    assert!(Rcs::for_each_window_mut(&mut slice, 6, |_| count += 1));
+    assert_eq!(count, 1);
+}
+
+#[test]
+fn test_for_each_window_mut_usize_max() {
+    // Zero-sized elements, so the buffer can be as long as possible without using any memory.
+    let buffer: Rc<[()]> = Rc::new([(); usize::MAX]);
+    let mut slice = RcSlice::from(buffer);
+
+    let mut calls = 0;
+    assert!(RcSlice::for_each_window_mut(
+        &mut slice,
+        usize::MAX,
+        |window| {
+            assert_eq!(window.len(), usize::MAX);
+            calls += 1;
+        }
+    ));
+    assert_eq!(calls, 1);
+
+    let mut calls = 0;
+    RcSlice::for_each_window_mut(&mut slice, usize::MAX - 1, |_| calls += 1);
+    assert_eq!(calls, 2);
+
+    let mut short = RcSlice::new(RcSlice::inner(&slice), 1..);
+    drop(slice);
+    let mut calls = 0;
+    RcSlice::for_each_window_mut(&mut short, usize::MAX, |_| calls += 1);
+    assert_eq!(calls, 0);
+}
+
+#[test]
+#[should_panic(expected = "window size must be non-zero")]
+fn test_for_each_window_mut_zero_panics() {
+    let buffer: Rc<[i32]> = Rc::new([1, 2, 3]);
+    RcSlice::for_each_window_mut(&mut RcSlice::from(buffer), 0, |_| {});
+}
+
+#[test]
+fn test_to_inner() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..3);
+    assert_eq!(Rc::strong_count(&buffer), 2);
+
+    let handle = Rcs::to_inner(&slice);
+    assert_eq!(Rc::strong_count(&buffer), 3);
+    assert!(Rc::ptr_eq(&handle, &buffer));
+
+    core::mem::drop(slice);
+    assert_eq!(Rc::strong_count(&handle), 2);
+}
+
+#[test]
+fn test_split_first_last_chunk() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    // Exact length.
+    let (chunk, rest) = Rcs::split_first_chunk::<3>(&slice).unwrap();
+    assert_eq!(chunk, &[4, 6, 8]);
+    assert!(rest.is_empty());
+    assert_eq!(Rcs::bounds_range(&rest), 4..4);
+
+    let (rest, chunk) = Rcs::split_last_chunk::<3>(&slice).unwrap();
+    assert_eq!(chunk, &[4, 6, 8]);
+    assert!(rest.is_empty());
+    assert_eq!(Rcs::bounds_range(&rest), 1..1);
+
+    // Longer view.
+    let (chunk, rest) = Rcs::split_first_chunk::<1>(&slice).unwrap();
+    assert_eq!(chunk, &[4]);
+    assert_eq!(Rcs::bounds_range(&rest), 2..4);
+    assert!(Rc::ptr_eq(Rcs::inner(&rest), &buffer));
+
+    let (rest, chunk) = Rcs::split_last_chunk::<1>(&slice).unwrap();
+    assert_eq!(chunk, &[8]);
+    assert_eq!(Rcs::bounds_range(&rest), 1..3);
+
+    let (chunk, rest) = Rcs::split_first_chunk::<0>(&slice).unwrap();
+    assert_eq!(chunk, &[]);
+    assert!(Rcs::ptr_eq(&rest, &slice));
+
+    // Too short.
+    assert_eq!(Rcs::split_first_chunk::<4>(&slice), None);
+    assert_eq!(Rcs::split_last_chunk::<4>(&slice), None);
+}
+
+#[test]
+fn test_sort_by_total_cmp() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[f64]> = Rc::new([1.0, f64::NAN, -0.0, f64::NEG_INFINITY, 0.0, -f64::NAN, -2.0]);
+    let mut slice = Rcs::new(&buffer, ..);
+
+    // Shared.
+    assert!(!Rcs::sort_by_total_cmp(&mut slice));
+    core::mem::drop(buffer);
+
+    assert!(Rcs::sort_by_total_cmp(&mut slice));
+    let bits: [u64; 7] = core::array::from_fn(|i| slice[i].to_bits());
+    let expected = [-f64::NAN, f64::NEG_INFINITY, -2.0, -0.0, 0.0, 1.0, f64::NAN].map(f64::to_bits);
+    assert_eq!(bits, expected);
+
+    let buffer: Rc<[f32]> = Rc::new([0.0, f32::NAN, -0.0, 3.0, -1.0]);
+    let mut slice = Rcs::new(&buffer, 1..);
+    core::mem::drop(buffer);
+    assert!(Rcs::sort_by_total_cmp(&mut slice));
+    let bits: [u32; 4] = core::array::from_fn(|i| slice[i].to_bits());
+    let expected = [-1.0, -0.0, 3.0, f32::NAN].map(f32::to_bits);
+    assert_eq!(bits, expected);
+    assert_eq!(Rcs::inner(&slice)[0].to_bits(), 0.0f32.to_bits());
+}
+
+#[test]
+fn test_get_index() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = Rcs::new(&buffer, 1..4);
+    assert_eq!(Rcs::get_index(&slice, 0), Some(&4));
+    assert_eq!(Rcs::get_index(&slice, 1), Some(&6));
+    assert_eq!(Rcs::get_index(&slice, 2), Some(&8));
+
+    // Indices past the view are out of bounds, even if the buffer has more elements.
+    assert_eq!(Rcs::get_index(&slice, 3), None);
+    assert_eq!(Rcs::get_index(&slice, usize::MAX), None);
+
+    let empty = Rcs::new(&buffer, 5..);
+    assert_eq!(Rcs::get_index(&empty, 0), None);
+}
+
+#[test]
+fn test_saturating_advance_retract_to() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // Zero out every other element, mutating between each step.
+    let mut slice = Rcs::from(Rc::new(vec![1u8, 2, 3, 4, 5, 6, 7]));
+    loop {
+        if let Some(first) = Rcs::get_mut(&mut slice).and_then(|s| s.first_mut()) {
+            *first = 0;
+        }
+        if Rcs::saturating_advance_to(&mut slice, 2).is_empty() {
+            break;
+        }
+    }
+    assert_eq!(Rcs::bounds_range(&slice), 7..7);
+    assert_eq!(**Rcs::inner(&slice), [0, 2, 0, 4, 0, 6, 0]);
+
+    let mut slice = Rcs::from(Rc::new(vec![1u8, 2, 3, 4, 5, 6, 7]));
+    while !Rcs::is_empty(&slice) {
+        if let Some(last) = Rcs::get_mut(&mut slice).and_then(|s| s.last_mut()) {
+            *last *= 10;
+        }
+        let range = Rcs::saturating_retract_to(&mut slice, 3);
+        assert_eq!(range, Rcs::bounds_range(&slice));
+    }
+    assert_eq!(**Rcs::inner(&slice), [10, 2, 3, 40, 5, 6, 70]);
+
+    // Overflowing amounts saturate at the other end of the slice.
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4]);
+    let mut slice = Rcs::new(&buffer, 1..3);
+    assert_eq!(Rcs::saturating_advance_to(&mut slice, usize::MAX), 3..3);
+    let mut slice = Rcs::new(&buffer, 1..3);
+    assert_eq!(Rcs::saturating_retract_to(&mut slice, usize::MAX), 1..1);
+}
+
+#[test]
+fn test_halve() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7]);
+
+    let (left, right) = Rcs::halve(&Rcs::new(&buffer, 1..5));
+    assert_eq!(*left, [2, 3]);
+    assert_eq!(*right, [4, 5]);
+    assert_eq!(Rcs::bounds_range(&left), 1..3);
+    assert_eq!(Rcs::bounds_range(&right), 3..5);
+
+    let (left, right) = Rcs::halve(&Rcs::from(buffer.clone()));
+    assert_eq!(*left, [1, 2, 3, 4]);
+    assert_eq!(*right, [5, 6, 7]);
+
+    let (left, right) = Rcs::halve(&Rcs::new(&buffer, 6..));
+    assert_eq!(*left, [7]);
+    assert_eq!(*right, []);
+
+    let (left, right) = Rcs::halve(&Rcs::new(&buffer, 3..3));
+    assert!(left.is_empty() && right.is_empty());
+    assert!(Rc::ptr_eq(Rcs::inner(&right), &buffer));
+}
+
+/// A container whose `len` claims two more elements than it has.
+#[cfg(test)]
+struct OverlongContainer(Vec<u8>);
+
+#[cfg(test)]
+impl RcSliceContainer for OverlongContainer {
+    type Item = u8;
+    const IS_SHRINKABLE: bool = false;
+
+    fn len(&self) -> usize {
+        self.0.len() + 2
+    }
+
+    fn get(&self, range: Range<usize>) -> Option<&[u8]> {
+        self.0.get(range)
+    }
+
+    fn get_mut(&mut self, range: Range<usize>) -> Option<&mut [u8]> {
+        self.0.get_mut(range)
+    }
+
+    fn shrink_container_to_range(&mut self, _keep_range: Range<usize>) -> Option<Range<usize>> {
+        unimplemented!()
+    }
+}
+
+#[test]
+#[should_panic(expected = "slice range is out of bounds for its container")]
+fn test_inconsistent_container_panics() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer = Rc::new(OverlongContainer(vec![1, 2, 3]));
+    let slice = Rcs::new(&buffer, 1..);
+    assert_eq!(Rcs::len(&slice), 4);
+    let _ = &*slice;
+}
+
+#[test]
+fn test_make_unique() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // Shared: the whole container is copied and the range is kept.
+    let buffer = Rc::new(vec![1u8, 2, 3, 4, 5]);
+    let mut slice = Rcs::new(&buffer, 1..4);
+    let other = slice.clone();
+    Rcs::make_unique(&mut slice);
+    assert!(!Rc::ptr_eq(Rcs::inner(&slice), &buffer));
+    assert_eq!(Rcs::bounds_range(&slice), 1..4);
+    assert_eq!(Rc::strong_count(&buffer), 2);
+    Rcs::get_mut(&mut slice).unwrap().fill(0);
+    Rcs::get_mut_full(&mut slice).unwrap().push(6);
+    Rcs::change_range(&mut slice, ..);
+    assert_eq!(*slice, [1, 0, 0, 0, 5, 6]);
+    assert_eq!(*other, [2, 3, 4]);
+
+    // Already unique: nothing is copied.
+    let before = Rcs::inner(&slice).as_ptr();
+    Rcs::make_unique(&mut slice);
+    assert_eq!(Rcs::inner(&slice).as_ptr(), before);
+
+    // Only weak references: the container is moved, and the weak references are disconnected.
+    let weak = Rc::downgrade(Rcs::inner(&slice));
+    Rcs::make_unique(&mut slice);
+    assert!(Rcs::get_mut(&mut slice).is_some());
+    assert_eq!(Rcs::inner(&slice).as_ptr(), before);
+    assert!(weak.upgrade().is_none());
+}
+
+#[test]
+fn test_boxed_trait_objects() {
+    use alloc::format;
+    use alloc::string::String;
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // Neither `Clone` nor `Default` is implemented for the elements.
+    let items: Vec<Box<dyn fmt::Debug>> = vec![Box::new(1u8), Box::new("two"), Box::new(3.5f32)];
+    let buffer: Rc<[Box<dyn fmt::Debug>]> = Rc::from(items);
+
+    let mut slice = Rcs::new(&buffer, 1..);
+    let (left, right) = Rcs::split_at(&slice, 1);
+    assert_eq!(format!("{:?}", left[0]), "\"two\"");
+    assert_eq!(format!("{:?}", &*right), "[3.5]");
+    assert_eq!(format!("{:?}", Rcs::peek(&slice).unwrap()), "\"two\"");
+
+    assert!(Rcs::get_mut(&mut slice).is_none());
+    drop((left, right, buffer));
+    Rcs::get_mut(&mut slice).unwrap()[1] = Box::new(String::from("four"));
+    assert_eq!(format!("{:?}", &*slice), "[\"two\", \"four\"]");
+
+    // A `Vec` container can also be shrunk, since `Vec` is `Default` for any element type.
+    let items: Vec<Box<dyn fmt::Debug>> = vec![Box::new(1u8), Box::new('b'), Box::new(())];
+    let mut slice = Rcs::new(&Rc::new(items), 1..2);
+    assert!(Rcs::shrink(&mut slice));
+    assert_eq!(format!("{:?}", &**Rcs::inner(&slice)), "['b']");
+}
+
+#[test]
+fn test_range_within() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    let parent = Rcs::new(&buffer, 2..7);
+
+    // Contained children, including nested views and empty slices at either end.
+    let child = Rcs::subslice(&parent, 1..4);
+    assert_eq!(Rcs::range_within(&child, &parent), Some(1..4));
+    let grandchild = Rcs::subslice(&child, 2..);
+    assert_eq!(Rcs::range_within(&grandchild, &parent), Some(3..4));
+    assert_eq!(Rcs::range_within(&grandchild, &child), Some(2..3));
+    assert_eq!(
+        Rcs::range_within(&Rcs::new(&buffer, 2..2), &parent),
+        Some(0..0)
+    );
+    assert_eq!(
+        Rcs::range_within(&Rcs::new(&buffer, 7..7), &parent),
+        Some(5..5)
+    );
+
+    // Children that stick out of the parent, or the parent inside the child.
+    assert_eq!(Rcs::range_within(&Rcs::new(&buffer, 1..4), &parent), None);
+    assert_eq!(Rcs::range_within(&Rcs::new(&buffer, 5..8), &parent), None);
+    assert_eq!(Rcs::range_within(&parent, &child), None);
+
+    // The same range of a different buffer.
+    let other: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7, 8]);
+    assert_eq!(Rcs::range_within(&Rcs::new(&other, 3..5), &parent), None);
+}
+
+#[test]
+fn test_modify() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u32]> = Rc::new([1, 2, 3, 4, 5]);
+    let mut slice = Rcs::new(&buffer, 1..4);
+
+    let mut calls = 0;
+    let result = Rcs::modify(&mut slice, |_| calls += 1);
+    assert_eq!(result, None);
+    assert_eq!(calls, 0);
+
+    drop(buffer);
+    let sum = Rcs::modify(&mut slice, |s| {
+        s.iter_mut().for_each(|x| *x *= 10);
+        s.iter().sum::<u32>()
+    });
+    assert_eq!(sum, Some(90));
+    assert_eq!(*slice, [20, 30, 40]);
+
+    // The closure only sees the view, not the rest of the buffer.
+    let len = Rcs::modify(&mut slice, |s| {
+        s.reverse();
+        s.len()
+    });
+    assert_eq!(len, Some(3));
+    assert_eq!(**Rcs::inner(&slice), [1, 40, 30, 20, 5]);
+}
+
+#[test]
+fn test_char_indices() {
+    use RcSlice as Rcs;
+
+    // One, two, three, and four byte characters.
+    let buffer: Rc<[u8]> = Rc::from("xaé€😀z".as_bytes());
+    let slice = Rcs::new(&buffer, 2..);
+
+    let chars: Vec<_> = Rcs::char_indices(&slice).unwrap().collect();
+    assert_eq!(chars, [(0, 'é'), (2, '€'), (5, '😀'), (9, 'z')]);
+
+    // Each offset is a char boundary of the view, not the buffer.
+    for (offset, c) in chars {
+        let (_, rest) = Rcs::split_at(&slice, offset);
+        let mut utf8 = [0; 4];
+        assert!(rest.starts_with(c.encode_utf8(&mut utf8).as_bytes()));
+    }
+
+    assert_eq!(
+        Rcs::char_indices(&Rcs::new(&buffer, 2..2)).unwrap().count(),
+        0
+    );
+    assert!(Rcs::char_indices(&Rcs::new(&buffer, 3..)).is_none());
+    assert!(Rcs::char_indices(&Rcs::new(&buffer, ..8)).is_none());
+}
+
+#[test]
+fn test_get_mut_or() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer = Rc::new(vec![1u8, 2, 3, 4]);
+    let mut slice = Rcs::new(&buffer, 1..3);
+
+    // Strong sharing is reported even if there are also weak references.
+    let weak = Rc::downgrade(&buffer);
+    assert_eq!(Rcs::get_mut_or(&mut slice), Err(MutError::Shared));
+    let other = slice.clone();
+    drop(buffer);
+    assert_eq!(Rcs::get_mut_or(&mut slice), Err(MutError::Shared));
+    drop(other);
+
+    // Only weak references left: get_mut_ignoring_weak is the remedy.
+    assert_eq!(Rcs::get_mut_or(&mut slice), Err(MutError::HasWeak));
+    Rcs::get_mut_ignoring_weak(&mut slice).unwrap()[0] = 20;
+    assert!(weak.upgrade().is_none());
+
+    Rcs::get_mut_or(&mut slice).unwrap()[1] = 30;
+    assert_eq!(*slice, [20, 30]);
+    assert_eq!(**Rcs::inner(&slice), [1, 20, 30, 4]);
+}
+
+#[test]
+fn test_eq_iter() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    assert!(Rcs::eq_iter(&slice, [2, 3, 4]));
+    assert!(!Rcs::eq_iter(&slice, [2, 3]));
+    assert!(!Rcs::eq_iter(&slice, [2, 3, 4, 5]));
+    assert!(!Rcs::eq_iter(&slice, [2, 0, 4]));
+    assert!(Rcs::eq_iter(&Rcs::new(&buffer, 2..2), core::iter::empty()));
+
+    // Stops at the first mismatch, and takes at most one item past the end of the slice.
+    let mut taken = 0;
+    let counting = (2..).inspect(|_| taken += 1);
+    assert!(!Rcs::eq_iter(&slice, counting));
+    assert_eq!(taken, 4);
+
+    let mut taken = 0;
+    let counting = [2, 9, 4, 5].into_iter().inspect(|_| taken += 1);
+    assert!(!Rcs::eq_iter(&slice, counting));
+    assert_eq!(taken, 2);
+}
+
+#[test]
+fn test_take_prefix() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer = Rc::new(vec![1u8, 2, 3, 4, 5, 6]);
+    let mut stream = Rcs::new(&buffer, 1..);
+
+    let mut prefix = Rcs::take_prefix(&mut stream, 2).unwrap();
+    assert_eq!(*prefix, [2, 3]);
+    assert_eq!(*stream, [4, 5, 6]);
+    assert_eq!(Rcs::bounds_range(&stream), 3..6);
+
+    // The prefix owns its buffer: it can be mutated, and outlives the original.
+    Rcs::get_mut(&mut prefix).unwrap()[0] = 20;
+    drop(stream);
+    drop(buffer);
+    assert_eq!(*prefix, [20, 3]);
+    assert_eq!(Rc::strong_count(Rcs::inner(&prefix)), 1);
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let mut stream = Rcs::from(buffer.clone());
+    assert_eq!(Rcs::take_prefix(&mut stream, 4), None);
+    assert_eq!(*stream, [1, 2, 3]);
+    assert!(Rcs::take_prefix(&mut stream, 0).unwrap().is_empty());
+    assert_eq!(*Rcs::take_prefix(&mut stream, 3).unwrap(), [1, 2, 3]);
+    assert!(stream.is_empty());
+    assert_eq!(Rc::strong_count(&buffer), 2);
+}
+
+#[test]
+fn test_debug_ascii() {
+    use alloc::format;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::from(&b"\x01ok \"q\" \\ \t\x7f\x80\xfe!"[..]);
+    let slice = Rcs::new(&buffer, 1..);
+    assert_eq!(
+        format!("{:?}", Rcs::debug_ascii(&slice)),
+        r#""ok \"q\" \\ \t\x7f\x80\xfe!""#
+    );
+
+    assert_eq!(
+        format!("{:?}", Rcs::debug_ascii(&Rcs::new(&buffer, 0..0))),
+        "\"\""
+    );
+
+    // The elements themselves still format as a list of numbers.
+    assert_eq!(
+        format!("{:?}", &*slice),
+        "[111, 107, 32, 34, 113, 34, 32, 92, 32, 9, 127, 128, 254, 33]"
+    );
+}
+
+#[test]
+fn test_from_parts() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5]);
+
+    // Round trip through the parts of an existing slice.
+    let slice = Rcs::new(&buffer, 1..4);
+    let rebuilt = Rcs::from_parts(Rcs::to_inner(&slice), Rcs::bounds_range(&slice)).unwrap();
+    assert!(Rcs::ptr_eq(&slice, &rebuilt));
+
+    // Valid ranges at the edges of the buffer.
+    assert_eq!(
+        *Rcs::from_parts(buffer.clone(), 0..5).unwrap(),
+        [1, 2, 3, 4, 5]
+    );
+    assert!(Rcs::from_parts(buffer.clone(), 5..5).unwrap().is_empty());
+    assert!(Rcs::from_parts(buffer.clone(), 0..0).unwrap().is_empty());
+
+    // Invalid ranges are rejected instead of clamped.
+    assert_eq!(Rcs::from_parts(buffer.clone(), 0..6), None);
+    assert_eq!(Rcs::from_parts(buffer.clone(), 6..6), None);
+    assert_eq!(
+        Rcs::from_parts(buffer.clone(), usize::MAX..usize::MAX),
+        None
+    );
+    #[allow(clippy::reversed_empty_ranges)]
+    let reversed = Rcs::from_parts(buffer.clone(), 3..2);
+    assert_eq!(reversed, None);
+    assert_eq!(Rc::strong_count(&buffer), 3);
+}
+
+#[test]
+fn test_take_replace() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    struct State {
+        pending: RcSlice<Vec<u8>>,
+    }
+
+    let buffer = Rc::new(vec![1u8, 2, 3, 4]);
+    let mut state = State {
+        pending: Rcs::new(&buffer, 1..3),
+    };
+
+    let taken = Rcs::take(&mut state.pending);
+    assert_eq!(*taken, [2, 3]);
+    assert!(Rc::ptr_eq(Rcs::inner(&taken), &buffer));
+    assert!(state.pending.is_empty());
+    assert!(!Rc::ptr_eq(Rcs::inner(&state.pending), &buffer));
+    assert_eq!(Rc::strong_count(&buffer), 2);
+
+    let old = Rcs::replace(&mut state.pending, taken);
+    assert!(old.is_empty());
+    assert_eq!(*state.pending, [2, 3]);
+    assert_eq!(Rc::strong_count(&buffer), 2);
+
+    let old = Rcs::replace(&mut state.pending, Rcs::new(&buffer, 3..));
+    assert_eq!(*old, [2, 3]);
+    assert_eq!(*state.pending, [4]);
+    assert_eq!(Rc::strong_count(&buffer), 3);
+}
+
+#[test]
+fn test_collect_references() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([5, 1, 4, 2, 3]);
+    let slice = Rcs::new(&buffer, 1..);
+
+    let small: RcSlice<[u8]> = slice.iter().filter(|&&x| x < 4).collect();
+    assert_eq!(*small, [1, 2, 3]);
+    assert!(!Rc::ptr_eq(Rcs::inner(&small), &buffer));
+    assert_eq!(Rc::strong_count(Rcs::inner(&small)), 1);
+
+    let none: RcSlice<[u8]> = slice.iter().filter(|&&x| x > 10).collect();
+    assert!(none.is_empty());
+}
+
+#[test]
+fn test_with_appended() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u32]> = Rc::new([1, 2, 3, 4]);
+    let slice = Rcs::new(&buffer, 1..3);
+
+    let grown = Rcs::with_appended(&slice, 10..13);
+    assert_eq!(*grown, [2, 3, 10, 11, 12]);
+    assert_eq!(Rcs::bounds_range(&grown), 0..5);
+    assert_eq!(Rc::strong_count(Rcs::inner(&grown)), 1);
+    assert_eq!(*slice, [2, 3]);
+    assert_eq!(*buffer, [1, 2, 3, 4]);
+
+    let same = Rcs::with_appended(&slice, core::iter::empty());
+    assert_eq!(*same, [2, 3]);
+    assert!(!Rc::ptr_eq(Rcs::inner(&same), &buffer));
+
+    let only_extra = Rcs::with_appended(&Rcs::new(&buffer, 4..), [7]);
+    assert_eq!(*only_extra, [7]);
+}
+
+#[test]
+fn test_with_prepended() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::from(&b"..body.."[..]);
+    let body = Rcs::new(&buffer, 2..6);
+
+    let header = [b'H', b'D', body.len() as u8];
+    let packet = Rcs::with_prepended(&body, header);
+    assert_eq!(*packet, *b"HD\x04body");
+    assert_eq!(Rcs::bounds_range(&packet), 0..7);
+    assert_eq!(Rc::strong_count(Rcs::inner(&packet)), 1);
+    assert_eq!(*buffer, *b"..body..");
+
+    // An iterator with no useful size hint works too.
+    let packet = Rcs::with_prepended(&body, b"#!".iter().copied().filter(|_| true));
+    assert_eq!(*packet, *b"#!body");
+
+    let empty = Rcs::with_prepended(&Rcs::new(&buffer, 0..0), core::iter::empty());
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_try_map() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::from(&b"12a45"[..]);
+    let digit = |&b: &u8| char::from(b).to_digit(10).ok_or(b);
+
+    let digits = Rcs::try_map(&Rcs::new(&buffer, ..2), digit).unwrap();
+    assert_eq!(*digits, [1, 2]);
+    assert_eq!(Rc::strong_count(Rcs::inner(&digits)), 1);
+
+    let digits = Rcs::try_map(&Rcs::new(&buffer, 3..), digit).unwrap();
+    assert_eq!(*digits, [4, 5]);
+
+    // Stops at the first error.
+    let mut calls = 0;
+    let result = Rcs::try_map(&Rcs::from(buffer.clone()), |b| {
+        calls += 1;
+        digit(b)
+    });
+    assert_eq!(result, Err(b'a'));
+    assert_eq!(calls, 3);
+
+    let empty = Rcs::try_map(&Rcs::new(&buffer, 5..), digit).unwrap();
+    assert!(empty.is_empty());
+}
+
+#[test]
+fn test_eq_until() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::from(&b"\0abc\0de"[..]);
+
+    // Sentinel present in the middle: only the part before it is compared.
+    let slice = Rcs::new(&buffer, 1..);
+    assert!(Rcs::eq_until(&slice, b"abc", &0));
+    assert!(!Rcs::eq_until(&slice, b"abc\0de", &0));
+    assert!(!Rcs::eq_until(&slice, b"ab", &0));
+
+    // Sentinel absent: the whole slice is compared.
+    let slice = Rcs::new(&buffer, 5..);
+    assert!(Rcs::eq_until(&slice, b"de", &0));
+    assert!(!Rcs::eq_until(&slice, b"d", &0));
+
+    // Sentinel at the start: compares as empty.
+    let slice = Rcs::from(buffer.clone());
+    assert!(Rcs::eq_until(&slice, b"", &0));
+    assert!(!Rcs::eq_until(&slice, b"abc", &0));
+    assert!(Rcs::eq_until(&Rcs::new(&buffer, 0..0), b"", &0));
+}
+
+#[test]
+fn test_rchunks() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([0, 1, 2, 3, 4, 5, 6, 7, 8]);
+    let slice = Rcs::new(&buffer, 1..);
+
+    // From the back, the remainder comes last.
+    let chunks = Rcs::rchunks(&slice, 3);
+    assert_eq!(chunks.len(), 3);
+    let chunks: Vec<_> = chunks.collect();
+    assert_eq!(chunks.len(), 3);
+    assert_eq!(*chunks[0], [6, 7, 8]);
+    assert_eq!(*chunks[1], [3, 4, 5]);
+    assert_eq!(*chunks[2], [1, 2]);
+    assert_eq!(Rcs::bounds_range(&chunks[2]), 1..3);
+    assert!(chunks.iter().all(|c| Rc::ptr_eq(Rcs::inner(c), &buffer)));
+
+    // Reversed, the remainder comes first, and the rest are full-sized and end-aligned.
+    let lens: Vec<_> = Rcs::rchunks(&slice, 3).rev().map(|c| c.len()).collect();
+    assert_eq!(lens, [2, 3, 3]);
+
+    // Both ends at once.
+    let mut chunks = Rcs::rchunks(&slice, 3);
+    assert_eq!(*chunks.next_back().unwrap(), [1, 2]);
+    assert_eq!(*chunks.next().unwrap(), [6, 7, 8]);
+    assert_eq!(chunks.len(), 1);
+    assert_eq!(*chunks.next_back().unwrap(), [3, 4, 5]);
+    assert_eq!(chunks.next(), None);
+
+    // Exact multiples, oversized chunks, and empty slices.
+    let lens: Vec<_> = Rcs::rchunks(&slice, 4).rev().map(|c| c.len()).collect();
+    assert_eq!(lens, [4, 4]);
+    let chunks: Vec<_> = Rcs::rchunks(&slice, 100).collect();
+    assert_eq!(chunks, vec![slice.clone()]);
+    assert_eq!(Rcs::rchunks(&Rcs::new(&buffer, 9..), 2).next(), None);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_rchunks_zero_panics() {
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3]);
+    let _ = RcSlice::rchunks(&RcSlice::from(buffer), 0);
+}
+
+#[test]
+fn test_as_chunks() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5, 6, 7]);
+
+    let even = Rcs::new(&buffer, 1..7);
+    let (chunks, rest) = Rcs::as_chunks::<2>(&even);
+    assert_eq!(chunks, [[2, 3], [4, 5], [6, 7]]);
+    assert!(rest.is_empty());
+    assert_eq!(Rcs::bounds_range(&rest), 7..7);
+
+    let uneven = Rcs::new(&buffer, ..);
+    let (chunks, rest) = Rcs::as_chunks::<3>(&uneven);
+    assert_eq!(chunks, [[1, 2, 3], [4, 5, 6]]);
+    assert_eq!(*rest, [7]);
+    assert!(Rc::ptr_eq(Rcs::inner(&rest), &buffer));
+
+    let (chunks, rest) = Rcs::as_chunks::<8>(&uneven);
+    assert!(chunks.is_empty());
+    assert_eq!(rest, uneven);
+}
+
+#[test]
+#[should_panic(expected = "chunk size must be non-zero")]
+fn test_as_chunks_zero_panics() {
+    RcSlice::as_chunks::<0>(&RcSlice::from(Rc::<[u8]>::from([1, 2])));
+}
+
+#[test]
+fn test_same_or_eq() {
+    use core::cell::Cell;
+    use RcSlice as Rcs;
+
+    #[derive(Clone)]
+    struct Counting<'a>(u8, &'a Cell<usize>);
+
+    impl PartialEq for Counting<'_> {
+        fn eq(&self, other: &Self) -> bool {
+            self.1.set(self.1.get() + 1);
+            self.0 == other.0
         }
     }
+
+    let count = Cell::new(0);
+    let buffer: Rc<[Counting]> = (0..10).map(|i| Counting(i, &count)).collect();
+    let slice = Rcs::new(&buffer, 2..8);
+
+    assert!(Rcs::same_or_eq(&slice, &slice.clone()));
+    assert_eq!(count.get(), 0);
+
+    let copy: Rc<[Counting]> = Rc::from(&buffer[..]);
+    assert!(Rcs::same_or_eq(&slice, &Rcs::new(&copy, 2..8)));
+    assert_eq!(count.get(), 6);
+    assert!(!Rcs::same_or_eq(&slice, &Rcs::new(&copy, 3..9)));
+    assert_eq!(count.get(), 7);
 }
 
-impl<T: RcSliceContainer + ?Sized + Default> RcSlice<T> {
-    /// Tries to reduce the size of the original buffer, if this is the only
-    /// Rc or RcSlice referencing the buffer.
-    ///
-    /// This operation doesn't preserve weak references.
-    /// ```
-    /// # extern crate alloc;
-    /// # use alloc::rc::Rc;
-    /// # use rc_slice2::RcSlice;
-    /// use RcSlice as Rcs;
-    ///
-    /// let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10, 12]);
-    /// let mut slice = Rcs::new(&buffer, 1..4);
-    /// let weak_buffer = Rc::downgrade(&buffer);
-    ///
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(**Rcs::inner(&slice), [2, 4, 6, 8, 10, 12]);
-    ///
-    /// // Shrink fails: there are two strong references.
-    /// assert_eq!(Rcs::shrink(&mut slice), false);
-    ///
-    /// core::mem::drop(buffer);
-    ///
-    /// // Shrink successful: only one strong reference.
-    /// assert_eq!(Rcs::shrink(&mut slice), true);
-    ///
-    /// // The slice is unchanged, and the buffer has shrunk.
-    /// assert_eq!(*slice, [4, 6, 8]);
-    /// assert_eq!(**Rcs::inner(&slice), [4, 6, 8]);
-    ///
-    /// // Weak references were not preserved.
-    /// assert_eq!(weak_buffer.upgrade(), None);
-    ///
-    /// ```
-    pub fn shrink(it: &mut Self) -> bool {
-        // This will be optimized away
-        if !T::IS_SHRINKABLE {
-            return false;
-        }
+#[test]
+fn test_read_integers() {
+    use RcSlice as Rcs;
 
-        if it.start == 0 && it.end == it.underlying.len() {
-            return false;
-        }
+    let buffer: Rc<[u8]> = Rc::new([0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    let slice = Rcs::new(&buffer, 1..9);
 
-        let mut temp = Rc::new(T::default());
-        core::mem::swap(&mut temp, &mut it.underlying);
-        match Rc::try_unwrap(temp) {
-            Ok(mut container) => {
-                if let Some(new_range) = container.shrink_container_to_range(it.start..it.end) {
-                    it.start = new_range.start;
-                    it.end = new_range.end;
-                }
-                let mut temp2 = Rc::new(container);
-                core::mem::swap(&mut temp2, &mut it.underlying);
-                true
-            }
-            Err(mut temp2) => {
-                core::mem::swap(&mut temp2, &mut it.underlying);
-                false
-            }
-        }
+    assert_eq!(Rcs::read_u16_le(&slice), Some(0x0201));
+    assert_eq!(Rcs::read_u16_be(&slice), Some(0x0102));
+    assert_eq!(Rcs::read_u32_le(&slice), Some(0x04030201));
+    assert_eq!(Rcs::read_u32_be(&slice), Some(0x01020304));
+    assert_eq!(Rcs::read_u64_le(&slice), Some(0x0807060504030201));
+    assert_eq!(Rcs::read_u64_be(&slice), Some(0x0102030405060708));
+    assert_eq!(Rcs::bounds_range(&slice), 1..9);
+
+    // Too short.
+    let short = Rcs::new(&buffer, 3..10);
+    assert_eq!(Rcs::read_u64_le(&short), None);
+    assert_eq!(Rcs::read_u64_be(&short), None);
+    assert_eq!(Rcs::read_u32_le(&short), Some(0x06050403));
+    let short = Rcs::new(&buffer, 7..10);
+    assert_eq!(Rcs::read_u32_le(&short), None);
+    assert_eq!(Rcs::read_u32_be(&short), None);
+    let short = Rcs::new(&buffer, 9..);
+    assert_eq!(Rcs::read_u16_le(&short), None);
+    assert_eq!(Rcs::read_u16_be(&short), None);
+}
+
+#[test]
+fn test_take_integers() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([
+        0x01, 0x02, 0x01, 0x02, 0x01, 0x02, 0x03, 0x04, 0x01, 0x02, 0x03, 0x04, 0x01, 0x02, 0x03,
+        0x04, 0x05, 0x06, 0x07, 0x08, 0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0xff,
+    ]);
+    let mut slice = Rcs::new(&buffer, ..);
+
+    assert_eq!(Rcs::take_u16_le(&mut slice), Some(0x0201));
+    assert_eq!(Rcs::take_u16_be(&mut slice), Some(0x0102));
+    assert_eq!(Rcs::take_u32_le(&mut slice), Some(0x04030201));
+    assert_eq!(Rcs::take_u32_be(&mut slice), Some(0x01020304));
+    assert_eq!(Rcs::take_u64_le(&mut slice), Some(0x0807060504030201));
+    assert_eq!(Rcs::take_u64_be(&mut slice), Some(0x0102030405060708));
+    assert_eq!(Rcs::bounds_range(&slice), 28..29);
+
+    // Too short; nothing is consumed.
+    assert_eq!(Rcs::take_u16_le(&mut slice), None);
+    assert_eq!(Rcs::take_u16_be(&mut slice), None);
+    assert_eq!(Rcs::take_u32_le(&mut slice), None);
+    assert_eq!(Rcs::take_u32_be(&mut slice), None);
+    assert_eq!(Rcs::take_u64_le(&mut slice), None);
+    assert_eq!(Rcs::take_u64_be(&mut slice), None);
+    assert_eq!(*slice, [0xff]);
+}
+
+#[test]
+fn test_named_iterators() {
+    use alloc::format;
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    // The iterators can be stored and named, not just consumed where they are created.
+    struct Reader {
+        records: RcRChunks<[u8]>,
+        lines: RcLines<[u8]>,
+        owned: RcIntoIterOwned<Vec<u8>>,
+    }
+
+    let buffer: Rc<[u8]> = Rc::from(&b"ab\ncd\n"[..]);
+    let mut reader = Reader {
+        records: Rcs::rchunks(&Rcs::from(buffer.clone()), 4),
+        lines: Rcs::lines(&Rcs::from(buffer.clone())),
+        owned: Rcs::into_iter_owned(Rcs::from(Rc::new(vec![1u8, 2, 3]))),
+    };
+
+    let saved = reader.records.clone();
+    assert_eq!(*reader.records.next().unwrap(), *b"\ncd\n");
+    assert_eq!(reader.records.len(), 1);
+    assert_eq!(saved.len(), 2);
+    assert!(saved
+        .into_iter()
+        .all(|chunk| Rc::ptr_eq(Rcs::inner(&chunk), &buffer)));
+
+    let saved = reader.lines.clone();
+    assert_eq!(*reader.lines.next().unwrap(), *b"ab");
+    assert_eq!(saved.count(), 2);
+    assert_eq!(reader.lines.count(), 1);
+
+    // Unique buffer: the cloned iterator moves out its own copy of the elements.
+    let saved = reader.owned.clone();
+    assert_eq!(reader.owned.next(), Some(1));
+    assert_eq!(saved.collect::<Vec<_>>(), [1, 2, 3]);
+    assert!(format!("{:?}", reader.owned).starts_with("Unique("));
+
+    let shared = Rcs::into_iter_owned(Rcs::new(&Rc::new(vec![4u8, 5]), 1..));
+    assert!(format!("{:?}", shared).starts_with("Shared("));
+    assert_eq!(shared.clone().collect::<Vec<_>>(), [5]);
+
+    let chunks = Rcs::rchunks(&Rcs::from(buffer), 4);
+    assert!(format!("{:?}", chunks).contains("chunk_size: 4"));
+}
+
+#[test]
+fn test_copy_from_slice() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([1, 2, 3, 4, 5]);
+    let mut slice = Rcs::new(&buffer, 1..4);
+
+    // Shared with the Rc, and then with another slice.
+    assert!(!Rcs::copy_from_slice(&mut slice, &[7, 8, 9]));
+    drop(buffer);
+    let other = Rcs::new(Rcs::inner(&slice), 4..);
+    assert!(!Rcs::copy_from_slice(&mut slice, &[7, 8, 9]));
+    assert_eq!(*slice, [2, 3, 4]);
+    drop(other);
+
+    assert!(Rcs::copy_from_slice(&mut slice, &[7, 8, 9]));
+    assert_eq!(*slice, [7, 8, 9]);
+    assert_eq!(**Rcs::inner(&slice), [1, 7, 8, 9, 5]);
+
+    let mut empty = Rcs::new(Rcs::inner(&slice), 5..);
+    assert!(!Rcs::copy_from_slice(&mut empty, &[]));
+}
+
+#[test]
+#[should_panic(expected = "source slice length does not match the slice length")]
+fn test_copy_from_slice_length_mismatch_panics() {
+    let mut slice = RcSlice::new(&Rc::<[u8]>::from([1, 2, 3]), 1..);
+    RcSlice::copy_from_slice(&mut slice, &[1, 2, 3]);
+}
+
+#[test]
+fn test_leak() {
+    use alloc::vec;
+    use RcSlice as Rcs;
+
+    fn intern(slice: RcSlice<Vec<u32>>) -> Option<&'static [u32]> {
+        Rcs::leak(slice)
     }
+
+    let buffer = Rc::new(vec![1, 2, 3, 4, 5]);
+    let slice = Rcs::new(&buffer, 1..4);
+    let other = Rcs::new(&buffer, ..1);
+    drop(buffer);
+
+    // Shared with another slice.
+    assert_eq!(intern(slice.clone()), None);
+    drop(other);
+
+    let weak = Rc::downgrade(Rcs::inner(&slice));
+    let leaked = intern(slice).unwrap();
+    assert_eq!(leaked, [2, 3, 4]);
+    assert!(weak.upgrade().is_none());
+
+    let empty = Rcs::new(&Rc::new(vec![1, 2]), 2..);
+    assert_eq!(intern(empty), Some(&[][..]));
 }
 
 #[test]
-fn test_index_ranges() {
+fn test_leak_copy() {
     use RcSlice as Rcs;
 
-    let buffer: Rc<alloc::vec::Vec<u8>> = Rc::new((0..10).into_iter().collect());
-    let slice = Rcs::new(&buffer, 3..8);
-    assert_eq!(*slice, [3, 4, 5, 6, 7]);
+    let buffer: Rc<[u32]> = Rc::new([1, 2, 3, 4, 5]);
+    let slice = Rcs::new(&buffer, 1..4);
 
-    assert_eq!(*Rcs::index(&slice, ..), [3, 4, 5, 6, 7]);
-    assert_eq!(*Rcs::index(&slice, 1..), [4, 5, 6, 7]);
-    assert_eq!(*Rcs::index(&slice, 8..), []);
-    assert_eq!(*Rcs::index(&slice, 5..), []);
-    assert_eq!(*Rcs::index(&slice, ..2), [3, 4]);
-    assert_eq!(*Rcs::index(&slice, ..70), [3, 4, 5, 6, 7]);
-    assert_eq!(*Rcs::index(&slice, 3..8), [6, 7]);
+    // Shared with the Rc.
+    assert_eq!(Rcs::leak_copy(slice.clone()), None);
+    drop(buffer);
+
+    // Weak pointers count as sharing too.
+    let weak = Rc::downgrade(Rcs::inner(&slice));
+    assert_eq!(Rcs::leak_copy(slice.clone()), None);
+    drop(weak);
+
+    let leaked: &'static [u32] = Rcs::leak_copy(slice).unwrap();
+    assert_eq!(leaked, [2, 3, 4]);
+
+    let empty = Rcs::new(&Rc::<[u32]>::from([1, 2]), 2..);
+    assert_eq!(Rcs::leak_copy(empty), Some(&[][..]));
+}
+
+#[test]
+fn test_gaps() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<[u8]> = Rc::new([0; 20]);
+    let parent = Rcs::new(&buffer, 2..18);
+    let ranges = |slices: Vec<RcSlice<[u8]>>| {
+        assert!(slices
+            .iter()
+            .all(|gap| Rc::ptr_eq(Rcs::inner(gap), &buffer)));
+        slices
+            .iter()
+            .map(|gap| (gap.start, gap.end))
+            .collect::<Vec<_>>()
+    };
+
+    // No children: the whole parent is a gap.
+    assert_eq!(ranges(Rcs::gaps(&parent, &[])), [(2, 18)]);
+
+    // Disjoint, given out of order.
+    let children = [Rcs::new(&buffer, 10..12), Rcs::new(&buffer, 4..6)];
+    assert_eq!(
+        ranges(Rcs::gaps(&parent, &children)),
+        [(2, 4), (6, 10), (12, 18)]
+    );
+
+    // Overlapping, including one inside another.
+    let children = [
+        Rcs::new(&buffer, 4..9),
+        Rcs::new(&buffer, 7..12),
+        Rcs::new(&buffer, 8..10),
+    ];
+    assert_eq!(ranges(Rcs::gaps(&parent, &children)), [(2, 4), (12, 18)]);
+
+    // Adjacent, and touching both ends of the parent.
+    let children = [
+        Rcs::new(&buffer, 2..5),
+        Rcs::new(&buffer, 5..8),
+        Rcs::new(&buffer, 14..18),
+    ];
+    assert_eq!(ranges(Rcs::gaps(&parent, &children)), [(8, 14)]);
+
+    // Fully covered, with an empty child.
+    let children = [Rcs::new(&buffer, 2..18), Rcs::new(&buffer, 9..9)];
+    assert_eq!(ranges(Rcs::gaps(&parent, &children)), []);
+
+    // Other buffers, and children that stick out of the parent, are ignored.
+    let other: Rc<[u8]> = Rc::new([0; 20]);
+    let children = [
+        Rcs::new(&other, 4..10),
+        Rcs::new(&buffer, 0..6),
+        Rcs::new(&buffer, 12..14),
+    ];
+    assert_eq!(ranges(Rcs::gaps(&parent, &children)), [(2, 12), (14, 18)]);
+
+    // The free function does the same.
+    assert_eq!(ranges(gaps(&parent, &children)), [(2, 12), (14, 18)]);
 }
 
 impl<T: ?Sized> Clone for RcSlice<T> {
@@ -760,10 +5551,8 @@ impl<T: ?Sized> Clone for RcSlice<T> {
 
 impl<T: RcSliceContainer + ?Sized> AsRef<[T::Item]> for RcSlice<T> {
     fn as_ref(&self) -> &[T::Item] {
-        // TODO: Evaluate whether this will panic, and when.
-        // I believe it will only panic if the container trait impl
-        // is implemented weirdly.
-        self.underlying.get(self.start..self.end).unwrap()
+        self.underlying
+            .get_unchecked_by_invariant(self.start..self.end)
     }
 }
 
@@ -775,9 +5564,57 @@ impl<T: RcSliceContainer + ?Sized> Deref for RcSlice<T> {
     }
 }
 
+/// Indexes into the elements viewed by the slice, exactly like indexing a `[T]`. To get an
+/// RcSlice of a range instead of a borrowed slice, see [`RcSlice::index`].
+impl<T, I> Index<I> for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    I: SliceIndex<[T::Item]>,
+{
+    type Output = I::Output;
+
+    fn index(&self, index: I) -> &Self::Output {
+        &self.as_ref()[index]
+    }
+}
+
+impl<T: RcSliceContainer + ?Sized> Sealed for RcSlice<T> {}
+
+impl<T: RcSliceContainer + ?Sized> SharedSlice for RcSlice<T> {
+    type Item = T::Item;
+
+    fn len(it: &Self) -> usize {
+        RcSlice::len(it)
+    }
+
+    fn is_empty(it: &Self) -> bool {
+        RcSlice::is_empty(it)
+    }
+
+    fn as_slice(it: &Self) -> &[T::Item] {
+        RcSlice::as_slice(it)
+    }
+
+    fn split_at(it: &Self, mid: usize) -> (Self, Self) {
+        RcSlice::split_at(it, mid)
+    }
+
+    fn advance(it: &mut Self, incr: usize) -> Option<&[T::Item]> {
+        RcSlice::advance(it, incr)
+    }
+
+    fn bounds_range(it: &Self) -> Range<usize> {
+        RcSlice::bounds_range(it)
+    }
+
+    fn ptr_eq(this: &Self, other: &Self) -> bool {
+        RcSlice::ptr_eq(this, other)
+    }
+}
+
 impl<T: RcSliceContainer + ?Sized> From<Rc<T>> for RcSlice<T> {
     fn from(underlying: Rc<T>) -> Self {
-        Self::new(&underlying, ..)
+        Self::from_rc_range(underlying, ..)
     }
 }
 
@@ -788,29 +5625,166 @@ fn test_from() {
     assert_eq!(*ref_slice, [4, 5, 6, 7]);
 }
 
+impl<T> From<RcSlice<T>> for Vec<T::Item>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: RcSlice<T>) -> Self {
+        slice.as_ref().to_vec()
+    }
+}
+
+impl<T> From<RcSlice<T>> for Rc<[T::Item]>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: RcSlice<T>) -> Self {
+        Rc::from(slice.as_ref())
+    }
+}
+
+impl<T> From<RcSlice<T>> for Box<[T::Item]>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Clone,
+{
+    fn from(slice: RcSlice<T>) -> Self {
+        Box::from(slice.as_ref())
+    }
+}
+
+/// Clones the elements into a new buffer, and returns a slice of all of it.
+///
+/// The buffer is allocated once, and the elements are cloned straight into it, without an
+/// intermediate `Vec`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::RcSlice;
+/// let data = [2, 4, 6];
+/// let slice = RcSlice::from(&data[1..]);
+/// assert_eq!(*slice, [4, 6]);
+/// ```
+impl<T: Clone> From<&[T]> for RcSlice<[T]> {
+    fn from(data: &[T]) -> Self {
+        RcSlice::from(Rc::<[T]>::from(data))
+    }
+}
+
+/// Collects the elements into a new buffer, and returns a slice of all of it.
+///
+/// This is exactly as efficient as collecting into an `Rc<[T]>`: if the iterator's length is
+/// known exactly up front (for example a range, or a `map` over a slice's iterator), the buffer
+/// is allocated once. Otherwise, the elements are first collected into a `Vec` preallocated
+/// from the iterator's `size_hint`, and then moved into the buffer.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::RcSlice;
+/// let squares: RcSlice<[u32]> = (1..5).map(|x| x * x).collect();
+/// assert_eq!(*squares, [1, 4, 9, 16]);
+/// ```
+impl<T> FromIterator<T> for RcSlice<[T]> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        RcSlice::from(iter.into_iter().collect::<Rc<[T]>>())
+    }
+}
+
+/// Copies the referenced elements into a new buffer, and returns a slice of all of it. This
+/// is the same as collecting `iter.into_iter().copied()`.
+///
+/// ```
+/// # extern crate alloc;
+/// # use rc_slice2::RcSlice;
+/// let data = [1, 2, 3, 4, 5, 6];
+/// let even: RcSlice<[u32]> = data.iter().filter(|&&x| x % 2 == 0).collect();
+/// assert_eq!(*even, [2, 4, 6]);
+/// ```
+impl<'a, T: Copy + 'a> FromIterator<&'a T> for RcSlice<[T]> {
+    fn from_iter<I: IntoIterator<Item = &'a T>>(iter: I) -> Self {
+        iter.into_iter().copied().collect()
+    }
+}
+
+#[test]
+fn test_into_vec_and_rc() {
+    use alloc::vec;
+
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![2, 4, 6, 8, 10]);
+    let slice = RcSlice::new(&buffer, 1..4);
+
+    let copy: Vec<u8> = slice.clone().into();
+    assert_eq!(copy, [4, 6, 8]);
+
+    let copy: Rc<[u8]> = slice.into();
+    assert_eq!(*copy, [4, 6, 8]);
+    assert_eq!(copy.len(), 3);
+    assert_eq!(Rc::strong_count(&copy), 1);
+
+    // The original buffer is no longer referenced by the slice.
+    assert_eq!(Rc::strong_count(&buffer), 1);
+}
+
+#[test]
+fn test_into_boxed_slice() {
+    let buffer: Rc<[u8]> = Rc::new([2, 4, 6, 8, 10]);
+    let slice = RcSlice::new(&buffer, 1..4);
+
+    let boxed: Box<[u8]> = slice.clone().into();
+    assert_eq!(boxed.len(), slice.len());
+    assert_eq!(*boxed, *slice);
+
+    let empty: Box<[u8]> = RcSlice::new(&buffer, 5..).into();
+    assert!(empty.is_empty());
+}
+
 impl<T: RcSliceContainer + fmt::Debug + ?Sized> fmt::Debug for RcSlice<T> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         self.underlying.fmt(f)
     }
 }
 
-impl<T: RcSliceContainer + PartialEq + ?Sized> PartialEq for RcSlice<T> {
+impl<T> PartialEq for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: PartialEq,
+{
+    /// Compares the elements viewed by both slices, the same way `[T]` does. This agrees with
+    /// [`PartialOrd`] and with comparing the borrowed slices.
+    ///
+    /// The elements are compared even if both slices view the same range of the same buffer.
+    /// To skip them in that case, use [`same_or_eq`](RcSlice::same_or_eq).
     fn eq(&self, other: &Self) -> bool {
-        self.underlying == other.underlying
+        self.as_ref() == other.as_ref()
     }
 }
 
-impl<T: RcSliceContainer + Eq + ?Sized> Eq for RcSlice<T> {}
+impl<T> Eq for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Eq,
+{
+}
 
-impl<T: RcSliceContainer + PartialOrd + ?Sized> PartialOrd for RcSlice<T> {
+impl<T> PartialOrd for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: PartialOrd,
+{
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        self.underlying.partial_cmp(&other.underlying)
+        self.as_ref().partial_cmp(other.as_ref())
     }
 }
 
-impl<T: RcSliceContainer + Ord + ?Sized> Ord for RcSlice<T> {
+impl<T> Ord for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    T::Item: Ord,
+{
     fn cmp(&self, other: &Self) -> Ordering {
-        self.underlying.cmp(&other.underlying)
+        RcSlice::cmp(self, other)
     }
 }
 
@@ -820,18 +5794,41 @@ impl<T: RcSliceContainer + ?Sized> Borrow<[T::Item]> for RcSlice<T> {
     }
 }
 
+/// Hashes the elements viewed by the slice the same way `[T]` does: the length, followed by each
+/// element. This matches the `Borrow<[T]>` impl, so a map with RcSlice keys can be searched
+/// with a plain slice.
+///
+/// ```
+/// # extern crate alloc;
+/// # use alloc::rc::Rc;
+/// # use rc_slice2::RcSlice;
+/// use std::collections::HashMap;
+///
+/// let buffer: Rc<[u8]> = Rc::new([0, 1, 2, 3]);
+/// let mut names = HashMap::new();
+/// names.insert(RcSlice::new(&buffer, 1..), "counting");
+///
+/// assert_eq!(names.get(&[1, 2, 3][..]), Some(&"counting"));
+/// ```
 impl<T> Hash for RcSlice<T>
 where
     T: RcSliceContainer + Hash + ?Sized,
     T::Item: Hash,
 {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        Hash::hash_slice(self.deref(), state)
+        <[T::Item] as Hash>::hash(self.as_ref(), state)
     }
 }
 
-impl<T: RcSliceContainer + Default + ?Sized> Default for RcSlice<T> {
+/// Creates an empty slice over the default (empty) container. This never allocates space for
+/// any elements: `[T]` and `Vec<T>` buffers have a capacity of zero. `SmallVec` and `TinyVec`
+/// buffers report their inline capacity, but don't allocate on the heap.
+impl<T> Default for RcSlice<T>
+where
+    T: RcSliceContainer + ?Sized,
+    Rc<T>: Default,
+{
     fn default() -> Self {
-        Self::new(&Rc::new(T::default()), ..)
+        RcSlice::from(Rc::default())
     }
 }
@@ -0,0 +1,76 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+use serde::de::{Deserialize, Deserializer, SeqAccess, Visitor};
+use serde::ser::{Serialize, Serializer};
+
+use crate::{ArcSlice, RcSlice, RcSliceContainer};
+
+// Only byte slices are supported. They are written with `serialize_bytes`, like `serde_bytes`
+// does, so compact formats store them as a single byte string instead of one element at a time.
+// A generic impl for other element types would overlap with these, and can't be specialized.
+
+/// Serializes the bytes viewed by the slice (not the whole buffer) as a byte string. Formats
+/// without a byte string type decide how to write it; for example, JSON writes an array of
+/// numbers.
+impl<T: RcSliceContainer<Item = u8> + ?Sized> Serialize for RcSlice<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+/// Serializes the bytes viewed by the slice (not the whole buffer) as a byte string. Formats
+/// without a byte string type decide how to write it; for example, JSON writes an array of
+/// numbers.
+impl<T: RcSliceContainer<Item = u8> + ?Sized> Serialize for ArcSlice<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_bytes(self)
+    }
+}
+
+/// Deserializes a byte string (or a sequence of bytes) into a new buffer, and returns a slice
+/// of all of it.
+impl<'de> Deserialize<'de> for RcSlice<[u8]> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+        Ok(RcSlice::from(Rc::<[u8]>::from(bytes)))
+    }
+}
+
+/// Deserializes a byte string (or a sequence of bytes) into a new buffer, and returns a slice
+/// of all of it.
+impl<'de> Deserialize<'de> for ArcSlice<[u8]> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = deserializer.deserialize_bytes(BytesVisitor)?;
+        Ok(ArcSlice::from(Arc::<[u8]>::from(bytes)))
+    }
+}
+
+struct BytesVisitor;
+
+impl<'de> Visitor<'de> for BytesVisitor {
+    type Value = Vec<u8>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a byte string")
+    }
+
+    fn visit_bytes<E: serde::de::Error>(self, v: &[u8]) -> Result<Vec<u8>, E> {
+        Ok(v.to_vec())
+    }
+
+    fn visit_byte_buf<E: serde::de::Error>(self, v: Vec<u8>) -> Result<Vec<u8>, E> {
+        Ok(v)
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Vec<u8>, A::Error> {
+        // Don't trust the hint with a huge allocation.
+        let mut bytes = Vec::with_capacity(seq.size_hint().unwrap_or(0).min(4096));
+        while let Some(byte) = seq.next_element()? {
+            bytes.push(byte);
+        }
+        Ok(bytes)
+    }
+}
@@ -0,0 +1,75 @@
+use alloc::rc::Rc;
+use alloc::sync::Arc;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::{ArcSlice, RcSlice};
+
+/// A [`fmt::Write`] implementation that collects formatted text into a new byte buffer, which
+/// can then be turned into an [`RcSlice`] or [`ArcSlice`].
+///
+/// ```
+/// # use rc_slice2::RcByteWriter;
+/// use core::fmt::Write;
+///
+/// let mut writer = RcByteWriter::new();
+/// write!(writer, "{}-{}", 12, "ab").unwrap();
+///
+/// let slice = writer.into_rc_slice();
+/// assert_eq!(*slice, *b"12-ab");
+/// ```
+#[derive(Debug, Default, Clone)]
+pub struct RcByteWriter {
+    buffer: Vec<u8>,
+}
+
+impl RcByteWriter {
+    /// Create a new, empty writer.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the bytes written so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
+    /// Moves the written bytes into a new `Rc`, and returns a slice of all of them.
+    pub fn into_rc_slice(self) -> RcSlice<[u8]> {
+        RcSlice::from(Rc::<[u8]>::from(self.buffer))
+    }
+
+    /// Moves the written bytes into a new `Arc`, and returns a slice of all of them.
+    pub fn into_arc_slice(self) -> ArcSlice<[u8]> {
+        ArcSlice::from(Arc::<[u8]>::from(self.buffer))
+    }
+}
+
+impl fmt::Write for RcByteWriter {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.buffer.extend_from_slice(s.as_bytes());
+        Ok(())
+    }
+}
+
+#[test]
+fn test_byte_writer() {
+    use fmt::Write;
+
+    let mut writer = RcByteWriter::new();
+    assert_eq!(writer.as_bytes(), b"");
+
+    for x in [1, 20, 300] {
+        write!(writer, "{},", x).unwrap();
+    }
+    writer.write_char('é').unwrap();
+    assert_eq!(writer.as_bytes(), "1,20,300,é".as_bytes());
+
+    let slice = writer.clone().into_rc_slice();
+    assert_eq!(*slice, *"1,20,300,é".as_bytes());
+    assert_eq!(RcSlice::bounds_range(&slice), 0..11);
+    assert_eq!(Rc::strong_count(RcSlice::inner(&slice)), 1);
+
+    let slice = writer.into_arc_slice();
+    assert_eq!(*slice, *"1,20,300,é".as_bytes());
+}
@@ -0,0 +1,34 @@
+//! Checks that creating views of an existing buffer never allocates.
+//!
+//! This is an integration test because it replaces the global allocator (see `common`), and only
+//! contains a single test so that no other test can allocate while it is counting.
+
+use std::sync::Arc;
+
+use rc_slice2::ArcSlice;
+
+mod common;
+
+use common::count_allocations;
+
+#[test]
+fn test_views_do_not_allocate() {
+    let buffer: Arc<[u32]> = (0..1024).collect();
+    let slice = ArcSlice::new(&buffer, 16..1000);
+
+    let (views, allocations) = count_allocations(|| {
+        let mut views = 0;
+        for i in 0..1000 {
+            let clone = slice.clone();
+            let (low, high) = ArcSlice::split_at(&clone, i % clone.len());
+            let mut rest = ArcSlice::new(&buffer, ..);
+            let front = ArcSlice::split_off_before(&mut rest, i).unwrap();
+            views += low.len() + high.len() + front.len() + rest.len();
+        }
+        views
+    });
+
+    assert_eq!(allocations, 0);
+    assert_eq!(views, 1000 * (984 + 1024));
+    assert_eq!(Arc::strong_count(&buffer), 2);
+}
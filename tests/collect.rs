@@ -0,0 +1,63 @@
+//! Checks how many allocations collecting or copying into a new slice makes.
+//!
+//! This is an integration test because it replaces the global allocator (see `common`), and only
+//! contains a single test so that no other test can allocate while it is counting.
+
+use rc_slice2::{ArcSlice, RcSlice};
+
+mod common;
+
+use common::count_allocations;
+
+/// An exact-size iterator that the standard library can't see through, so collecting it goes
+/// through an intermediate `Vec`.
+struct Countdown(u32);
+
+impl Iterator for Countdown {
+    type Item = u32;
+
+    fn next(&mut self) -> Option<u32> {
+        self.0 = self.0.checked_sub(1)?;
+        Some(self.0)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.0 as usize, Some(self.0 as usize))
+    }
+}
+
+impl ExactSizeIterator for Countdown {}
+
+#[test]
+fn test_collect_allocations() {
+    // A source with a trusted length is collected straight into the buffer.
+    let (slice, allocations) =
+        count_allocations(|| (0..1000u32).map(|x| x * 2).collect::<RcSlice<[u32]>>());
+    assert_eq!(allocations, 1);
+    assert_eq!(slice.len(), 1000);
+    assert_eq!(slice[999], 1998);
+
+    let (slice, allocations) = count_allocations(|| (0..1000u32).collect::<ArcSlice<[u32]>>());
+    assert_eq!(allocations, 1);
+    assert_eq!(slice.len(), 1000);
+
+    // Any other exact-size source is preallocated, so it never reallocates: one allocation
+    // for the intermediate `Vec`, and one for the buffer.
+    let (slice, allocations) = count_allocations(|| Countdown(1000).collect::<RcSlice<[u32]>>());
+    assert_eq!(allocations, 2);
+    assert_eq!(slice[0], 999);
+
+    let (slice, allocations) = count_allocations(|| Countdown(1000).collect::<ArcSlice<[u32]>>());
+    assert_eq!(allocations, 2);
+    assert_eq!(slice[999], 0);
+
+    // Copying from a borrowed slice allocates the buffer and nothing else.
+    let data: Vec<u32> = (0..1000).collect();
+    let (slice, allocations) = count_allocations(|| RcSlice::from(&data[10..]));
+    assert_eq!(allocations, 1);
+    assert_eq!(*slice, data[10..]);
+
+    let (slice, allocations) = count_allocations(|| ArcSlice::from(data.as_slice()));
+    assert_eq!(allocations, 1);
+    assert_eq!(*slice, *data);
+}
@@ -0,0 +1,153 @@
+//! Property tests checking that no sequence of operations can break the bounds invariant
+//! `start <= end <= underlying.len()`, or make a slice view the wrong elements.
+
+use std::ops::Bound;
+use std::rc::Rc;
+
+use proptest::prelude::*;
+use rc_slice2::RcSlice;
+
+#[derive(Debug, Clone)]
+enum Op {
+    New(Bound<usize>, Bound<usize>),
+    ChangeRange(Bound<usize>, Bound<usize>),
+    Advance(usize),
+    SaturatingAdvance(usize),
+    Retract(usize),
+    SaturatingRetract(usize),
+    SplitOffBefore(usize),
+    SplitOffAfter(usize),
+    SplitAtChecked(usize),
+    Index(Bound<usize>, Bound<usize>),
+    Shrink,
+}
+
+/// Mostly small numbers, so that they are often in bounds, plus some extreme values.
+fn number() -> impl Strategy<Value = usize> {
+    prop_oneof![
+        4 => 0..40usize,
+        1 => Just(usize::MAX),
+        1 => Just(usize::MAX - 1),
+        1 => any::<usize>(),
+    ]
+}
+
+fn bound() -> impl Strategy<Value = Bound<usize>> {
+    prop_oneof![
+        number().prop_map(Bound::Included),
+        number().prop_map(Bound::Excluded),
+        Just(Bound::Unbounded),
+    ]
+}
+
+/// `new` asserts in debug builds that the start of a range isn't after its end, so only
+/// generate ranges that are in order.
+fn ordered(start: Bound<usize>, end: Bound<usize>) -> bool {
+    let start = match start {
+        Bound::Included(x) => x,
+        Bound::Excluded(x) => x.saturating_add(1),
+        Bound::Unbounded => 0,
+    };
+    match end {
+        Bound::Included(x) => start <= x.saturating_add(1),
+        Bound::Excluded(x) => start <= x,
+        Bound::Unbounded => true,
+    }
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    prop_oneof![
+        (bound(), bound())
+            .prop_filter("inverted range", |(s, e)| ordered(*s, *e))
+            .prop_map(|(s, e)| Op::New(s, e)),
+        (bound(), bound()).prop_map(|(s, e)| Op::ChangeRange(s, e)),
+        number().prop_map(Op::Advance),
+        number().prop_map(Op::SaturatingAdvance),
+        number().prop_map(Op::Retract),
+        number().prop_map(Op::SaturatingRetract),
+        number().prop_map(Op::SplitOffBefore),
+        number().prop_map(Op::SplitOffAfter),
+        number().prop_map(Op::SplitAtChecked),
+        (bound(), bound()).prop_map(|(s, e)| Op::Index(s, e)),
+        Just(Op::Shrink),
+    ]
+}
+
+/// Checks the invariant, and that the slice views the expected elements of the buffer.
+fn check(slice: &RcSlice<Vec<u32>>) {
+    let range = RcSlice::bounds_range(slice);
+    let inner = RcSlice::inner(slice);
+    assert!(range.start <= range.end, "{range:?}");
+    assert!(range.end <= inner.len(), "{range:?} in {}", inner.len());
+    assert_eq!(**slice, inner[range.clone()]);
+    assert_eq!(RcSlice::len(slice), range.len());
+}
+
+proptest! {
+    #[test]
+    fn bounds_invariant_holds(
+        len in 0..32usize,
+        ops in proptest::collection::vec(op(), 0..24),
+    ) {
+        let buffer: Rc<Vec<u32>> = Rc::new((0..len as u32).collect());
+        let mut slice = RcSlice::new(&buffer, ..);
+        // Kept alive (or dropped) so that `Shrink` sometimes succeeds.
+        let mut others = vec![buffer];
+
+        for op in ops {
+            match op {
+                Op::New(s, e) => slice = RcSlice::new(RcSlice::inner(&slice), (s, e)),
+                Op::ChangeRange(s, e) => {
+                    let range = RcSlice::change_range(&mut slice, (s, e));
+                    prop_assert_eq!(range, RcSlice::bounds_range(&slice));
+                }
+                Op::Advance(n) => {
+                    let before = slice.len();
+                    let shed = RcSlice::advance(&mut slice, n).map(<[u32]>::len);
+                    prop_assert_eq!(shed, (n <= before).then_some(n));
+                }
+                Op::SaturatingAdvance(n) => {
+                    let before = slice.len();
+                    let shed = RcSlice::saturating_advance(&mut slice, n).len();
+                    prop_assert_eq!(shed, n.min(before));
+                }
+                Op::Retract(n) => {
+                    let before = slice.len();
+                    let shed = RcSlice::retract(&mut slice, n).map(<[u32]>::len);
+                    prop_assert_eq!(shed, (n <= before).then_some(n));
+                }
+                Op::SaturatingRetract(n) => {
+                    let before = slice.len();
+                    let shed = RcSlice::saturating_retract(&mut slice, n).len();
+                    prop_assert_eq!(shed, n.min(before));
+                }
+                Op::SplitOffBefore(n) => {
+                    if let Some(front) = RcSlice::split_off_before(&mut slice, n) {
+                        check(&front);
+                        others.push(RcSlice::inner(&front).clone());
+                    }
+                }
+                Op::SplitOffAfter(n) => {
+                    if let Some(back) = RcSlice::split_off_after(&mut slice, n) {
+                        check(&back);
+                    }
+                }
+                Op::SplitAtChecked(n) => {
+                    let split = RcSlice::split_at_checked(&slice, n);
+                    prop_assert_eq!(split.is_some(), n <= slice.len());
+                    if let Some((low, high)) = split {
+                        check(&low);
+                        check(&high);
+                        prop_assert_eq!(low.len() + high.len(), slice.len());
+                    }
+                }
+                Op::Index(s, e) => check(&RcSlice::index(&slice, (s, e))),
+                Op::Shrink => {
+                    others.clear();
+                    RcSlice::shrink(&mut slice);
+                }
+            }
+            check(&slice);
+        }
+    }
+}
@@ -0,0 +1,45 @@
+//! Round trips of byte slices through a binary and a human-readable format. These live outside
+//! the library so that `serde_json`'s `PartialEq` impls don't break type inference in its tests.
+
+#![cfg(feature = "serde")]
+
+use std::rc::Rc;
+use std::sync::Arc;
+
+use rc_slice2::{ArcSlice, RcSlice};
+
+#[test]
+fn test_serde_bytes_binary() {
+    use RcSlice as Rcs;
+
+    let buffer: Rc<Vec<u8>> = Rc::new(vec![1, 2, 3, 200, 5]);
+    let slice = Rcs::new(&buffer, 1..4);
+
+    // A byte string is a length prefix and then the raw bytes, with no per-element overhead.
+    let encoded = postcard::to_allocvec(&slice).unwrap();
+    assert_eq!(encoded, [3, 2, 3, 200]);
+
+    let decoded: RcSlice<[u8]> = postcard::from_bytes(&encoded).unwrap();
+    assert_eq!(*decoded, [2, 3, 200]);
+    assert_eq!(Rcs::bounds_range(&decoded), 0..3);
+
+    let decoded: ArcSlice<[u8]> = postcard::from_bytes(&encoded).unwrap();
+    assert_eq!(*decoded, [2, 3, 200]);
+    assert_eq!(postcard::to_allocvec(&decoded).unwrap(), encoded);
+}
+
+#[test]
+fn test_serde_bytes_json() {
+    let buffer: Arc<[u8]> = Arc::new([1, 2, 3, 200, 5]);
+    let slice = ArcSlice::new(&buffer, 2..);
+
+    let encoded = serde_json::to_string(&slice).unwrap();
+    assert_eq!(encoded, "[3,200,5]");
+
+    let decoded: ArcSlice<[u8]> = serde_json::from_str(&encoded).unwrap();
+    assert_eq!(*decoded, [3, 200, 5]);
+
+    let decoded: RcSlice<[u8]> = serde_json::from_str("[]").unwrap();
+    assert!(decoded.is_empty());
+    assert!(serde_json::from_str::<RcSlice<[u8]>>("[256]").is_err());
+}